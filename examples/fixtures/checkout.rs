@@ -0,0 +1,240 @@
+use crate::{
+    validate_items_async, validators, AsyncValidatable, FieldPath, FormValidator, Validatable,
+    ValidationError, ValidationErrors, Validator,
+};
+use std::{future::Future, pin::Pin};
+
+/// Product SKUs which are out of stock, checked by the asynchronous
+/// validation of a [LineItem].
+pub const OUT_OF_STOCK_SKUS: &[&str] = &["SKU-404"];
+
+/// A postal address, a nested section of a [CheckoutForm].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub postcode: String,
+}
+
+impl Address {
+    /// An address which passes all of its validation.
+    pub fn valid() -> Self {
+        Self {
+            street: "1 Main Street".to_string(),
+            city: "Springfield".to_string(),
+            postcode: "4000".to_string(),
+        }
+    }
+
+    /// The validator for an address in the section of the form at
+    /// `prefix`. Each of the fields is `REQUIRED`, and the `postcode`
+    /// must be 4 digits (`INVALID_POSTCODE`).
+    pub fn validator(prefix: FieldPath) -> FormValidator<Address, FieldPath> {
+        let postcode: Validator<String, FieldPath> = Validator::new()
+            .validation(validators::required())
+            .validation(|postcode: &String, key: &FieldPath| {
+                if postcode.len() == 4 && postcode.chars().all(|c| c.is_ascii_digit()) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::new(key.clone(), "INVALID_POSTCODE").into())
+                }
+            });
+
+        FormValidator::new()
+            .field(
+                prefix.clone().field("street"),
+                |address: &Address| &address.street,
+                validators::required(),
+            )
+            .field(
+                prefix.clone().field("city"),
+                |address: &Address| &address.city,
+                validators::required(),
+            )
+            .field(
+                prefix.field("postcode"),
+                |address: &Address| &address.postcode,
+                postcode,
+            )
+    }
+}
+
+/// An item being purchased in a [CheckoutForm].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineItem {
+    pub sku: String,
+    pub quantity: u32,
+}
+
+impl LineItem {
+    /// The synchronous validator for a line item: the `sku` is
+    /// `REQUIRED`, and the `quantity` must be between 1 and 99
+    /// (`OUT_OF_RANGE`).
+    pub fn validator() -> FormValidator<LineItem, FieldPath> {
+        FormValidator::new()
+            .field(
+                "sku".into(),
+                |item: &LineItem| &item.sku,
+                validators::required(),
+            )
+            .field(
+                "quantity".into(),
+                |item: &LineItem| &item.quantity,
+                validators::range(1..=99),
+            )
+    }
+}
+
+impl AsyncValidatable<FieldPath> for LineItem {
+    /// Checks that the `sku` is in stock (`OUT_OF_STOCK`).
+    fn validate_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<FieldPath>>>>> {
+        let sku = self.sku.clone();
+        Box::pin(async move {
+            if OUT_OF_STOCK_SKUS.contains(&sku.as_str()) {
+                Err(ValidationError::new(FieldPath::from("sku"), "OUT_OF_STOCK").into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+/// A checkout form, with nested address sections and a collection of
+/// line items.
+///
+/// Synchronous validation:
+///
+/// + `email` - `REQUIRED`, or `INVALID_EMAIL`.
+/// + `shipping.*` - see [Address::validator()].
+/// + `billing.*` - see [Address::validator()], only when
+///   `billing_same_as_shipping` is `false`.
+/// + `items` - `REQUIRED` if there are no items.
+/// + `items[i].*` - see [LineItem::validator()].
+///
+/// Asynchronous validation:
+///
+/// + `items[i].sku` - `OUT_OF_STOCK` if it is one of the
+///   [OUT_OF_STOCK_SKUS].
+///
+/// ## Example
+/// ```
+/// use form_validation::{fixtures::CheckoutForm, FieldPath, Validatable};
+///
+/// let mut form = CheckoutForm::valid();
+/// assert!(form.validate().is_ok());
+///
+/// form.items[0].quantity = 0;
+/// let errors = form.validate().unwrap_err();
+/// assert_eq!("items[0].quantity", errors.errors[0].key.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckoutForm {
+    pub email: String,
+    pub shipping: Address,
+    pub billing_same_as_shipping: bool,
+    pub billing: Address,
+    pub items: Vec<LineItem>,
+}
+
+impl CheckoutForm {
+    /// A form which passes all of its validation.
+    pub fn valid() -> Self {
+        Self {
+            email: "customer@example.com".to_string(),
+            shipping: Address::valid(),
+            billing_same_as_shipping: true,
+            billing: Address {
+                street: String::new(),
+                city: String::new(),
+                postcode: String::new(),
+            },
+            items: vec![LineItem {
+                sku: "SKU-001".to_string(),
+                quantity: 2,
+            }],
+        }
+    }
+
+    /// The keys of the fields in this form (excluding the line items,
+    /// which are dynamic), in the order they are displayed.
+    pub fn fields() -> Vec<FieldPath> {
+        let mut fields = vec![FieldPath::from("email")];
+        for section in &["shipping", "billing"] {
+            for field in &["street", "city", "postcode"] {
+                fields.push(FieldPath::new().field(*section).field(*field));
+            }
+        }
+        fields.push(FieldPath::from("items"));
+        fields
+    }
+
+    /// The synchronous validator for this form.
+    pub fn validator() -> FormValidator<CheckoutForm, FieldPath> {
+        let item = LineItem::validator();
+
+        FormValidator::new()
+            .field(
+                "email".into(),
+                |form: &CheckoutForm| &form.email,
+                Validator::new()
+                    .validation(validators::required())
+                    .validation(validators::email().skip_empty()),
+            )
+            .nested(
+                |form: &CheckoutForm| &form.shipping,
+                Address::validator("shipping".into()),
+            )
+            .nested_if(
+                |form: &CheckoutForm| !form.billing_same_as_shipping,
+                |form: &CheckoutForm| &form.billing,
+                Address::validator("billing".into()),
+            )
+            .field(
+                "items".into(),
+                |form: &CheckoutForm| &form.items,
+                validators::required(),
+            )
+            .rule(
+                "items".into(),
+                move |form: &CheckoutForm, key: &FieldPath| {
+                    let mut errors = ValidationErrors::default();
+                    for (index, line_item) in form.items.iter().enumerate() {
+                        let prefix = key.clone().index(index);
+                        errors.extend(
+                            item.validate(line_item)
+                                .err()
+                                .unwrap_or_default()
+                                .map_keys(|item_key| prefix.clone().join(item_key)),
+                        );
+                    }
+                    if errors.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(errors)
+                    }
+                },
+            )
+    }
+}
+
+impl Validatable<FieldPath> for CheckoutForm {
+    fn validate(&self) -> Result<(), ValidationErrors<FieldPath>> {
+        Self::validator().validate(self)
+    }
+}
+
+impl AsyncValidatable<FieldPath> for CheckoutForm {
+    fn validate_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<FieldPath>>>>> {
+        let items = self.items.clone();
+        Box::pin(async move {
+            validate_items_async(&items, 4, |index, key: &FieldPath| {
+                FieldPath::new().field("items").index(index).join(key)
+            })
+            .await
+        })
+    }
+}
@@ -0,0 +1,22 @@
+//! Reference implementations of realistic forms, with known-good
+//! validation behaviour, exercising synchronous, asynchronous, nested
+//! and collection validation. These are used by this crate's own
+//! integration tests, and can be used by downstream crates (for
+//! example user interface framework integrations) to test their glue
+//! code against.
+//!
+//! The forms use [FieldPath](crate::FieldPath) keys. Each form has a
+//! `valid()` constructor for an instance which passes all of its
+//! validation, and a `fields()` function listing the keys of its
+//! fields in the order they are displayed.
+//!
+//! The source for this module lives in `examples/fixtures`, see also
+//! the `signup` example.
+
+mod checkout;
+mod settings;
+mod signup;
+
+pub use checkout::*;
+pub use settings::*;
+pub use signup::*;
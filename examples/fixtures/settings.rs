@@ -0,0 +1,103 @@
+use crate::{
+    validate_entries, validators, FieldPath, FormValidator, Validatable, ValidationErrors,
+    Validator,
+};
+use std::collections::BTreeMap;
+
+/// The themes which can be selected in a [SettingsForm].
+pub const THEMES: &[&str] = &["light", "dark", "system"];
+
+/// A form for the settings of an account, with optional fields and a
+/// map of per-locale values.
+///
+/// Synchronous validation:
+///
+/// + `display_name` - `REQUIRED`, or `LENGTH_OUT_OF_RANGE` (up to 32
+///   characters).
+/// + `notification_email` - `INVALID_EMAIL`, only when it is
+///   provided.
+/// + `theme` - `NOT_ONE_OF` the [THEMES].
+/// + `signatures.<locale>` - `LENGTH_OUT_OF_RANGE` (up to 200
+///   characters) for each entry.
+///
+/// ## Example
+/// ```
+/// use form_validation::{fixtures::SettingsForm, Validatable};
+///
+/// let mut form = SettingsForm::valid();
+/// assert!(form.validate().is_ok());
+///
+/// form.signatures.insert("de".to_string(), "x".repeat(201));
+/// let errors = form.validate().unwrap_err();
+/// assert_eq!("signatures.de", errors.errors[0].key.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsForm {
+    pub display_name: String,
+    pub notification_email: Option<String>,
+    pub theme: String,
+    pub signatures: BTreeMap<String, String>,
+}
+
+impl SettingsForm {
+    /// A form which passes all of its validation.
+    pub fn valid() -> Self {
+        let mut signatures = BTreeMap::new();
+        signatures.insert("en".to_string(), "Kind regards".to_string());
+
+        Self {
+            display_name: "Alice".to_string(),
+            notification_email: None,
+            theme: "system".to_string(),
+            signatures,
+        }
+    }
+
+    /// The keys of the fields in this form (excluding the signatures,
+    /// which are dynamic), in the order they are displayed.
+    pub fn fields() -> Vec<FieldPath> {
+        ["display_name", "notification_email", "theme", "signatures"]
+            .iter()
+            .map(|field| FieldPath::from(*field))
+            .collect()
+    }
+
+    /// The synchronous validator for this form.
+    pub fn validator() -> FormValidator<SettingsForm, FieldPath> {
+        let signature: Validator<String, (String, FieldPath)> =
+            Validator::new().validation(validators::string_length(0, 200));
+
+        FormValidator::new()
+            .field(
+                "display_name".into(),
+                |form: &SettingsForm| &form.display_name,
+                Validator::new()
+                    .validation(validators::required())
+                    .validation(validators::string_length(1, 32).skip_empty()),
+            )
+            .field(
+                "notification_email".into(),
+                |form: &SettingsForm| &form.notification_email,
+                validators::email::<String, FieldPath>().optional(),
+            )
+            .field(
+                "theme".into(),
+                |form: &SettingsForm| &form.theme,
+                validators::one_of(THEMES.iter().map(|theme| theme.to_string()).collect()),
+            )
+            .rule(
+                "signatures".into(),
+                move |form: &SettingsForm, key: &FieldPath| {
+                    validate_entries(&form.signatures, key, &signature).map_err(|errors| {
+                        errors.map_keys(|(locale, key)| key.clone().field(locale.clone()))
+                    })
+                },
+            )
+    }
+}
+
+impl Validatable<FieldPath> for SettingsForm {
+    fn validate(&self) -> Result<(), ValidationErrors<FieldPath>> {
+        Self::validator().validate(self)
+    }
+}
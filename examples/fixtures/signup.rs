@@ -0,0 +1,146 @@
+use crate::{
+    validators, AsyncValidatable, FieldPath, FormValidator, Validatable, ValidationError,
+    ValidationErrors, Validator, ValidatorFn,
+};
+use std::{future::Future, pin::Pin};
+
+/// Usernames which are already registered, checked by the
+/// asynchronous validation of a [SignupForm].
+pub const TAKEN_USERNAMES: &[&str] = &["admin", "alice"];
+
+/// A sign up form for a new account.
+///
+/// Synchronous validation:
+///
+/// + `username` - `REQUIRED`, `INVALID_IDENTIFIER`, or
+///   `LENGTH_OUT_OF_RANGE` (3 to 16 characters).
+/// + `email` - `REQUIRED`, or `INVALID_EMAIL`.
+/// + `password` - `LENGTH_OUT_OF_RANGE` (8 to 64 characters).
+/// + `confirm_password` - `FIELDS_DO_NOT_MATCH`.
+/// + `accept_terms` - `TERMS_NOT_ACCEPTED`.
+///
+/// Asynchronous validation:
+///
+/// + `username` - `USERNAME_TAKEN` if it is one of the
+///   [TAKEN_USERNAMES].
+///
+/// ## Example
+/// ```
+/// use form_validation::{fixtures::SignupForm, FieldPath, Validatable};
+///
+/// assert!(SignupForm::valid().validate().is_ok());
+///
+/// let form = SignupForm {
+///     confirm_password: "something else".to_string(),
+///     ..SignupForm::valid()
+/// };
+/// let errors = form.validate().unwrap_err();
+/// assert_eq!(FieldPath::from("confirm_password"), errors.errors[0].key);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignupForm {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub confirm_password: String,
+    pub accept_terms: bool,
+}
+
+impl SignupForm {
+    /// A form which passes all of its validation.
+    pub fn valid() -> Self {
+        Self {
+            username: "new_user".to_string(),
+            email: "new_user@example.com".to_string(),
+            password: "correct horse battery staple".to_string(),
+            confirm_password: "correct horse battery staple".to_string(),
+            accept_terms: true,
+        }
+    }
+
+    /// The keys of the fields in this form, in the order they are
+    /// displayed.
+    pub fn fields() -> Vec<FieldPath> {
+        [
+            "username",
+            "email",
+            "password",
+            "confirm_password",
+            "accept_terms",
+        ]
+        .iter()
+        .map(|field| FieldPath::from(*field))
+        .collect()
+    }
+
+    /// The synchronous validator for this form.
+    pub fn validator() -> FormValidator<SignupForm, FieldPath> {
+        let username: ValidatorFn<String, FieldPath> = validators::identifier();
+
+        FormValidator::new()
+            .field(
+                "username".into(),
+                |form: &SignupForm| &form.username,
+                Validator::new()
+                    .validation(validators::required())
+                    .validation(username.skip_empty())
+                    .validation(validators::string_length(3, 16).skip_empty()),
+            )
+            .field(
+                "email".into(),
+                |form: &SignupForm| &form.email,
+                Validator::new()
+                    .validation(validators::required())
+                    .validation(validators::email().skip_empty()),
+            )
+            .field(
+                "password".into(),
+                |form: &SignupForm| &form.password,
+                validators::string_length(8, 64),
+            )
+            .rule(
+                "confirm_password".into(),
+                validators::equals_field(
+                    |form: &SignupForm| &form.password,
+                    |form: &SignupForm| &form.confirm_password,
+                ),
+            )
+            .rule(
+                "accept_terms".into(),
+                |form: &SignupForm, key: &FieldPath| {
+                    if form.accept_terms {
+                        Ok(())
+                    } else {
+                        Err(ValidationError::new(key.clone(), "TERMS_NOT_ACCEPTED")
+                            .message("You must accept the terms and conditions")
+                            .into())
+                    }
+                },
+            )
+    }
+}
+
+impl Validatable<FieldPath> for SignupForm {
+    fn validate(&self) -> Result<(), ValidationErrors<FieldPath>> {
+        Self::validator().validate(self)
+    }
+}
+
+impl AsyncValidatable<FieldPath> for SignupForm {
+    fn validate_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<FieldPath>>>>> {
+        let username = self.username.clone();
+        Box::pin(async move {
+            if TAKEN_USERNAMES.contains(&username.as_str()) {
+                Err(
+                    ValidationError::new(FieldPath::from("username"), "USERNAME_TAKEN")
+                        .message("This username is already taken")
+                        .into(),
+                )
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
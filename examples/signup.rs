@@ -0,0 +1,27 @@
+//! Validates a sign up form from the `fixtures` module, printing the
+//! errors which are displayed as the user fills it in.
+//!
+//! Run with `cargo run --example signup --features fixtures`.
+
+use form_validation::{fixtures::SignupForm, FormState};
+use futures::executor::block_on;
+use std::cell::RefCell;
+
+fn main() {
+    let form = SignupForm {
+        username: "alice".to_string(),
+        email: "alice.example.com".to_string(),
+        password: "hunter2".to_string(),
+        confirm_password: "hunter3".to_string(),
+        accept_terms: false,
+    };
+
+    let state = RefCell::new(FormState::new(form, SignupForm::fields()));
+
+    let result = block_on(FormState::submit_with(&state, |_form| async { Ok(()) }));
+
+    match result {
+        Ok(()) => println!("Submitted"),
+        Err(error) => println!("{}", error),
+    }
+}
@@ -0,0 +1,31 @@
+//! A minimal library using the built-in validators, used by
+//! `wasm-size.sh` to measure the size they contribute to a
+//! `wasm32-unknown-unknown` binary.
+
+use form_validation::{validators, Validation, ValidatorFn};
+
+/// Validate the string in the specified buffer, returning the total
+/// length of the error messages.
+///
+/// # Safety
+///
+/// `ptr` must point to a buffer of at least `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn validate(ptr: *const u8, len: usize) -> usize {
+    let input = std::slice::from_raw_parts(ptr, len);
+    let value = String::from_utf8_lossy(input).into_owned();
+
+    let validations: Vec<ValidatorFn<String, &str>> = vec![
+        validators::email(),
+        validators::iban(),
+        validators::ip_address(),
+        validators::iso_date(),
+        validators::postal_code("GB"),
+    ];
+
+    validations
+        .iter()
+        .filter_map(|validation| validation.validate_value(&value, &"field").err())
+        .map(|errors| errors.to_string().len())
+        .sum()
+}
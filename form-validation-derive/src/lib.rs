@@ -0,0 +1,1062 @@
+//! Derive and attribute macros for the
+//! [form-validation](https://crates.io/crates/form-validation) crate.
+//! These are re-exported by `form-validation` when its `"derive"`
+//! feature is enabled, and should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Expr, Fields,
+    FnArg, GenericParam, Ident, ItemFn, LitStr, Path, Token, Type, WherePredicate,
+};
+
+/// Derive an implementation of `Validatable<Key>` for a struct with
+/// named fields, for any `Key` which implements `From<&'static str>`
+/// (such as `&'static str`, `String` or `FieldPath`), or only for the
+/// key type specified using a `#[validate(key = "Type")]` attribute on
+/// the struct (which is required when using custom validation
+/// functions for a specific key type), or for the enum generated by
+/// [FormFields](derive@FormFields) specified using a
+/// `#[validate(fields = "Type")]` attribute. Each field is
+/// validated using the validators listed in its `#[validate(...)]`
+/// attributes, using the name of the field as its key, and the
+/// results are joined using `concat_results()`. Fields without a
+/// `#[validate(...)]` attribute are not validated.
+///
+/// The following validators are supported:
+///
+/// + `length(min = 3, max = 20)` - `validators::string_length()`,
+///   where `min` defaults to `0` and `max` defaults to no maximum.
+/// + `range(min = 1, max = 10)` - `validators::range()`.
+/// + `with = "path::to::function"` - a custom validation function,
+///   with the signature `fn(&Value, &Key) -> Result<(),
+///   ValidationErrors<Key>>`.
+/// + `nested` - validate a field containing a nested form, which
+///   implements `Validatable<Key>`, prefixing the keys of its errors
+///   with the key of the field using `NestedKey` (for example
+///   `address.street`). This requires a key which implements
+///   `NestedKey`, such as `FieldPath` or `String`.
+/// + Any other built-in validator, using the name of its constructor
+///   in the `validators` module, followed by its arguments if it has
+///   any, for example `email`, `identifier`, `multiple_of(6)` or
+///   `starts_with("https://")`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{Validatable, ValidationErrors};
+///
+/// fn not_admin(value: &String, key: &&'static str) -> Result<(), ValidationErrors<&'static str>> {
+///     if value == "admin" {
+///         Err(form_validation::ValidationError::new(*key, "RESERVED").into())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Validatable)]
+/// #[validate(key = "&'static str")]
+/// struct Signup {
+///     #[validate(length(min = 3, max = 20), identifier, with = "not_admin")]
+///     username: String,
+///     #[validate(email)]
+///     email: String,
+///     #[validate(range(min = 13, max = 150))]
+///     age: u32,
+///     newsletter: bool,
+/// }
+///
+/// let signup = Signup {
+///     username: "admin".to_string(),
+///     email: "not an email".to_string(),
+///     age: 12,
+///     newsletter: false,
+/// };
+///
+/// let errors = signup.validate().unwrap_err();
+/// assert_eq!("RESERVED", errors.get(&"username").unwrap().errors[0].type_id);
+/// assert_eq!("INVALID_EMAIL", errors.get(&"email").unwrap().errors[0].type_id);
+/// assert_eq!("OUT_OF_RANGE", errors.get(&"age").unwrap().errors[0].type_id);
+/// ```
+///
+/// ## Nested Forms
+/// ```
+/// use form_validation::{FieldPath, Validatable};
+///
+/// #[derive(Validatable)]
+/// struct Address {
+///     #[validate(required)]
+///     street: String,
+/// }
+///
+/// #[derive(Validatable)]
+/// struct Order {
+///     #[validate(nested)]
+///     shipping: Address,
+/// }
+///
+/// let order = Order {
+///     shipping: Address {
+///         street: String::new(),
+///     },
+/// };
+///
+/// let errors: form_validation::ValidationErrors<FieldPath> = order.validate().unwrap_err();
+/// assert_eq!("shipping.street", errors.errors[0].key.to_string());
+/// ```
+#[proc_macro_derive(Validatable, attributes(validate))]
+pub fn derive_validatable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    validatable(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive an enum with a variant for each field of a struct with
+/// named fields, for use as a strongly typed key for its errors
+/// instead of a string. The enum is called `{Struct}Field` (which can
+/// be changed using a `#[form_fields(name = "Name")]` attribute on the
+/// struct), has the same visibility as the struct, and its variants
+/// are the names of the fields converted to `PascalCase`. It
+/// implements `FormField`, `Display` and `FromStr` (using the names of
+/// the fields), and can be converted into a `&'static str` or a
+/// `FieldPath`.
+///
+/// Use a `#[validate(fields = "{Struct}Field")]` attribute on the
+/// struct to use the enum as the key for the errors produced by
+/// [Validatable](derive@Validatable) and
+/// [AsyncValidatable](derive@AsyncValidatable).
+///
+/// ## Example
+/// ```
+/// use form_validation::{FormField, FormFields, Validatable};
+///
+/// #[derive(FormFields, Validatable)]
+/// #[validate(fields = "SignupField")]
+/// struct Signup {
+///     #[validate(length(min = 3, max = 20))]
+///     username: String,
+///     #[validate(email)]
+///     email_address: String,
+/// }
+///
+/// let signup = Signup {
+///     username: "al".to_string(),
+///     email_address: "al@example.com".to_string(),
+/// };
+///
+/// let errors = signup.validate().unwrap_err();
+/// assert!(errors.get(&SignupField::Username).is_some());
+/// assert!(errors.get(&SignupField::EmailAddress).is_none());
+///
+/// assert_eq!("email_address", SignupField::EmailAddress.to_string());
+/// assert_eq!(Ok(SignupField::Username), "username".parse());
+/// assert!("password".parse::<SignupField>().is_err());
+/// assert_eq!(2, SignupField::ALL.len());
+/// ```
+#[proc_macro_derive(FormFields, attributes(form_fields))]
+pub fn derive_form_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    form_fields(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn form_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let vis = &input.vis;
+    let ident = &input.ident;
+
+    let mut enum_ident = Ident::new(&format!("{}Field", ident), ident.span());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("form_fields"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                enum_ident = name.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown attribute, expected `name`"))
+            }
+        })?;
+    }
+
+    let field_idents: Vec<&Ident> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect("named field"))
+        .collect();
+    let variants: Vec<Ident> = field_idents
+        .iter()
+        .map(|field| variant_ident(field))
+        .collect();
+    let names: Vec<LitStr> = field_idents
+        .iter()
+        .map(|field| LitStr::new(&field_name(field), field.span()))
+        .collect();
+    let enum_doc = format!("The fields of [{}].", ident);
+    let variant_docs: Vec<String> = names
+        .iter()
+        .map(|name| format!("The `{}` field.", name.value()))
+        .collect();
+
+    Ok(quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #vis enum #enum_ident {
+            #(
+                #[doc = #variant_docs]
+                #variants,
+            )*
+        }
+
+        impl ::form_validation::FormField for #enum_ident {
+            const ALL: &'static [Self] = &[#(#enum_ident::#variants),*];
+
+            fn name(&self) -> &'static str {
+                match *self {
+                    #(#enum_ident::#variants => #names,)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #enum_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::form_validation::FormField::name(self))
+            }
+        }
+
+        impl ::std::str::FromStr for #enum_ident {
+            type Err = ::form_validation::UnknownField;
+
+            fn from_str(name: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as ::form_validation::FormField>::from_name(name).ok_or_else(|| {
+                    ::form_validation::UnknownField {
+                        name: ::std::string::ToString::to_string(name),
+                    }
+                })
+            }
+        }
+
+        impl ::std::convert::From<#enum_ident> for &'static str {
+            fn from(field: #enum_ident) -> Self {
+                ::form_validation::FormField::name(&field)
+            }
+        }
+
+        impl ::std::convert::From<#enum_ident> for ::form_validation::FieldPath {
+            fn from(field: #enum_ident) -> Self {
+                ::form_validation::FieldPath::from(::form_validation::FormField::name(&field))
+            }
+        }
+    })
+}
+
+/// Derive an implementation of `AsyncValidatable<Key>` for a struct
+/// with named fields (requires the `"async"` feature of
+/// `form-validation`). Each field is validated using both the
+/// synchronous validators listed in its `#[validate(...)]` attributes
+/// (see [Validatable](derive@Validatable)), and the asynchronous
+/// validation functions listed in its `#[validate_async(with =
+/// "path::to::function")]` attributes, which have the same signature
+/// as the closure taken by `AsyncValidatorFn::new()`. Fields with a
+/// `#[validate(nested)]` attribute are validated using their
+/// `AsyncValidatable<Key>` implementation. The validation
+/// of all the fields is performed concurrently, and the errors are
+/// reported in the order of the fields. The key type is chosen in the
+/// same way as for [Validatable](derive@Validatable), and the types of
+/// the validated fields need to implement `Clone` and `PartialEq`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{AsyncValidatable, Validatable, ValidationError, ValidationErrors};
+/// use futures::executor::block_on;
+/// use std::{future::Future, pin::Pin};
+///
+/// fn check_username_free(
+///     value: &String,
+///     key: &&'static str,
+/// ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+///     let taken = value == "alice";
+///     let key = *key;
+///     Box::pin(async move {
+///         if taken {
+///             Err(ValidationError::new(key, "USERNAME_TAKEN").into())
+///         } else {
+///             Ok(())
+///         }
+///     })
+/// }
+///
+/// #[derive(Validatable, AsyncValidatable)]
+/// #[validate(key = "&'static str")]
+/// struct Signup {
+///     #[validate(length(min = 3, max = 20))]
+///     #[validate_async(with = "check_username_free")]
+///     username: String,
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// let signup = Signup {
+///     username: "alice".to_string(),
+///     email: "alice".to_string(),
+/// };
+///
+/// // only the synchronous validation
+/// assert_eq!(1, signup.validate().unwrap_err().len());
+///
+/// let errors = block_on(signup.validate_future()).unwrap_err();
+/// assert_eq!("USERNAME_TAKEN", errors.errors[0].type_id);
+/// assert_eq!("INVALID_EMAIL", errors.errors[1].type_id);
+/// ```
+#[proc_macro_derive(AsyncValidatable, attributes(validate, validate_async))]
+pub fn derive_async_validatable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    async_validatable(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn async_validatable(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let ident = &input.ident;
+    let key_config = KeyConfig::parse(input)?;
+    let key = key_config.ty();
+    let future_ty = quote! {
+        ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<
+            Output = ::std::result::Result<(), ::form_validation::ValidationErrors<#key>>,
+        >>>
+    };
+
+    let mut checks = Vec::new();
+    let mut nested_fields = Vec::new();
+    for field in fields {
+        let (validations, nested) = field_validations(field)?;
+        let async_validations = field_async_validations(field)?;
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_key = key_config.field_key(field_ident);
+        let ty = &field.ty;
+        if !validations.is_empty() || !async_validations.is_empty() {
+            checks.push(quote! {
+                {
+                    let validator: ::form_validation::AsyncValidator<#ty, #key> =
+                        ::form_validation::AsyncValidator::new()
+                            #(.validation(::form_validation::ValidatorFn::<#ty, #key>::from(#validations)))*
+                            #(.validation(::form_validation::AsyncValidatorFn::<#ty, #key>::new(#async_validations)))*;
+                    let value: #ty = ::std::clone::Clone::clone(&self.#field_ident);
+                    let future: #future_ty = ::std::boxed::Box::pin(async move {
+                        validator
+                            .validate_value(
+                                &value,
+                                &#field_key,
+                            )
+                            .await
+                    });
+                    future
+                }
+            });
+        }
+        if nested {
+            nested_fields.push(field);
+            checks.push(quote! {
+                {
+                    let future = ::form_validation::AsyncValidatable::<#key>::validate_future(
+                        &self.#field_ident,
+                    );
+                    let prefix: #key = #field_key;
+                    let future: #future_ty = ::std::boxed::Box::pin(async move {
+                        future.await.map_err(|errors| {
+                            errors.map_keys(|key| ::form_validation::NestedKey::nested(&prefix, key))
+                        })
+                    });
+                    future
+                }
+            });
+        }
+    }
+
+    let predicates = key_config
+        .nested_predicates(quote!(::form_validation::AsyncValidatable), &nested_fields)?;
+    let (impl_generics, ty_generics, where_clause) =
+        split_generics(input, key_config.is_generic(), predicates);
+
+    Ok(quote! {
+        impl #impl_generics ::form_validation::AsyncValidatable<#key> for #ident #ty_generics
+        #where_clause
+        {
+            fn validate_future(
+                &self,
+            ) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<
+                Output = ::std::result::Result<(), ::form_validation::ValidationErrors<#key>>,
+            >>> {
+                let futures = ::std::vec![#(#checks),*];
+                ::std::boxed::Box::pin(async move {
+                    ::form_validation::concat_results(
+                        ::form_validation::__private::join_all(futures).await,
+                    )
+                })
+            }
+        }
+    })
+}
+
+/// Turn a validation function with the signature `fn(&Value, &Key) ->
+/// Result<(), ValidationErrors<Key>>` into a function without
+/// arguments which returns a `ValidatorFn<Value, Key>`, so that
+/// validators can be defined as named top-level functions and reused
+/// across forms. The function can be generic, in which case its type
+/// parameters are required to be `'static`.
+///
+/// The id of the `ValidatorFn` is the path of the function (for
+/// example `"my_app::forms::not_negative"`), which can be changed
+/// using `#[validator(id = "not_negative")]`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validator, Validation, ValidationError, ValidationErrors, ValidatorFn};
+///
+/// /// Checks that the value is not negative.
+/// #[validator]
+/// fn not_negative(value: &i32, key: &String) -> Result<(), ValidationErrors<String>> {
+///     if *value < 0 {
+///         Err(ValidationError::new(key.clone(), "NEGATIVE").into())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[validator(id = "even")]
+/// fn even<Key: Clone + PartialEq>(value: &u32, key: &Key) -> Result<(), ValidationErrors<Key>> {
+///     if value % 2 == 1 {
+///         Err(ValidationError::new(key.clone(), "ODD").into())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// let v: ValidatorFn<i32, String> = not_negative();
+/// assert!(v.validate_value(&1, &"count".to_string()).is_ok());
+/// assert!(v.validate_value(&-1, &"count".to_string()).is_err());
+///
+/// let v: ValidatorFn<u32, &str> = even();
+/// assert_eq!("even", v.id().to_string());
+/// assert!(v.validate_value(&3, &"count").is_err());
+/// ```
+#[proc_macro_attribute]
+pub fn validator(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut id = None;
+    let args_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("id") {
+            id = Some(meta.value()?.parse::<LitStr>()?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown attribute, expected `id`"))
+        }
+    });
+    parse_macro_input!(args with args_parser);
+    let function = parse_macro_input!(input as ItemFn);
+    validator_function(id, function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn validator_function(id: Option<LitStr>, function: ItemFn) -> syn::Result<TokenStream2> {
+    let sig = &function.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "#[validator] does not support async functions",
+        ));
+    }
+    if let Some(lifetime) = sig.generics.lifetimes().next() {
+        return Err(syn::Error::new(
+            lifetime.span(),
+            "#[validator] does not support lifetime parameters",
+        ));
+    }
+
+    let mut arg_types = Vec::new();
+    for arg in &sig.inputs {
+        match arg {
+            FnArg::Typed(arg) => match &*arg.ty {
+                Type::Reference(reference) if reference.mutability.is_none() => {
+                    arg_types.push(&reference.elem)
+                }
+                ty => return Err(syn::Error::new(ty.span(), "expected a shared reference")),
+            },
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new(
+                    receiver.span(),
+                    "#[validator] does not support methods",
+                ))
+            }
+        }
+    }
+    let (value, key) = match arg_types.as_slice() {
+        [value, key] => (value, key),
+        _ => {
+            return Err(syn::Error::new(
+                sig.inputs.span(),
+                "expected the arguments `(value: &Value, key: &Key)`",
+            ))
+        }
+    };
+
+    let ident = &sig.ident;
+    let id = match id {
+        Some(id) => quote!(#id),
+        None => quote!(::std::concat!(
+            ::std::module_path!(),
+            "::",
+            ::std::stringify!(#ident)
+        )),
+    };
+
+    let mut generics = sig.generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(syn::parse_quote!('static));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = sig.generics.split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
+    let attrs = &function.attrs;
+    let vis = &function.vis;
+    let constness = &sig.constness;
+    let unsafety = &sig.unsafety;
+    let mut inner = function.clone();
+    // the signature is dictated by `ValidatorFn`, so `&String` can't be
+    // replaced with `&str`.
+    inner.attrs = vec![syn::parse_quote!(#[allow(clippy::ptr_arg)])];
+    inner.vis = syn::Visibility::Inherited;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #constness #unsafety fn #ident #impl_generics()
+            -> ::form_validation::ValidatorFn<#value, #key>
+        #where_clause
+        {
+            #inner
+            ::form_validation::ValidatorFn::with_id(#id, #ident #turbofish)
+        }
+    })
+}
+
+/// Build a `Validator` for values of the specified type from a string
+/// containing a small rule language, which is parsed at compile time,
+/// for very terse form definitions. The rules are built-in validators
+/// separated by `&&`, and are validated in order. Each rule is the
+/// name of a constructor in the `validators` module, followed by its
+/// arguments if it has any, in the same way as for the
+/// `#[validate(...)]` attributes of [Validatable](derive@Validatable),
+/// with the following additions:
+///
+/// + `len(3..=20)` - `validators::string_length()` with the length
+///   range, where either bound may be omitted (for example `len(3..)`).
+/// + `range(1..=10)` - `validators::range()` with the range.
+/// + `pattern("^[a-z]+$")` - `validators::pattern()` (requires the
+///   `"regex"` feature of `form-validation`), which panics when the
+///   validator is built if the regular expression is invalid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{rules, Validation, Validator};
+///
+/// let v: Validator<String, &str> = rules!(String, "len(3..=20) && pattern(\"^[a-z]+$\")");
+/// assert!(v.validate_value(&"alice".to_string(), &"username").is_ok());
+///
+/// let errors = v.validate_value(&"Al".to_string(), &"username").unwrap_err();
+/// assert_eq!(2, errors.len());
+///
+/// let v: Validator<u32, &str> = rules!(u32, "range(1..=10) && multiple_of(2)");
+/// assert!(v.validate_value(&3, &"seats").is_err());
+/// ```
+///
+/// Rules which can't be parsed fail to compile:
+///
+/// ```compile_fail
+/// use form_validation::{rules, Validator};
+///
+/// let v: Validator<String, &str> = rules!(String, "alphanumeric || email");
+/// ```
+#[proc_macro]
+pub fn rules(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RulesInput);
+    rules_validator(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The input of the [rules!] macro.
+struct RulesInput {
+    ty: Type,
+    rules: LitStr,
+}
+
+impl syn::parse::Parse for RulesInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let rules = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, rules })
+    }
+}
+
+fn rules_validator(input: &RulesInput) -> syn::Result<TokenStream2> {
+    let expr: Expr = input.rules.parse()?;
+    let mut rules = Vec::new();
+    collect_rules(expr, &mut rules)?;
+    let validations = rules
+        .into_iter()
+        .map(rule_validation)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let ty = &input.ty;
+    Ok(quote! {
+        ::form_validation::Validator::<#ty, _>::new() #(.validation(#validations))*
+    })
+}
+
+/// Flatten the rules joined by `&&` in `expr` into `rules`.
+fn collect_rules(expr: Expr, rules: &mut Vec<Expr>) -> syn::Result<()> {
+    match expr {
+        Expr::Binary(binary) => match binary.op {
+            syn::BinOp::And(_) => {
+                collect_rules(*binary.left, rules)?;
+                collect_rules(*binary.right, rules)
+            }
+            op => Err(syn::Error::new(
+                op.span(),
+                "rules can only be combined using `&&`",
+            )),
+        },
+        Expr::Paren(paren) => collect_rules(*paren.expr, rules),
+        expr => {
+            rules.push(expr);
+            Ok(())
+        }
+    }
+}
+
+/// The expression constructing the validator for a single rule.
+fn rule_validation(rule: Expr) -> syn::Result<TokenStream2> {
+    let (name, args): (Ident, Vec<Expr>) = match rule {
+        Expr::Path(path) => (path.path.require_ident()?.clone(), Vec::new()),
+        Expr::Call(call) => match *call.func {
+            Expr::Path(path) => (
+                path.path.require_ident()?.clone(),
+                call.args.into_iter().collect(),
+            ),
+            func => return Err(syn::Error::new(func.span(), "expected the name of a rule")),
+        },
+        rule => {
+            return Err(syn::Error::new(
+                rule.span(),
+                "expected a rule, such as `email` or `len(3..=20)`",
+            ))
+        }
+    };
+
+    let span = name.span();
+    match (name.to_string().as_str(), args.as_slice()) {
+        ("len", [Expr::Range(range)]) => {
+            let min = match &range.start {
+                Some(start) => quote!(#start),
+                None => quote!(0),
+            };
+            let max = match (&range.end, &range.limits) {
+                (Some(end), syn::RangeLimits::Closed(_)) => quote!(#end),
+                (Some(end), syn::RangeLimits::HalfOpen(_)) => quote!((#end) - 1),
+                (None, _) => quote!(::std::usize::MAX),
+            };
+            Ok(quote_spanned!(span=> ::form_validation::validators::string_length(#min, #max)))
+        }
+        ("len", _) => Err(syn::Error::new(
+            span,
+            "expected a range of lengths, for example `len(3..=20)`",
+        )),
+        ("range", [range @ Expr::Range(_)]) => {
+            Ok(quote_spanned!(span=> ::form_validation::validators::range(#range)))
+        }
+        ("pattern", [regex]) => Ok(quote_spanned!(span=>
+            ::form_validation::validators::pattern(#regex)
+                .expect("invalid regular expression in rules!")
+        )),
+        _ => validation(&name, args),
+    }
+}
+
+fn validatable(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let ident = &input.ident;
+    let key_config = KeyConfig::parse(input)?;
+    let key = key_config.ty();
+
+    let mut checks = Vec::new();
+    let mut nested_fields = Vec::new();
+    for field in fields {
+        let (validations, nested) = field_validations(field)?;
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_key = key_config.field_key(field_ident);
+        let ty = &field.ty;
+        if !validations.is_empty() {
+            checks.push(quote! {
+                {
+                    let validator: ::form_validation::Validator<#ty, #key> =
+                        ::form_validation::Validator::new() #(.validation(#validations))*;
+                    ::form_validation::Validation::validate_value(
+                        &validator,
+                        &self.#field_ident,
+                        &#field_key,
+                    )
+                }
+            });
+        }
+        if nested {
+            nested_fields.push(field);
+            checks.push(quote! {
+                ::form_validation::Validatable::<#key>::validate(&self.#field_ident).map_err(
+                    |errors| {
+                        let prefix: #key = #field_key;
+                        errors.map_keys(|key| ::form_validation::NestedKey::nested(&prefix, key))
+                    },
+                )
+            });
+        }
+    }
+
+    let predicates =
+        key_config.nested_predicates(quote!(::form_validation::Validatable), &nested_fields)?;
+    let (impl_generics, ty_generics, where_clause) =
+        split_generics(input, key_config.is_generic(), predicates);
+
+    Ok(quote! {
+        impl #impl_generics ::form_validation::Validatable<#key> for #ident #ty_generics
+        #where_clause
+        {
+            fn validate(&self) -> ::std::result::Result<(), ::form_validation::ValidationErrors<#key>> {
+                ::form_validation::concat_results(::std::vec![#(#checks),*])
+            }
+        }
+    })
+}
+
+/// The named fields of the struct being derived.
+fn named_fields(input: &DeriveInput) -> syn::Result<&Punctuated<syn::Field, Token![,]>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(&fields.named),
+            _ => Err(syn::Error::new(
+                input.ident.span(),
+                "Validatable can only be derived for structs with named fields",
+            )),
+        },
+        _ => Err(syn::Error::new(
+            input.ident.span(),
+            "Validatable can only be derived for structs",
+        )),
+    }
+}
+
+/// How the keys of the errors are created, configured using a
+/// `#[validate(key = "Type")]` or `#[validate(fields = "Type")]`
+/// attribute on the struct.
+enum KeyConfig {
+    /// Generic over any key which implements `From<&'static str>`.
+    Generic,
+    /// The specified key type, which implements `From<&'static str>`.
+    Type(syn::Type),
+    /// The specified enum generated by `#[derive(FormFields)]`.
+    Fields(syn::Type),
+}
+
+impl KeyConfig {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut config = KeyConfig::Generic;
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("validate"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let is_fields = meta.path.is_ident("fields");
+                if !is_fields && !meta.path.is_ident("key") {
+                    return Err(meta.error("unknown attribute, expected `key` or `fields`"));
+                }
+                if !matches!(config, KeyConfig::Generic) {
+                    return Err(meta.error("only one of `key` or `fields` may be specified"));
+                }
+                let ty: LitStr = meta.value()?.parse()?;
+                let ty = ty.parse()?;
+                config = if is_fields {
+                    KeyConfig::Fields(ty)
+                } else {
+                    KeyConfig::Type(ty)
+                };
+                Ok(())
+            })?;
+        }
+        Ok(config)
+    }
+
+    fn is_generic(&self) -> bool {
+        matches!(self, KeyConfig::Generic)
+    }
+
+    /// The key type.
+    fn ty(&self) -> syn::Type {
+        match self {
+            KeyConfig::Generic => syn::parse_quote!(__Key),
+            KeyConfig::Type(ty) | KeyConfig::Fields(ty) => ty.clone(),
+        }
+    }
+
+    /// The where clause predicates required to validate the `nested`
+    /// field types using the `trait_path` (`Validatable` or
+    /// `AsyncValidatable`), and to prefix the keys of their errors.
+    fn nested_predicates(
+        &self,
+        trait_path: TokenStream2,
+        nested: &[&syn::Field],
+    ) -> syn::Result<Vec<WherePredicate>> {
+        let first = match nested.first() {
+            Some(first) => first,
+            None => return Ok(Vec::new()),
+        };
+        if let KeyConfig::Fields(_) = self {
+            return Err(syn::Error::new(
+                first.span(),
+                "nested fields are not supported with `fields` keys, \
+                 use a key which implements `NestedKey`, such as `FieldPath`",
+            ));
+        }
+        let key = self.ty();
+        let mut predicates: Vec<WherePredicate> = vec![
+            syn::parse_quote!(#key: ::form_validation::NestedKey + ::form_validation::MaybeSendSync),
+        ];
+        for field in nested {
+            let ty = &field.ty;
+            predicates.push(syn::parse_quote!(#ty: #trait_path<#key>));
+        }
+        Ok(predicates)
+    }
+
+    /// The expression creating the key for the field.
+    fn field_key(&self, field: &Ident) -> TokenStream2 {
+        match self {
+            KeyConfig::Fields(ty) => {
+                let variant = variant_ident(field);
+                quote!(<#ty>::#variant)
+            }
+            _ => {
+                let ty = self.ty();
+                let name = LitStr::new(&field_name(field), field.span());
+                quote!(<#ty as ::std::convert::From<&'static str>>::from(#name))
+            }
+        }
+    }
+}
+
+/// The name of a field, without the `r#` prefix of a raw identifier.
+fn field_name(field: &Ident) -> String {
+    let name = field.to_string();
+    name.strip_prefix("r#").map(str::to_string).unwrap_or(name)
+}
+
+/// The name of the variant of the enum generated by
+/// `#[derive(FormFields)]` for a field, converting its name from
+/// `snake_case` to `PascalCase`.
+fn variant_ident(field: &Ident) -> Ident {
+    let name: String = field_name(field)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    Ident::new(&name, field.span())
+}
+
+/// The generics for the impl, adding the `__Key` type parameter if
+/// the impl is `generic_key`, and the additional `predicates` to the
+/// where clause.
+fn split_generics(
+    input: &DeriveInput,
+    generic_key: bool,
+    predicates: Vec<WherePredicate>,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    let mut generics = input.generics.clone();
+    if generic_key {
+        generics.params.push(syn::parse_quote!(
+            __Key: ::std::convert::From<&'static str>
+                + ::std::clone::Clone
+                + ::std::cmp::PartialEq
+                + 'static
+        ));
+    }
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    (
+        quote!(#impl_generics),
+        quote!(#ty_generics),
+        quote!(#where_clause),
+    )
+}
+
+/// The expressions constructing the validation functions listed in
+/// the field's `#[validate(...)]` attributes, and whether the field is
+/// marked as `nested`.
+fn field_validations(field: &syn::Field) -> syn::Result<(Vec<TokenStream2>, bool)> {
+    let mut validations = Vec::new();
+    let mut nested = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let name = meta
+                .path
+                .get_ident()
+                .cloned()
+                .ok_or_else(|| meta.error("expected the name of a validator"))?;
+
+            if name == "nested" {
+                nested = true;
+                return Ok(());
+            }
+
+            if name == "with" {
+                let function: LitStr = meta.value()?.parse()?;
+                let function: Path = function.parse()?;
+                validations.push(quote_spanned!(function.span()=> #function));
+                return Ok(());
+            }
+
+            let args: Vec<Expr> = if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                Punctuated::<Expr, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            validations.push(validation(&name, args)?);
+            Ok(())
+        })?;
+    }
+    Ok((validations, nested))
+}
+
+/// The paths of the asynchronous validation functions listed in the
+/// field's `#[validate_async(...)]` attributes.
+fn field_async_validations(field: &syn::Field) -> syn::Result<Vec<Path>> {
+    let mut validations = Vec::new();
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate_async"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                let function: LitStr = meta.value()?.parse()?;
+                validations.push(function.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown attribute, expected `with`"))
+            }
+        })?;
+    }
+    Ok(validations)
+}
+
+/// The expression constructing the validator called `name`, with the
+/// specified arguments.
+fn validation(name: &Ident, args: Vec<Expr>) -> syn::Result<TokenStream2> {
+    let span = name.span();
+    match name.to_string().as_str() {
+        "length" => {
+            let mut named = NamedArgs::parse(name, args, &["min", "max"])?;
+            let min = named.take("min").unwrap_or_else(|| syn::parse_quote!(0));
+            let max = named
+                .take("max")
+                .unwrap_or_else(|| syn::parse_quote!(::std::usize::MAX));
+            Ok(quote_spanned!(span=> ::form_validation::validators::string_length(#min, #max)))
+        }
+        "range" => {
+            let mut named = NamedArgs::parse(name, args, &["min", "max"])?;
+            let min = named.required(name, "min")?;
+            let max = named.required(name, "max")?;
+            Ok(quote_spanned!(span=> ::form_validation::validators::range(#min..=#max)))
+        }
+        _ => Ok(quote_spanned!(span=> ::form_validation::validators::#name(#(#args),*))),
+    }
+}
+
+/// The `name = value` arguments of a validator.
+struct NamedArgs {
+    args: Vec<(Ident, Expr)>,
+}
+
+impl NamedArgs {
+    fn parse(validator: &Ident, args: Vec<Expr>, allowed: &[&str]) -> syn::Result<Self> {
+        let mut named = Vec::new();
+        for arg in args {
+            match arg {
+                Expr::Assign(assign) => {
+                    let name = match &*assign.left {
+                        Expr::Path(path) => path.path.get_ident().cloned(),
+                        _ => None,
+                    };
+                    match name {
+                        Some(name) if allowed.iter().any(|allowed| name == allowed) => {
+                            named.push((name, *assign.right))
+                        }
+                        _ => {
+                            return Err(syn::Error::new(
+                                assign.left.span(),
+                                format!(
+                                    "unknown argument for {}, expected one of: {}",
+                                    validator,
+                                    allowed.join(", ")
+                                ),
+                            ))
+                        }
+                    }
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        format!("expected `name = value` arguments for {}", validator),
+                    ))
+                }
+            }
+        }
+        Ok(Self { args: named })
+    }
+
+    fn take(&mut self, name: &str) -> Option<Expr> {
+        let index = self.args.iter().position(|(arg, _)| arg == name)?;
+        Some(self.args.remove(index).1)
+    }
+
+    fn required(&mut self, validator: &Ident, name: &str) -> syn::Result<Expr> {
+        self.take(name).ok_or_else(|| {
+            syn::Error::new(
+                validator.span(),
+                format!("{} requires the `{}` argument", validator, name),
+            )
+        })
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+use form_validation::{validators, Validation, ValidatorFn};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let date: ValidatorFn<String, ()> = validators::chrono::date("%d/%m/%Y").unwrap();
+    let datetime: ValidatorFn<String, ()> = validators::chrono::datetime("%Y-%m-%d %H:%M").unwrap();
+    for v in &[date, datetime] {
+        if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+            let _ = errors.to_string();
+        }
+    }
+});
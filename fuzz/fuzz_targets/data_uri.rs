@@ -0,0 +1,11 @@
+#![no_main]
+use form_validation::{validators, Validation, ValidatorFn};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let v: ValidatorFn<String, ()> =
+        validators::data_uri(vec!["image/png", "text/plain"], Some(64));
+    if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+        let _ = errors.to_string();
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use form_validation::{validators, Validation, ValidatorFn};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let v: ValidatorFn<String, ()> = validators::iban();
+    if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+        let _ = errors.to_string();
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use form_validation::{validators, Validation, ValidatorFn};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    for country in &["CA", "GB", "NL", "US"] {
+        let v: ValidatorFn<String, ()> = validators::postal_code(*country);
+        if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+            let _ = errors.to_string();
+        }
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+use form_validation::{validators, Validation, ValidatorFn};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let date: ValidatorFn<String, ()> = validators::time::date("[day]/[month]/[year]").unwrap();
+    let datetime: ValidatorFn<String, ()> =
+        validators::time::datetime("[year]-[month]-[day] [hour]:[minute]").unwrap();
+    for v in &[date, datetime] {
+        if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+            let _ = errors.to_string();
+        }
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+use form_validation::{
+    validators::{self, UrlOptions},
+    Validation, ValidatorFn,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let v: ValidatorFn<String, ()> = validators::url(
+        UrlOptions::new()
+            .allowed_schemes(vec!["https"])
+            .require_host(true),
+    );
+    if let Err(errors) = v.validate_value(&data.to_string(), &()) {
+        let _ = errors.to_string();
+    }
+});
@@ -0,0 +1,154 @@
+//! Helpers for rendering errors accessibly, using
+//! [WAI-ARIA](https://www.w3.org/TR/wai-aria/) attributes, so that
+//! errors are announced by screen readers.
+//!
+//! The functions in this module require the key to implement
+//! `Display`, for other key types, use
+//! [KeyDisplay::display()](crate::KeyDisplay::display()).
+//!
+//! ## Example
+//! ```
+//! use form_validation::{aria, ValidationError, ValidationErrors};
+//!
+//! let errors = ValidationErrors::new(vec![ValidationError::new("email", "INVALID_EMAIL")]);
+//!
+//! // <input id="email" aria-invalid="true" aria-describedby="email-error">
+//! assert_eq!("true", aria::invalid(&"email", &errors));
+//! assert_eq!(Some("email-error".to_string()), aria::describedby(&"email", &errors));
+//! assert_eq!(None, aria::describedby(&"name", &errors));
+//!
+//! // <p id="email-error">...</p>
+//! assert_eq!("email-error", aria::error_element_id(&"email"));
+//! ```
+
+use crate::ValidationErrors;
+use std::{borrow::Cow, fmt::Display};
+
+/// A stable id for the element which displays the errors for the
+/// field with the specified `key`, of the form `{key}-error`.
+/// Characters which are not valid in an HTML id are replaced with
+/// `-`.
+pub fn error_element_id<Key: Display + ?Sized>(key: &Key) -> String {
+    let id: String = key
+        .to_string()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    format!("{}-error", id)
+}
+
+/// The value for the `aria-describedby` attribute of the input for
+/// the field with the specified `key`, which references the element
+/// displaying its errors (see [error_element_id()]). Returns `None`
+/// if the field has no errors.
+pub fn describedby<Key>(key: &Key, errors: &ValidationErrors<Key>) -> Option<String>
+where
+    Key: Display + PartialEq,
+{
+    if has_errors(key, errors) {
+        Some(error_element_id(key))
+    } else {
+        None
+    }
+}
+
+/// The value for the `aria-invalid` attribute of the input for the
+/// field with the specified `key`.
+pub fn invalid<Key>(key: &Key, errors: &ValidationErrors<Key>) -> &'static str
+where
+    Key: PartialEq,
+{
+    if has_errors(key, errors) {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+fn has_errors<Key: PartialEq>(key: &Key, errors: &ValidationErrors<Key>) -> bool {
+    errors.errors.iter().any(|error| &error.key == key)
+}
+
+/// Aggregates errors into announcements for an `aria-live` region.
+/// Each time the errors for the form change, call
+/// [update()](LiveRegion::update()) and render the returned text
+/// into the region, so that screen readers announce only the errors
+/// which have newly appeared.
+///
+/// ## Example
+/// ```
+/// use form_validation::{aria::LiveRegion, ValidationError, ValidationErrors};
+///
+/// let mut region = LiveRegion::new();
+///
+/// let errors = ValidationErrors::new(vec![
+///     ValidationError::new("email", "INVALID_EMAIL").message("Email is invalid"),
+/// ]);
+/// assert_eq!(Some("Email is invalid".to_string()), region.update(&errors));
+/// // already announced
+/// assert_eq!(None, region.update(&errors));
+///
+/// let errors = ValidationErrors::new(vec![
+///     ValidationError::new("email", "INVALID_EMAIL").message("Email is invalid"),
+///     ValidationError::new("name", "REQUIRED").message("Name is required"),
+/// ]);
+/// assert_eq!(Some("Name is required".to_string()), region.update(&errors));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveRegion<Key> {
+    announced: Vec<(Key, Cow<'static, str>)>,
+}
+
+impl<Key> LiveRegion<Key>
+where
+    Key: Clone + PartialEq,
+{
+    /// Create a new `LiveRegion`, which has not announced anything.
+    pub fn new() -> Self {
+        Self {
+            announced: Vec::new(),
+        }
+    }
+
+    /// Update the region with the current errors for the form,
+    /// returning the text to announce for any errors which were not
+    /// present in the previous update, or `None` if there is nothing
+    /// new to announce. Errors which have been resolved will be
+    /// announced again if they reappear.
+    pub fn update(&mut self, errors: &ValidationErrors<Key>) -> Option<String> {
+        let mut announcements: Vec<String> = Vec::new();
+        let mut current: Vec<(Key, Cow<'static, str>)> = Vec::with_capacity(errors.len());
+
+        for error in &errors.errors {
+            let id = (error.key.clone(), error.type_id.clone());
+            if !self.announced.contains(&id) && !current.contains(&id) {
+                announcements.push(error.to_string());
+            }
+            current.push(id);
+        }
+
+        self.announced = current;
+
+        if announcements.is_empty() {
+            None
+        } else {
+            Some(announcements.join(". "))
+        }
+    }
+}
+
+impl<Key> Default for LiveRegion<Key>
+where
+    Key: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
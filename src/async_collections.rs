@@ -0,0 +1,148 @@
+use crate::{AsyncValidatable, MaybeSendSync, ValidationErrors};
+use futures::stream::{self, StreamExt};
+
+/// Asynchronously validate each of the `items` in a collection of
+/// nested [AsyncValidatable] forms (for example a dynamic list of
+/// line items, each with a remote stock check). The errors for each
+/// item are converted into errors for the parent form using
+/// `map_key`, which is provided with the index of the item, and the
+/// key of the error within the item.
+///
+/// At most `concurrency_limit` (minimum 1) items are validated at the
+/// same time, so that a long list doesn't result in a burst of
+/// requests to a remote service. The errors are returned in the
+/// order of the items.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validate_items_async, AsyncValidatable, ValidationError, ValidationErrors};
+/// use futures::executor::block_on;
+/// use std::{future::Future, pin::Pin};
+///
+/// struct LineItem {
+///     sku: String,
+/// }
+///
+/// impl AsyncValidatable<&'static str> for LineItem {
+///     fn validate_future(
+///         &self,
+///     ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+///         let sku = self.sku.clone();
+///         Box::pin(async move {
+///             // pretend this is a request to a remote service
+///             if sku.starts_with("SKU-") {
+///                 Ok(())
+///             } else {
+///                 Err(ValidationError::new("sku", "UNKNOWN_SKU").into())
+///             }
+///         })
+///     }
+/// }
+///
+/// let items = vec![
+///     LineItem { sku: "SKU-1".to_string() },
+///     LineItem { sku: "???".to_string() },
+/// ];
+///
+/// let errors = block_on(validate_items_async(&items, 4, |index, key| {
+///     format!("items[{}].{}", index, key)
+/// }))
+/// .unwrap_err();
+///
+/// assert_eq!(1, errors.len());
+/// assert_eq!("items[1].sku", errors.errors[0].key);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub async fn validate_items_async<'a, Item, Key, NewKey, I, F>(
+    items: I,
+    concurrency_limit: usize,
+    mut map_key: F,
+) -> Result<(), ValidationErrors<NewKey>>
+where
+    I: IntoIterator<Item = &'a Item>,
+    Item: AsyncValidatable<Key> + 'a,
+    Key: MaybeSendSync + 'static,
+    F: FnMut(usize, &Key) -> NewKey,
+{
+    let results: Vec<(usize, ValidationErrors<Key>)> = stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let future = item.validate_future_or_empty();
+            async move { (index, future.await) }
+        })
+        .buffered(concurrency_limit.max(1))
+        .collect()
+        .await;
+
+    let mut errors = ValidationErrors::default();
+
+    for (index, item_errors) in results {
+        errors.extend(item_errors.map_keys(|key| map_key(index, key)));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::validate_items_async;
+    use crate::{AsyncValidatable, ValidationErrors};
+    use futures::{executor::block_on, future::poll_fn, task::Poll, Future};
+    use std::{cell::Cell, pin::Pin, rc::Rc};
+
+    struct Item {
+        in_flight: Rc<Cell<usize>>,
+        max_in_flight: Rc<Cell<usize>>,
+    }
+
+    impl AsyncValidatable<&'static str> for Item {
+        fn validate_future(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+            let in_flight = self.in_flight.clone();
+            let max_in_flight = self.max_in_flight.clone();
+            Box::pin(async move {
+                in_flight.set(in_flight.get() + 1);
+                max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+
+                // yield once, so that other items get a chance to start
+                let mut yielded = false;
+                poll_fn(|cx| {
+                    if yielded {
+                        Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+
+                in_flight.set(in_flight.get() - 1);
+                Ok(())
+            })
+        }
+    }
+
+    /// No more than the concurrency limit of items are validated at
+    /// the same time.
+    #[test]
+    fn respects_concurrency_limit() {
+        let in_flight = Rc::new(Cell::new(0));
+        let max_in_flight = Rc::new(Cell::new(0));
+        let items: Vec<Item> = (0..50)
+            .map(|_| Item {
+                in_flight: in_flight.clone(),
+                max_in_flight: max_in_flight.clone(),
+            })
+            .collect();
+
+        block_on(validate_items_async(&items, 3, |_, key| *key)).unwrap();
+
+        assert_eq!(3, max_in_flight.get());
+        assert_eq!(0, in_flight.get());
+    }
+}
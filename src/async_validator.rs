@@ -0,0 +1,404 @@
+use crate::{
+    scheduler::Clock, telemetry::Stopwatch, AsyncValidatorFn, ErrorsMiddleware, ErrorsPipeline,
+    ValidationErrors, ValidationOutcome, ValidationSink, Validator, ValidatorId,
+};
+use futures::future::join_all;
+use std::{fmt::Debug, rc::Rc, time::Duration};
+
+/// Validates a particular type of value asynchronously, can contain
+/// many validation functions. Generally used with a single key for
+/// all contained validation functions.
+///
+/// See [Validator] for the synchronous version.
+///
+/// ```
+/// use form_validation::{AsyncValidator, ValidationError, AsyncValidatorFn, ValidatorFn};
+/// use futures::executor::block_on;
+///
+/// let v: AsyncValidator<i32, String> = AsyncValidator::new()
+///     .validation(AsyncValidatorFn::from_async(|value: i32, key: String| async move {
+///         if value < 0 {
+///             Err(ValidationError::new(key, "NOT_LESS_THAN_0")
+///                 .with_message(move |key| {
+///                     format!("The value of {} ({}) cannot be less than 0", key, value)
+///                 })
+///                 .into()) // convert into ValidationErrors
+///         } else {
+///             Ok(())
+///         }
+///     }))
+///     // also supports compatibility with the synchronous ValidatorFn
+///     .validation(ValidatorFn::new(|value: &i32, key: &String| {
+///         if value > &10 {
+///             let value_clone = *value;
+///             Err(ValidationError::new(key.clone(), "NOT_GREATER_THAN_10")
+///                 .with_message(move |key| {
+///                     format!(
+///                         "The value of {} ({}) cannot be greater than 10",
+///                         key, value_clone
+///                     )
+///                 })
+///                 .into()) // convert into ValidationErrors
+///         } else {
+///             Ok(())
+///         }
+///     }));
+/// let key = "field1".to_string();
+/// {
+///     let errors = block_on(v.validate_value(&11, &key)).unwrap_err();
+///     assert_eq!(1, errors.len());
+///     let error = errors.errors.first().unwrap();
+///     assert_eq!("NOT_GREATER_THAN_10", error.type_id);
+/// }
+///
+/// assert!(block_on(v.validate_value(&5, &key)).is_ok());
+///
+/// {
+///     let errors = block_on(v.validate_value(&-1, &key)).unwrap_err();
+///     assert_eq!(1, errors.len());
+///     let error = errors.errors.first().unwrap();
+///     assert_eq!("NOT_LESS_THAN_0", error.type_id);
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[derive(Clone)]
+pub struct AsyncValidator<Value, Key> {
+    pub validations: Vec<AsyncValidatorFn<Value, Key>>,
+    sinks: Vec<Rc<dyn ValidationSink<Key>>>,
+    sanitizers: Vec<Rc<dyn Fn(Value) -> Value>>,
+    middleware: ErrorsPipeline<Key>,
+    clock: Option<Clock>,
+}
+
+impl<Value, Key> PartialEq for AsyncValidator<Value, Key> {
+    fn eq(&self, other: &Self) -> bool {
+        self.validations == other.validations
+    }
+}
+
+impl<Value, Key> AsyncValidator<Value, Key>
+where
+    Key: Clone + PartialEq,
+    Value: Clone + PartialEq,
+{
+    /// Create a new `Validator`.
+    pub fn new() -> Self {
+        Self {
+            validations: Vec::new(),
+            sinks: Vec::new(),
+            sanitizers: Vec::new(),
+            middleware: ErrorsPipeline::new(),
+            clock: None,
+        }
+    }
+
+    /// A factory method to add a validation function to this validator.
+    pub fn validation<F: Into<AsyncValidatorFn<Value, Key>> + 'static>(
+        mut self,
+        async_validator_fn: F,
+    ) -> Self {
+        self.validations.push(async_validator_fn.into());
+        self
+    }
+
+    /// A factory method to install a [ValidationSink] on this
+    /// validator, which will receive an anonymized
+    /// [ValidationOutcome] after each validation run.
+    pub fn sink<S>(mut self, sink: S) -> Self
+    where
+        S: ValidationSink<Key> + 'static,
+    {
+        self.sinks.push(Rc::new(sink));
+        self
+    }
+
+    /// A factory method to install an [ErrorsMiddleware] on this
+    /// validator, which is applied to the errors produced by the
+    /// validation functions (before they are recorded by any
+    /// [ValidationSink]s), see
+    /// [Validator::middleware()](crate::Validator::middleware()).
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{AsyncValidator, AsyncValidatorFn, ValidationError};
+    /// use futures::executor::block_on;
+    ///
+    /// let v: AsyncValidator<String, &str> = AsyncValidator::new()
+    ///     .validation(AsyncValidatorFn::from_async(|_value: String, key: &str| async move {
+    ///         Err(ValidationError::new(key, "internal.DB_TIMEOUT").into())
+    ///     }))
+    ///     .middleware(|error: ValidationError<&'static str>| {
+    ///         Some(ValidationError::new(error.key, "UNAVAILABLE"))
+    ///     });
+    ///
+    /// let errors = block_on(v.validate_value(&"alice".to_string(), &"username")).unwrap_err();
+    /// assert_eq!("UNAVAILABLE", errors.errors[0].type_id);
+    /// ```
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: ErrorsMiddleware<Key> + 'static,
+    {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// A factory method to add a sanitizer to this validator, which
+    /// normalizes a value before it is validated by
+    /// [AsyncValidator::validate_and_normalize()], see
+    /// [Validator::sanitizer()](crate::Validator::sanitizer()).
+    pub fn sanitizer<S>(mut self, sanitizer: S) -> Self
+    where
+        S: Fn(Value) -> Value + 'static,
+    {
+        self.sanitizers.push(Rc::new(sanitizer));
+        self
+    }
+
+    /// A factory method to provide the current time (as the duration
+    /// since an arbitrary fixed point, for example the value of
+    /// `performance.now()`), which is recorded as the
+    /// [timestamp](crate::ValidationError::timestamp()) of the errors
+    /// when the validation completes, so that results which arrive
+    /// after the user has moved on can be detected using
+    /// [ValidationError::is_stale()](crate::ValidationError::is_stale()).
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{AsyncValidator, AsyncValidatorFn, ValidationError};
+    /// use futures::executor::block_on;
+    /// use std::time::Duration;
+    ///
+    /// let v: AsyncValidator<String, &str> = AsyncValidator::new()
+    ///     .validation(AsyncValidatorFn::from_async(|_value: String, key: &str| async move {
+    ///         Err(ValidationError::new(key, "USERNAME_TAKEN").into())
+    ///     }))
+    ///     .clock(|| Duration::from_secs(10));
+    ///
+    /// let errors = block_on(v.validate_value(&"alice".to_string(), &"username")).unwrap_err();
+    /// assert_eq!(Some(Duration::from_secs(10)), errors.errors[0].timestamp());
+    /// ```
+    pub fn clock<F>(mut self, now: F) -> Self
+    where
+        F: Fn() -> Duration + 'static,
+    {
+        self.clock = Some(Clock::new(now));
+        self
+    }
+
+    /// Remove the validation function with the specified `id` from
+    /// this validator. Returns `true` if a validation function was
+    /// removed.
+    pub fn remove_validation<I: Into<ValidatorId>>(&mut self, id: I) -> bool {
+        let id = id.into();
+        let len_before = self.validations.len();
+        self.validations.retain(|validation| validation.id() != &id);
+        self.validations.len() != len_before
+    }
+
+    /// Normalize the `value` using this validator's sanitizers, and
+    /// then validate the normalized value. Returns the normalized
+    /// value to store, along with the result of validating it, see
+    /// [Validator::validate_and_normalize()](crate::Validator::validate_and_normalize()).
+    pub async fn validate_and_normalize(
+        &self,
+        value: Value,
+        key: &Key,
+    ) -> (Value, Result<(), ValidationErrors<Key>>) {
+        let value = self
+            .sanitizers
+            .iter()
+            .fold(value, |value, sanitizer| sanitizer(value));
+        let result = self.validate_value(&value, key).await;
+        (value, result)
+    }
+
+    pub async fn validate_value(
+        &self,
+        value: &Value,
+        key: &Key,
+    ) -> Result<(), ValidationErrors<Key>> {
+        let stopwatch = if self.sinks.is_empty() {
+            None
+        } else {
+            Some(Stopwatch::start())
+        };
+        let mut errors = ValidationErrors::default();
+
+        let futures = self
+            .validations
+            .iter()
+            .map(|async_validator_fn| async_validator_fn.validate_value(value, key))
+            .collect::<Vec<_>>();
+
+        // Execute all the futures concurrently
+        let results: Vec<Result<(), ValidationErrors<Key>>> = join_all(futures).await;
+
+        for result in results {
+            if let Err(new_errors) = result {
+                errors.extend(new_errors)
+            }
+        }
+
+        self.middleware.apply_from(&mut errors, 0);
+
+        if let Some(clock) = &self.clock {
+            errors.stamp(clock.now());
+        }
+
+        if let Some(stopwatch) = stopwatch {
+            let outcome = ValidationOutcome {
+                key,
+                type_ids: errors
+                    .errors
+                    .iter()
+                    .map(|error| error.type_id.clone())
+                    .collect(),
+                severities: errors.errors.iter().map(|error| error.severity()).collect(),
+                duration: stopwatch.elapsed(),
+                is_async: true,
+            };
+
+            for sink in &self.sinks {
+                sink.record(&outcome);
+            }
+        }
+
+        if !errors.is_empty() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<Value, Key> Debug for AsyncValidator<Value, Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncValidator")
+            .field("len", &self.validations.len())
+            .field("validations", &self.validations)
+            .field("sinks", &self.sinks.len())
+            .finish()
+    }
+}
+
+impl<Value, Key> Default for AsyncValidator<Value, Key>
+where
+    Key: Clone + PartialEq,
+    Value: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Value, Key> From<Validator<Value, Key>> for AsyncValidator<Value, Key>
+where
+    Value: Clone + PartialEq + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    fn from(validator: Validator<Value, Key>) -> Self {
+        let mut async_validator: AsyncValidator<Value, Key> = AsyncValidator::new();
+
+        for validator_fn in validator.validations {
+            async_validator = async_validator.validation(validator_fn);
+        }
+
+        async_validator.sinks = validator.sinks;
+        async_validator.sanitizers = validator.sanitizers;
+        async_validator.middleware = validator.middleware;
+        async_validator
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AsyncValidator;
+    use crate::{ValidationError, Validator};
+    use futures::executor::block_on;
+
+    /// Unit test for the `From<Validator> for AsyncValidator` implmentation
+    #[test]
+    fn async_validator_from_validator() {
+        let v: Validator<i32, String> = Validator::new()
+            .validation(|value: &i32, key: &String| {
+                if value < &0 {
+                    let value_clone = *value;
+                    Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
+                        .with_message(move |key| {
+                            format!(
+                                "The value of {} ({}) cannot be less than 0",
+                                key, value_clone
+                            )
+                        })
+                        .into())
+                } else {
+                    Ok(())
+                }
+            })
+            .validation(|value: &i32, key: &String| {
+                if value > &10 {
+                    let value_clone = *value;
+                    Err(ValidationError::new(key.clone(), "NOT_GREATER_THAN_10")
+                        .with_message(move |key| {
+                            format!(
+                                "The value of {} ({}) cannot be greater than 10",
+                                key, value_clone
+                            )
+                        })
+                        .into())
+                } else {
+                    Ok(())
+                }
+            });
+
+        // perform the conversion
+        let av: AsyncValidator<i32, String> = v.into();
+
+        let key = "field1".to_string();
+        {
+            let errors = block_on(av.validate_value(&11, &key)).unwrap_err();
+            assert_eq!(1, errors.len());
+            let error = errors.errors.first().unwrap();
+            assert_eq!("NOT_GREATER_THAN_10", error.type_id);
+        }
+        assert!(block_on(av.validate_value(&5, &key)).is_ok());
+        {
+            let errors = block_on(av.validate_value(&-1, &key)).unwrap_err();
+            assert_eq!(1, errors.len());
+            let error = errors.errors.first().unwrap();
+            assert_eq!("NOT_LESS_THAN_0", error.type_id);
+        }
+    }
+
+    /// The middleware and sanitizers are retained by the
+    /// `From<Validator> for AsyncValidator` implementation.
+    #[test]
+    fn async_validator_from_validator_keeps_middleware_and_sanitizers() {
+        let v: Validator<String, String> = Validator::new()
+            .sanitizer(|value: String| value.trim().to_string())
+            .validation(|value: &String, key: &String| {
+                if value.is_empty() {
+                    Err(ValidationError::new(key.clone(), "internal.EMPTY").into())
+                } else {
+                    Ok(())
+                }
+            })
+            .middleware(|error: ValidationError<String>| {
+                Some(ValidationError::new(error.key, "REQUIRED"))
+            });
+
+        let av: AsyncValidator<String, String> = v.into();
+
+        let key = "name".to_string();
+        let errors = block_on(av.validate_value(&String::new(), &key)).unwrap_err();
+        assert_eq!("REQUIRED", errors.errors[0].type_id);
+
+        let (value, result) = block_on(av.validate_and_normalize("  Alice ".to_string(), &key));
+        assert_eq!("Alice", value);
+        assert!(result.is_ok());
+
+        let (value, result) = block_on(av.validate_and_normalize("   ".to_string(), &key));
+        assert_eq!("", value);
+        assert_eq!("REQUIRED", result.unwrap_err().errors[0].type_id);
+    }
+}
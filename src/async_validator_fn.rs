@@ -0,0 +1,220 @@
+use crate::{
+    validator_fn::DebugValidatorId, Validation, ValidationErrors, ValidatorFn, ValidatorId,
+};
+use std::{fmt::Debug, future::Future, marker::PhantomData, pin::Pin, rc::Rc};
+
+type AsyncValidatorFnTraitObject<Value, Key> =
+    dyn Fn(&Value, &Key) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>;
+
+/// An function to perform validation on a field asynchonously.
+///
+/// For the synchronous version, see [ValidatorFn].
+///
+/// ## Example
+///
+/// ```
+/// use form_validation::{AsyncValidatorFn, ValidationError};
+/// use futures::executor::block_on;
+///
+/// let v: AsyncValidatorFn<i32, String> =
+///     AsyncValidatorFn::new(|value: &i32, key: &String| {
+///         let key = key.clone();
+///         let value = *value;
+///         Box::pin(async move {
+///             // perform actions here that require async
+///             if value < 0 {
+///                 Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
+///                     .with_message(move |key| {
+///                         format!(
+///                             "The value of {} ({}) cannot be less than 0",
+///                             key, value
+///                         )
+///                     })
+///                     .into()) // convert into ValidationErrors
+///             } else {
+///                 Ok(())
+///             }
+///         })
+///     });
+///
+/// let key = "field1".to_string();
+/// assert!(block_on(v.validate_value(&20, &key)).is_ok());
+///
+/// let errors = block_on(v.validate_value(&-1, &key)).unwrap_err();
+/// assert_eq!(1, errors.len());
+/// let error = errors.errors.get(0).unwrap();
+/// assert_eq!(
+///     "The value of field1 (-1) cannot be less than 0",
+///     error.to_string()
+/// );
+/// assert_eq!("NOT_LESS_THAN_0", error.type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct AsyncValidatorFn<Value, Key> {
+    future_producer: Rc<AsyncValidatorFnTraitObject<Value, Key>>,
+    id: ValidatorId,
+    key_type: PhantomData<Key>,
+    value_type: PhantomData<Value>,
+}
+
+impl<Value, Key> AsyncValidatorFn<Value, Key>
+where
+    Key: Clone + PartialEq,
+    Value: Clone + PartialEq,
+{
+    /// Takes a closure that produces a `Future` that produces a
+    /// [ValidatorFn] closure.
+    pub fn new<C>(closure: C) -> Self
+    where
+        C: Fn(&Value, &Key) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>
+            + 'static,
+    {
+        Self {
+            future_producer: Rc::new(closure),
+            id: ValidatorId::random(),
+            key_type: PhantomData,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Takes a stable caller-supplied `id`, and a closure that
+    /// produces a `Future` that produces a [ValidatorFn] closure. Two
+    /// `AsyncValidatorFn`s with the same `id` are considered equal.
+    pub fn with_id<I, C>(id: I, closure: C) -> Self
+    where
+        I: Into<ValidatorId>,
+        C: Fn(&Value, &Key) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>
+            + 'static,
+    {
+        Self {
+            future_producer: Rc::new(closure),
+            id: id.into(),
+            key_type: PhantomData,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Takes an async function or closure which receives its own
+    /// clones of the value and key, so that they can be used in the
+    /// `Future` it produces without having to manually clone them and
+    /// call `Box::pin()`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use form_validation::{AsyncValidatorFn, ValidationError, ValidationErrors};
+    /// use futures::executor::block_on;
+    ///
+    /// async fn check_username_free(
+    ///     value: String,
+    ///     key: String,
+    /// ) -> Result<(), ValidationErrors<String>> {
+    ///     // perform actions here that require async
+    ///     if value == "alice" {
+    ///         Err(ValidationError::new(key, "USERNAME_TAKEN").into())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let v: AsyncValidatorFn<String, String> = AsyncValidatorFn::from_async(check_username_free);
+    ///
+    /// let key = "username".to_string();
+    /// assert!(block_on(v.validate_value(&"bob".to_string(), &key)).is_ok());
+    ///
+    /// let errors = block_on(v.validate_value(&"alice".to_string(), &key)).unwrap_err();
+    /// assert_eq!("USERNAME_TAKEN", errors.errors[0].type_id);
+    ///
+    /// // closures returning an async block also work
+    /// let v: AsyncValidatorFn<i32, String> =
+    ///     AsyncValidatorFn::from_async(|value: i32, key: String| async move {
+    ///         if value < 0 {
+    ///             Err(ValidationError::new(key, "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    /// assert!(block_on(v.validate_value(&-1, &key)).is_err());
+    /// ```
+    pub fn from_async<F, Fut>(function: F) -> Self
+    where
+        Value: 'static,
+        Key: 'static,
+        F: Fn(Value, Key) -> Fut + 'static,
+        Fut: Future<Output = Result<(), ValidationErrors<Key>>> + 'static,
+    {
+        Self::new(move |value: &Value, key: &Key| Box::pin(function(value.clone(), key.clone())))
+    }
+
+    /// The same as [AsyncValidatorFn::from_async()], but with a
+    /// stable caller-supplied `id`, see [AsyncValidatorFn::with_id()].
+    pub fn with_id_async<I, F, Fut>(id: I, function: F) -> Self
+    where
+        Value: 'static,
+        Key: 'static,
+        I: Into<ValidatorId>,
+        F: Fn(Value, Key) -> Fut + 'static,
+        Fut: Future<Output = Result<(), ValidationErrors<Key>>> + 'static,
+    {
+        Self::with_id(id, move |value: &Value, key: &Key| {
+            Box::pin(function(value.clone(), key.clone()))
+        })
+    }
+
+    /// The identifier for this validation function.
+    pub fn id(&self) -> &ValidatorId {
+        &self.id
+    }
+
+    /// Runs the future to produce the [ValidatorFn] closure, and then
+    /// performs the validation with that.
+    pub async fn validate_value(
+        &self,
+        value: &Value,
+        key: &Key,
+    ) -> Result<(), ValidationErrors<Key>> {
+        let future = (self.future_producer)(value, key);
+        future.await
+    }
+}
+
+impl<Value, Key> From<ValidatorFn<Value, Key>> for AsyncValidatorFn<Value, Key>
+where
+    Key: Clone + PartialEq + 'static,
+    Value: Clone + PartialEq + 'static,
+{
+    fn from(validator_fn: ValidatorFn<Value, Key>) -> Self {
+        let id = validator_fn.id().clone();
+        Self::with_id(id, move |value, key| {
+            let value_clone = value.clone();
+            let key_clone = key.clone();
+            let new_fn = validator_fn.clone();
+            Box::pin(async move { new_fn.validate_value(&value_clone, &key_clone) })
+        })
+    }
+}
+
+impl<Value, Key> PartialEq for AsyncValidatorFn<Value, Key> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<Value, Key> Clone for AsyncValidatorFn<Value, Key> {
+    fn clone(&self) -> Self {
+        Self {
+            future_producer: Rc::clone(&self.future_producer),
+            id: self.id.clone(),
+            key_type: PhantomData,
+            value_type: PhantomData,
+        }
+    }
+}
+
+impl<Value, Key> Debug for AsyncValidatorFn<Value, Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AsyncValidatorFn")
+            .field(&DebugValidatorId(&self.id))
+            .finish()
+    }
+}
@@ -0,0 +1,190 @@
+//! Constants for the [type_id](crate::ValidationError::type_id)s of
+//! the errors produced by this library and its built-in
+//! [validators](crate::validators). These identifiers are stable, so
+//! they can be relied upon as the keys of a
+//! [MessageCatalog](crate::MessageCatalog) and in tests.
+//!
+//! ## Example
+//!
+//! ```
+//! use form_validation::{codes, validators, Validation};
+//!
+//! let v = validators::required::<String, &str>();
+//! let errors = v.validate_value(&String::new(), &"name").unwrap_err();
+//!
+//! assert!(errors.has_code(codes::REQUIRED));
+//! assert_eq!(codes::REQUIRED, errors.errors[0].type_id);
+//! ```
+
+/// A validator panicked, see
+/// [ValidatorFn::catch_panics()](crate::ValidatorFn::catch_panics()).
+pub const VALIDATOR_PANICKED: &str = "VALIDATOR_PANICKED";
+
+/// A required key is missing from a map, see
+/// [validators::required_keys()](crate::validators::required_keys()).
+pub const MISSING_KEY: &str = "MISSING_KEY";
+
+/// A map contains a key which isn't allowed, see
+/// [validators::known_keys()](crate::validators::known_keys()).
+pub const UNKNOWN_KEY: &str = "UNKNOWN_KEY";
+
+/// A form contains a field which isn't allowed, see
+/// [validators::strict_fields()](crate::validators::strict_fields()).
+pub const UNKNOWN_FIELD: &str = "UNKNOWN_FIELD";
+
+/// A required value is empty, see
+/// [validators::required()](crate::validators::required()).
+pub const REQUIRED: &str = "REQUIRED";
+
+/// Two fields which should be equal are not, for example a password
+/// and its confirmation, see
+/// [validators::equals_field()](crate::validators::equals_field()).
+pub const FIELDS_DO_NOT_MATCH: &str = "FIELDS_DO_NOT_MATCH";
+
+/// Two fields which should be different are equal.
+pub const FIELDS_MATCH: &str = "FIELDS_MATCH";
+
+/// A comparison between two fields failed.
+pub const FIELD_COMPARISON_FAILED: &str = "FIELD_COMPARISON_FAILED";
+
+/// The length of a string is out of range, see
+/// [validators::string_length()](crate::validators::string_length()).
+pub const LENGTH_OUT_OF_RANGE: &str = "LENGTH_OUT_OF_RANGE";
+
+/// The number of items in a collection is out of range.
+pub const ITEMS_COUNT_OUT_OF_RANGE: &str = "ITEMS_COUNT_OUT_OF_RANGE";
+
+/// A collection contains a duplicate item.
+pub const DUPLICATE_ITEM: &str = "DUPLICATE_ITEM";
+
+/// A string contains characters which are not alphanumeric.
+pub const NOT_ALPHANUMERIC: &str = "NOT_ALPHANUMERIC";
+
+/// A string contains characters which are not ASCII.
+pub const NOT_ASCII: &str = "NOT_ASCII";
+
+/// A string is not a valid identifier.
+pub const INVALID_IDENTIFIER: &str = "INVALID_IDENTIFIER";
+
+/// A string doesn't start with the required prefix.
+pub const MISSING_PREFIX: &str = "MISSING_PREFIX";
+
+/// A string doesn't end with the required suffix.
+pub const MISSING_SUFFIX: &str = "MISSING_SUFFIX";
+
+/// A string doesn't contain the required substring.
+pub const MISSING_SUBSTRING: &str = "MISSING_SUBSTRING";
+
+/// A string contains a forbidden substring.
+pub const FORBIDDEN_SUBSTRING: &str = "FORBIDDEN_SUBSTRING";
+
+/// A number is out of range, see
+/// [validators::range()](crate::validators::range()).
+pub const OUT_OF_RANGE: &str = "OUT_OF_RANGE";
+
+/// A string is not a valid number.
+pub const INVALID_NUMBER: &str = "INVALID_NUMBER";
+
+/// A number is not a multiple of the required factor.
+pub const NOT_MULTIPLE_OF: &str = "NOT_MULTIPLE_OF";
+
+/// A string is not a valid coordinate.
+pub const INVALID_COORDINATE: &str = "INVALID_COORDINATE";
+
+/// A latitude or longitude is out of range.
+pub const COORDINATE_OUT_OF_RANGE: &str = "COORDINATE_OUT_OF_RANGE";
+
+/// A number has too many decimal places.
+pub const TOO_MANY_DECIMAL_PLACES: &str = "TOO_MANY_DECIMAL_PLACES";
+
+/// A value is not one of the allowed choices.
+pub const NOT_ONE_OF: &str = "NOT_ONE_OF";
+
+/// A date is not before the required date.
+pub const DATE_NOT_BEFORE: &str = "DATE_NOT_BEFORE";
+
+/// A date is not after the required date.
+pub const DATE_NOT_AFTER: &str = "DATE_NOT_AFTER";
+
+/// A date is in the future.
+pub const DATE_IN_FUTURE: &str = "DATE_IN_FUTURE";
+
+/// A date is in the past.
+pub const DATE_IN_PAST: &str = "DATE_IN_PAST";
+
+/// A string is not a valid UUID.
+pub const INVALID_UUID: &str = "INVALID_UUID";
+
+/// A UUID is not of the required version.
+pub const UUID_VERSION_MISMATCH: &str = "UUID_VERSION_MISMATCH";
+
+/// A string is not a valid email address.
+pub const INVALID_EMAIL: &str = "INVALID_EMAIL";
+
+/// A string is not a valid IBAN.
+pub const INVALID_IBAN: &str = "INVALID_IBAN";
+
+/// A string is not a valid postal code.
+pub const POSTAL_CODE_INVALID: &str = "POSTAL_CODE_INVALID";
+
+/// A string is not a valid IP address.
+pub const INVALID_IP_ADDRESS: &str = "INVALID_IP_ADDRESS";
+
+/// A string is not a valid date.
+pub const INVALID_DATE: &str = "INVALID_DATE";
+
+/// A string is not a valid date and time.
+pub const INVALID_DATETIME: &str = "INVALID_DATETIME";
+
+/// A string doesn't match the required pattern.
+pub const PATTERN_MISMATCH: &str = "PATTERN_MISMATCH";
+
+/// A string is not a valid URL.
+pub const URL_INVALID: &str = "URL_INVALID";
+
+/// A URL's scheme is not allowed.
+pub const URL_SCHEME_NOT_ALLOWED: &str = "URL_SCHEME_NOT_ALLOWED";
+
+/// A URL doesn't have a host.
+pub const URL_HOST_REQUIRED: &str = "URL_HOST_REQUIRED";
+
+/// A string is not a valid phone number.
+pub const PHONE_INVALID: &str = "PHONE_INVALID";
+
+/// A phone number is not valid for the region.
+pub const PHONE_INVALID_FOR_REGION: &str = "PHONE_INVALID_FOR_REGION";
+
+/// A password is too weak.
+pub const WEAK_PASSWORD: &str = "WEAK_PASSWORD";
+
+/// A file is too large.
+pub const FILE_TOO_LARGE: &str = "FILE_TOO_LARGE";
+
+/// A file's extension is not allowed.
+pub const FILE_EXTENSION_NOT_ALLOWED: &str = "FILE_EXTENSION_NOT_ALLOWED";
+
+/// A file's type is not allowed.
+pub const FILE_TYPE_NOT_ALLOWED: &str = "FILE_TYPE_NOT_ALLOWED";
+
+/// A string is not valid base64.
+pub const INVALID_BASE64: &str = "INVALID_BASE64";
+
+/// Decoded data is too large.
+pub const DECODED_SIZE_TOO_LARGE: &str = "DECODED_SIZE_TOO_LARGE";
+
+/// A string is not a valid data URI.
+pub const INVALID_DATA_URI: &str = "INVALID_DATA_URI";
+
+/// A data URI's media type is not allowed.
+pub const DATA_URI_TYPE_NOT_ALLOWED: &str = "DATA_URI_TYPE_NOT_ALLOWED";
+
+/// A string is not valid JSON.
+pub const INVALID_JSON: &str = "INVALID_JSON";
+
+/// A string is not a valid boolean, see
+/// [ValidationError::from_parse_error()](crate::ValidationError::from_parse_error()).
+pub const INVALID_BOOLEAN: &str = "INVALID_BOOLEAN";
+
+/// A string is not a single character, see
+/// [ValidationError::from_parse_error()](crate::ValidationError::from_parse_error()).
+pub const INVALID_CHARACTER: &str = "INVALID_CHARACTER";
@@ -0,0 +1,57 @@
+use crate::{Validation, ValidationErrors};
+
+/// Validate each of the entries in a map-shaped collection of form
+/// data (for example per-language translations keyed by locale, in a
+/// `HashMap` or `BTreeMap`). Each entry's value is validated using
+/// `validation`, and the errors are keyed by `(map_key, field)`.
+///
+/// Use [validators::required_keys()](crate::validators::required_keys())
+/// and [validators::known_keys()](crate::validators::known_keys()) to
+/// validate the keys in the map itself.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validate_entries, ValidationError, Validator};
+/// use std::collections::BTreeMap;
+///
+/// let mut titles: BTreeMap<&'static str, String> = BTreeMap::new();
+/// titles.insert("en", "Hello".to_string());
+/// titles.insert("de", "".to_string());
+///
+/// let v: Validator<String, (&'static str, &'static str)> = Validator::new()
+///     .validation(|value: &String, key: &(&'static str, &'static str)| {
+///         if value.is_empty() {
+///             Err(ValidationError::new(*key, "REQUIRED").into())
+///         } else {
+///             Ok(())
+///         }
+///     });
+///
+/// let errors = validate_entries(&titles, &"title", &v).unwrap_err();
+/// assert_eq!(1, errors.len());
+/// assert_eq!(("de", "title"), errors.errors[0].key);
+/// ```
+pub fn validate_entries<'a, MapKey, Value, Key, I, V>(
+    entries: I,
+    field: &Key,
+    validation: &V,
+) -> Result<(), ValidationErrors<(MapKey, Key)>>
+where
+    I: IntoIterator<Item = (&'a MapKey, &'a Value)>,
+    MapKey: Clone + PartialEq + 'a,
+    Value: 'a,
+    Key: Clone + PartialEq,
+    V: Validation<Value, (MapKey, Key)> + ?Sized,
+{
+    let mut errors = ValidationErrors::default();
+
+    for (map_key, value) in entries {
+        validation.validate_value_into(value, &(map_key.clone(), field.clone()), &mut errors);
+    }
+
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(())
+    }
+}
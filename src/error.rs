@@ -1,26 +1,190 @@
+use crate::{ErrorCode, LabelProvider, MessageContext};
 use std::{
-    fmt::{Debug, Display},
-    rc::Rc,
+    borrow::Cow,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    time::Duration,
 };
 
+/// How severe a [ValidationError] is. Only errors with the
+/// [Severity::Error] severity make a form invalid, so that
+/// non-blocking warnings (for example "this username is unusual") and
+/// informational hints can be displayed alongside the form without
+/// preventing it from being submitted.
+///
+/// Severities are ordered from the least to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// Information which may help the user to fill in the field.
+    Info,
+    /// A potential problem with the value, which doesn't make the
+    /// form invalid.
+    Warning,
+    /// A problem with the value which makes the form invalid.
+    #[default]
+    Error,
+}
+
+/// A bound on the functions stored in a [ValidationError] (and its
+/// key, for [ValidationError::map_key()]). When the `"send"` feature
+/// is enabled this requires `Send + Sync`, so that errors can be sent
+/// between threads, otherwise it is implemented for all types. Because
+/// Cargo unifies features across the dependency graph, the `"send"`
+/// feature should only be enabled by the final binary, see the
+/// [crate documentation](crate).
+#[cfg(feature = "send")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "send")]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+/// A bound on the functions stored in a [ValidationError] (and its
+/// key, for [ValidationError::map_key()]). When the `"send"` feature
+/// is enabled this requires `Send + Sync`, so that errors can be sent
+/// between threads, otherwise it is implemented for all types. Because
+/// Cargo unifies features across the dependency graph, the `"send"`
+/// feature should only be enabled by the final binary, see the
+/// [crate documentation](crate).
+#[cfg(not(feature = "send"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "send"))]
+impl<T> MaybeSendSync for T {}
+
+#[cfg(feature = "send")]
+pub(crate) type Shared<T> = std::sync::Arc<T>;
+#[cfg(feature = "send")]
+type MessageFn<Key> = dyn Fn(&Key) -> String + Send + Sync;
+#[cfg(feature = "send")]
+type LabelledMessageFn = dyn Fn(&str) -> String + Send + Sync;
+#[cfg(feature = "send")]
+type MessageFmtFn<Key> = dyn Fn(&Key, &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync;
+#[cfg(feature = "send")]
+type ContextualMessageFn<Key> = dyn Fn(&Key, &MessageContext) -> String + Send + Sync;
+
+#[cfg(not(feature = "send"))]
+pub(crate) type Shared<T> = std::rc::Rc<T>;
+#[cfg(not(feature = "send"))]
+type MessageFn<Key> = dyn Fn(&Key) -> String;
+#[cfg(not(feature = "send"))]
+type LabelledMessageFn = dyn Fn(&str) -> String;
+#[cfg(not(feature = "send"))]
+type MessageFmtFn<Key> = dyn Fn(&Key, &mut fmt::Formatter<'_>) -> fmt::Result;
+#[cfg(not(feature = "send"))]
+type ContextualMessageFn<Key> = dyn Fn(&Key, &MessageContext) -> String;
+
+/// The message of a [ValidationError].
+enum Message<Key> {
+    /// A message which doesn't depend on the key, stored without
+    /// allocating when it is `&'static str`.
+    Static(Cow<'static, str>),
+    /// Function that produces the message from the key.
+    Fn(Shared<MessageFn<Key>>),
+    /// Function that writes the message for the key directly into a
+    /// formatter.
+    Fmt(Shared<MessageFmtFn<Key>>),
+    /// Function that produces the message from the key and a
+    /// [MessageContext].
+    Contextual(Shared<ContextualMessageFn<Key>>),
+}
+
+impl<Key> Clone for Message<Key> {
+    fn clone(&self) -> Self {
+        match self {
+            Message::Static(message) => Message::Static(message.clone()),
+            Message::Fn(message_fn) => Message::Fn(message_fn.clone()),
+            Message::Fmt(fmt_fn) => Message::Fmt(fmt_fn.clone()),
+            Message::Contextual(message_fn) => Message::Contextual(message_fn.clone()),
+        }
+    }
+}
+
+/// Help for fixing a [ValidationError], such as a link to
+/// documentation about the format of a tax identification number, or
+/// where to find an API token.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Help {
+    /// A longer explanation than the error message.
+    pub text: Option<String>,
+    /// A link to documentation.
+    pub url: Option<String>,
+}
+
 /// An error associated with a form field.
 pub struct ValidationError<Key> {
     /// The key for the field that this validation error is associated with.
     pub key: Key,
-    /// An identifier for the type of error this is.
-    pub type_id: &'static str,
-    /// Function that produces the error message.
-    message: Rc<dyn Fn(&Key) -> String>,
+    /// An identifier for the type of error this is. This is usually a
+    /// `&'static str` constant (such as one of the [codes](crate::codes)
+    /// of the built-in validators), but it can also be owned, for
+    /// example to preserve an error code received from a backend API.
+    pub type_id: Cow<'static, str>,
+    /// The error message.
+    message: Message<Key>,
+    /// Function that produces the error message from the label of
+    /// the field, see [ValidationError::with_labelled_message()].
+    labelled_message: Option<Shared<LabelledMessageFn>>,
+    /// Whether the user has acknowledged (dismissed) this error.
+    acknowledged: bool,
+    /// How severe this error is.
+    severity: Severity,
+    /// Help for fixing this error.
+    help: Option<Help>,
+    /// A suggested replacement for the value.
+    suggestion: Option<String>,
+    /// How prominently this error should be displayed, see
+    /// [ValidationErrors::sorted()].
+    priority: i32,
+    /// When this error was produced, see
+    /// [ValidationError::with_timestamp()].
+    timestamp: Option<Duration>,
+    /// Named parameters describing the rule which failed.
+    params: Vec<(Cow<'static, str>, String)>,
+    /// Structured metadata attached to this error.
+    #[cfg(feature = "serde_json")]
+    meta: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
+/// Errors are equal if they have the same [key](ValidationError::key)
+/// and [type_id](ValidationError::type_id), regardless of their
+/// messages and other details, so that they can be deduplicated,
+/// stored in sets, and asserted against in tests without comparing
+/// rendered messages.
+///
+/// ## Example
+/// ```
+/// use form_validation::ValidationError;
+/// use std::collections::HashSet;
+///
+/// let errors: HashSet<ValidationError<&str>> = vec![
+///     ValidationError::new("email", "REQUIRED").message("Email is required"),
+///     ValidationError::new("email", "REQUIRED").message("Please enter your email"),
+///     ValidationError::new("email", "INVALID_EMAIL"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(2, errors.len());
+/// assert!(errors.contains(&ValidationError::new("email", "INVALID_EMAIL")));
+/// ```
 impl<Key> PartialEq for ValidationError<Key>
 where
     Key: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.key == other.key
-            && self.type_id == other.type_id
-            && self.get_message() == other.get_message()
+        self.key == other.key && self.type_id == other.type_id
+    }
+}
+
+impl<Key> Eq for ValidationError<Key> where Key: Eq {}
+
+impl<Key> Hash for ValidationError<Key>
+where
+    Key: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+        self.type_id.hash(state);
     }
 }
 
@@ -31,8 +195,18 @@ where
     fn clone(&self) -> Self {
         Self {
             key: self.key.clone(),
-            type_id: self.type_id,
+            type_id: self.type_id.clone(),
             message: self.message.clone(),
+            labelled_message: self.labelled_message.clone(),
+            acknowledged: self.acknowledged,
+            severity: self.severity,
+            help: self.help.clone(),
+            suggestion: self.suggestion.clone(),
+            priority: self.priority,
+            timestamp: self.timestamp,
+            params: self.params.clone(),
+            #[cfg(feature = "serde_json")]
+            meta: self.meta.clone(),
         }
     }
 }
@@ -41,18 +215,57 @@ impl<Key> ValidationError<Key> {
     /// Create a new `ValidationError` with a generic message, and
     /// specify the [type_id](ValidationError::type_id) which allows
     /// the error type to be identified programatically.
-    pub fn new(key: Key, type_id: &'static str) -> Self {
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("email", "INVALID_EMAIL");
+    /// assert_eq!("INVALID_EMAIL", error.type_id);
+    ///
+    /// // a code received from a backend API at runtime
+    /// let code: String = "billing.CARD_DECLINED".to_string();
+    /// let error = ValidationError::new("card", code);
+    /// assert_eq!("billing.CARD_DECLINED", error.type_id);
+    /// ```
+    pub fn new<T: Into<Cow<'static, str>>>(key: Key, type_id: T) -> Self {
         Self {
             key,
-            message: Rc::new(|_| "Validation error".to_string()),
-            type_id,
+            message: Message::Static(Cow::Borrowed("Validation error")),
+            labelled_message: None,
+            type_id: type_id.into(),
+            acknowledged: false,
+            severity: Severity::Error,
+            help: None,
+            suggestion: None,
+            priority: 0,
+            timestamp: None,
+            params: Vec::new(),
+            #[cfg(feature = "serde_json")]
+            meta: std::collections::BTreeMap::new(),
         }
     }
 
-    /// Factory method to set the message for this error.
-    pub fn message<S: Into<String>>(mut self, message: S) -> Self {
-        let message_string = message.into();
-        self.message = Rc::new(move |_| message_string.clone());
+    /// Create a new `ValidationError` with a generic message, using
+    /// the [type_id](ErrorCode::type_id()) of an application's own
+    /// [ErrorCode].
+    pub fn from_code<C: ErrorCode>(key: Key, code: C) -> Self {
+        Self::new(key, code.type_id())
+    }
+
+    /// The application's [ErrorCode] for the
+    /// [type_id](ValidationError::type_id) of this error, or `None` if
+    /// it isn't one of the codes `C` (for example if it was produced
+    /// by a built-in validator).
+    pub fn code<C: ErrorCode>(&self) -> Option<C> {
+        C::from_type_id(&self.type_id)
+    }
+
+    /// Factory method to set the message for this error. A
+    /// `&'static str` message is stored as is, without allocating.
+    pub fn message<S: Into<Cow<'static, str>>>(mut self, message: S) -> Self {
+        self.message = Message::Static(message.into());
+        self.labelled_message = None;
         self
     }
 
@@ -74,20 +287,482 @@ impl<Key> ValidationError<Key> {
     /// assert_eq!("The value of field1 (-10) cannot be less than 0", error.to_string());
     /// assert_eq!("NOT_LESS_THAN_0", error.type_id);
     /// ```
-    pub fn with_message<F: Fn(&Key) -> String + 'static>(mut self, message_fn: F) -> Self {
-        self.message = Rc::new(message_fn);
+    pub fn with_message<F>(mut self, message_fn: F) -> Self
+    where
+        F: Fn(&Key) -> String + MaybeSendSync + 'static,
+    {
+        self.message = Message::Fn(Shared::new(message_fn));
+        self.labelled_message = None;
+        self
+    }
+
+    /// Factory method to set the message for this error from a
+    /// function which writes it directly into a formatter, rather
+    /// than returning a `String`, so that displaying the error doesn't
+    /// require an intermediate allocation.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let min = 18;
+    /// let error = ValidationError::new("age", "TOO_YOUNG")
+    ///     .with_message_fmt(move |key, f| write!(f, "The {} must be at least {}", key, min));
+    ///
+    /// assert_eq!("The age must be at least 18", error.to_string());
+    /// ```
+    pub fn with_message_fmt<F>(mut self, fmt_fn: F) -> Self
+    where
+        F: Fn(&Key, &mut fmt::Formatter<'_>) -> fmt::Result + MaybeSendSync + 'static,
+    {
+        self.message = Message::Fmt(Shared::new(fmt_fn));
+        self.labelled_message = None;
+        self
+    }
+
+    /// Factory method to set the message for this error from a
+    /// function which is given a [MessageContext], carrying the locale
+    /// and application data for the current request, so that the
+    /// message can be localized when it is displayed using
+    /// [ValidationError::message_in()] (or by rendering it with the
+    /// context as a [MessageRenderer](crate::MessageRenderer)). When
+    /// the error is displayed without a context, an empty
+    /// [MessageContext] is used.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{MessageContext, ValidationError};
+    ///
+    /// let error = ValidationError::new("email", "REQUIRED")
+    ///     .with_contextual_message(|key, context| match context.locale() {
+    ///         Some("de") => format!("{} ist erforderlich", key),
+    ///         _ => format!("{} is required", key),
+    ///     });
+    ///
+    /// assert_eq!("email is required", error.to_string());
+    /// assert_eq!(
+    ///     "email ist erforderlich",
+    ///     error.message_in(&MessageContext::new().with_locale("de"))
+    /// );
+    /// ```
+    pub fn with_contextual_message<F>(mut self, message_fn: F) -> Self
+    where
+        F: Fn(&Key, &MessageContext) -> String + MaybeSendSync + 'static,
+    {
+        self.message = Message::Contextual(Shared::new(message_fn));
+        self.labelled_message = None;
+        self
+    }
+
+    /// Get the message for this error, produced using the `context`
+    /// if the message was set using
+    /// [ValidationError::with_contextual_message()].
+    pub fn message_in(&self, context: &MessageContext) -> String {
+        match &self.message {
+            Message::Contextual(message_fn) => message_fn(&self.key, context),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Factory method to set the message for this error from a
+    /// function which is given the label of the field, so that
+    /// messages can refer to a field by its human readable label
+    /// (for example "Email address") when they are produced using
+    /// [ValidationError::message_with_labels()]. When the message is
+    /// produced without a [LabelProvider] (or the provider doesn't have
+    /// a label for the key), the key itself is used as the label.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("email_addr", "REQUIRED")
+    ///     .with_labelled_message(|label| format!("{} is required", label));
+    ///
+    /// assert_eq!("email_addr is required", error.to_string());
+    ///
+    /// let labels = |key: &&str| match *key {
+    ///     "email_addr" => Some("Email address".to_string()),
+    ///     _ => None,
+    /// };
+    /// assert_eq!("Email address is required", error.message_with_labels(&labels));
+    /// ```
+    pub fn with_labelled_message<F>(mut self, message_fn: F) -> Self
+    where
+        Key: Display,
+        F: Fn(&str) -> String + MaybeSendSync + 'static,
+    {
+        let message_fn = Shared::new(message_fn);
+        self.labelled_message = Some(message_fn.clone());
+        self.message = Message::Fn(Shared::new(move |key| message_fn(&key.to_string())));
+        self
+    }
+
+    /// Get the message for this error, using the label of its field
+    /// from `labels` if the message was set using
+    /// [ValidationError::with_labelled_message()].
+    pub fn message_with_labels<L>(&self, labels: &L) -> String
+    where
+        L: LabelProvider<Key> + ?Sized,
+    {
+        match (&self.labelled_message, labels.label(&self.key)) {
+            (Some(labelled_message), Some(label)) => labelled_message(&label),
+            _ => self.get_message(),
+        }
+    }
+
+    /// Convert this error into an error associated with a different
+    /// key. The message for the error continues to be produced using
+    /// the original key.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("street", "REQUIRED")
+    ///     .with_message(|key| format!("{} is required", key))
+    ///     .map_key(|key| format!("address.{}", key));
+    ///
+    /// assert_eq!("address.street", error.key);
+    /// assert_eq!("street is required", error.to_string());
+    /// ```
+    pub fn map_key<NewKey, F>(self, map_fn: F) -> ValidationError<NewKey>
+    where
+        Key: MaybeSendSync + 'static,
+        F: FnOnce(&Key) -> NewKey,
+    {
+        let new_key = map_fn(&self.key);
+        let message = match self.message {
+            Message::Static(message) => Message::Static(message),
+            Message::Fn(message_fn) => {
+                let key = self.key;
+                Message::Fn(Shared::new(move |_: &NewKey| message_fn(&key)))
+            }
+            Message::Fmt(fmt_fn) => {
+                let key = self.key;
+                Message::Fmt(Shared::new(
+                    move |_: &NewKey, f: &mut fmt::Formatter<'_>| fmt_fn(&key, f),
+                ))
+            }
+            Message::Contextual(message_fn) => {
+                let key = self.key;
+                Message::Contextual(Shared::new(move |_: &NewKey, context: &MessageContext| {
+                    message_fn(&key, context)
+                }))
+            }
+        };
+
+        ValidationError {
+            key: new_key,
+            type_id: self.type_id,
+            message,
+            labelled_message: self.labelled_message,
+            acknowledged: self.acknowledged,
+            severity: self.severity,
+            help: self.help,
+            suggestion: self.suggestion,
+            priority: self.priority,
+            timestamp: self.timestamp,
+            params: self.params,
+            #[cfg(feature = "serde_json")]
+            meta: self.meta,
+        }
+    }
+
+    /// Factory method to attach a named parameter describing the rule
+    /// which failed (for example the allowed range, or the expected
+    /// pattern), so that user interfaces can produce their own
+    /// messages.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("age", "TOO_YOUNG")
+    ///     .with_param("min", 18)
+    ///     .message("You must be at least 18");
+    ///
+    /// assert_eq!(Some("18"), error.param("min"));
+    /// assert_eq!(None, error.param("max"));
+    /// ```
+    pub fn with_param<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        V: ToString,
+    {
+        self.params.push((name.into(), value.to_string()));
+        self
+    }
+
+    /// Get the value of the parameter with the specified `name`, see
+    /// [ValidationError::with_param()].
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param_name, _)| param_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get all the values of the parameters with the specified
+    /// `name`, for parameters which have been added more than once
+    /// (for example a list of allowed values).
+    pub fn param_values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.params
+            .iter()
+            .filter(move |(param_name, _)| param_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// All the named parameters attached to this error, in the order
+    /// they were added.
+    pub fn params(&self) -> &[(Cow<'static, str>, String)] {
+        &self.params
+    }
+
+    /// Factory method to attach structured metadata to this error,
+    /// such as the id of an error returned by a backend API, a retry
+    /// hint or analytics tags. Unlike the
+    /// [parameters](ValidationError::param()), metadata isn't intended
+    /// for use in messages, it is carried along with the error
+    /// untouched. Attaching metadata with the same `key` again
+    /// replaces the previous value.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{concat_results, ValidationError, ValidationErrors};
+    /// use serde_json::json;
+    ///
+    /// let result: Result<(), ValidationErrors<&str>> = Err(
+    ///     ValidationError::new("username", "USERNAME_TAKEN")
+    ///         .with_meta("request_id", json!("c0ffee"))
+    ///         .with_meta("retry_after", json!(30))
+    ///         .into(),
+    /// );
+    ///
+    /// let errors = concat_results(vec![Ok(()), result]).unwrap_err();
+    /// assert_eq!(Some(&json!(30)), errors.errors[0].meta("retry_after"));
+    /// assert_eq!(2, errors.errors[0].metadata().len());
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn with_meta<K: Into<String>>(mut self, key: K, value: serde_json::Value) -> Self {
+        self.meta.insert(key.into(), value);
+        self
+    }
+
+    /// Get the metadata with the specified `key`, see
+    /// [ValidationError::with_meta()].
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn meta(&self, key: &str) -> Option<&serde_json::Value> {
+        self.meta.get(key)
+    }
+
+    /// All the metadata attached to this error, see
+    /// [ValidationError::with_meta()].
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn metadata(&self) -> &std::collections::BTreeMap<String, serde_json::Value> {
+        &self.meta
+    }
+
+    /// Factory method to set the [Severity] of this error, which is
+    /// [Severity::Error] by default.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("username", "UNUSUAL_USERNAME").with_severity(Severity::Warning),
+    /// ]);
+    ///
+    /// assert_eq!(Severity::Warning, errors.errors[0].severity());
+    /// assert!(!errors.has_errors());
+    /// ```
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// How severe this error is, see
+    /// [ValidationError::with_severity()].
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Factory method to attach a link to documentation which helps
+    /// the user to fix this error, see [ValidationError::help()].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("vat_number", "INVALID_VAT")
+    ///     .message("Invalid VAT number")
+    ///     .with_help_text("VAT numbers start with a two letter country code")
+    ///     .with_help_url("https://example.com/help/vat");
+    ///
+    /// let help = error.help().unwrap();
+    /// assert_eq!(Some("https://example.com/help/vat"), help.url.as_deref());
+    /// assert_eq!(
+    ///     Some("VAT numbers start with a two letter country code"),
+    ///     help.text.as_deref()
+    /// );
+    /// ```
+    pub fn with_help_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.help.get_or_insert_with(Help::default).url = Some(url.into());
+        self
+    }
+
+    /// Factory method to attach an explanation which helps the user
+    /// to fix this error, which is longer than the message, see
+    /// [ValidationError::help()].
+    pub fn with_help_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.help.get_or_insert_with(Help::default).text = Some(text.into());
+        self
+    }
+
+    /// Help for fixing this error, if any has been attached using
+    /// [ValidationError::with_help_url()] or
+    /// [ValidationError::with_help_text()].
+    pub fn help(&self) -> Option<&Help> {
+        self.help.as_ref()
+    }
+
+    /// Factory method to attach a suggested replacement for the
+    /// value which failed validation, such as a normalized phone
+    /// number or a trimmed string, so that a user interface can offer
+    /// a "did you mean ...?" fix.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    ///
+    /// let error = ValidationError::new("email", "INVALID_EMAIL")
+    ///     .message("Invalid email address")
+    ///     .with_suggestion("alice@gmail.com");
+    ///
+    /// assert_eq!(Some("alice@gmail.com"), error.suggestion());
+    /// ```
+    pub fn with_suggestion<S: Into<String>>(mut self, suggestion: S) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// The suggested replacement for the value, if one has been
+    /// attached using [ValidationError::with_suggestion()].
+    pub fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+
+    /// Factory method to set the priority of this error, which is `0`
+    /// by default. When several rules fail for the same field, errors
+    /// with a higher priority are displayed first, see
+    /// [ValidationErrors::sorted()].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// The priority of this error, see
+    /// [ValidationError::with_priority()].
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Factory method to record the time at which this error was
+    /// produced, as the duration since an arbitrary fixed point (for
+    /// example the value of `performance.now()`, in the same way as
+    /// the [RevalidationScheduler](crate::RevalidationScheduler)), so
+    /// that it can later be checked using
+    /// [ValidationError::is_stale()]. The timestamp is provided by the
+    /// caller, because `std::time::Instant` is unavailable on
+    /// `wasm32-unknown-unknown`. See also [ValidationErrors::stamp()],
+    /// and [FormState::clock()](crate::FormState::clock()) to stamp the
+    /// results of asynchronous validations automatically.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::ValidationError;
+    /// use std::time::Duration;
+    ///
+    /// let error = ValidationError::new("username", "USERNAME_TAKEN")
+    ///     .with_timestamp(Duration::from_secs(10));
+    ///
+    /// assert_eq!(Some(Duration::from_secs(10)), error.timestamp());
+    /// assert!(!error.is_stale(Duration::from_secs(12), Duration::from_secs(5)));
+    /// assert!(error.is_stale(Duration::from_secs(20), Duration::from_secs(5)));
+    /// ```
+    pub fn with_timestamp(mut self, timestamp: Duration) -> Self {
+        self.timestamp = Some(timestamp);
         self
     }
 
+    /// The time at which this error was produced, if it has been
+    /// recorded using [ValidationError::with_timestamp()].
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.timestamp
+    }
+
+    /// Whether this error was produced more than `max_age` before
+    /// the time `now`, for example to check whether the result of an
+    /// asynchronous validation predates the user's latest edits.
+    ///
+    /// Errors without a [timestamp](ValidationError::timestamp())
+    /// (because no clock was provided when they were produced) are
+    /// never considered stale, so that they aren't discarded just
+    /// because their age is unknown.
+    pub fn is_stale(&self, now: Duration, max_age: Duration) -> bool {
+        match self.timestamp {
+            Some(timestamp) => now.saturating_sub(timestamp) > max_age,
+            None => false,
+        }
+    }
+
+    /// Whether the user has acknowledged (dismissed) this error, see
+    /// [ValidationErrors::acknowledge()].
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /// Set whether the user has acknowledged (dismissed) this error.
+    pub fn set_acknowledged(&mut self, acknowledged: bool) {
+        self.acknowledged = acknowledged;
+    }
+
+    /// Redact the text of this error using the `redact` function:
+    /// the values of the [parameters](ValidationError::params()), the
+    /// [suggestion](ValidationError::suggestion()), and the message,
+    /// which is rendered and replaced with its redacted text.
+    pub(crate) fn redact<R>(&mut self, redact: R)
+    where
+        R: Fn(&str) -> String,
+    {
+        for (_, value) in &mut self.params {
+            *value = redact(value);
+        }
+        self.suggestion = self.suggestion.take().map(|suggestion| redact(&suggestion));
+        self.message = Message::Static(Cow::Owned(redact(&self.to_string())));
+        self.labelled_message = None;
+    }
+
     /// Get the message for this error.
     fn get_message(&self) -> String {
-        (self.message)(&self.key)
+        self.to_string()
     }
 }
 
 impl<Key> Display for ValidationError<Key> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.get_message())
+        match &self.message {
+            Message::Static(message) => f.write_str(message),
+            Message::Fn(message_fn) => f.write_str(&message_fn(&self.key)),
+            Message::Fmt(fmt_fn) => fmt_fn(&self.key, f),
+            Message::Contextual(message_fn) => {
+                f.write_str(&message_fn(&self.key, &MessageContext::default()))
+            }
+        }
     }
 }
 
@@ -108,11 +783,178 @@ where
 
 impl<Key> std::error::Error for ValidationError<Key> where Key: Debug {}
 
+/// Serializes the `key`, `type_id`, `message` (rendered when the error
+/// is serialized) and `params` of the error, for example to return
+/// validation errors in the JSON response of an API. Parameters which
+/// have been added more than once are serialized as a list of their
+/// values.
+///
+/// ## Example
+/// ```
+/// use form_validation::{ValidationError, ValidationErrors};
+/// use serde_json::json;
+///
+/// let errors: ValidationErrors<&str> = ValidationError::new("age", "OUT_OF_RANGE")
+///     .with_message(|key| format!("The {} must be between 18 and 130", key))
+///     .with_param("min", 18)
+///     .with_param("max", 130)
+///     .into();
+///
+/// assert_eq!(
+///     json!([{
+///         "key": "age",
+///         "type_id": "OUT_OF_RANGE",
+///         "message": "The age must be between 18 and 130",
+///         "params": { "min": "18", "max": "130" },
+///     }]),
+///     serde_json::to_value(&errors).unwrap()
+/// );
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Key> serde::Serialize for ValidationError<Key>
+where
+    Key: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ValidationError", 4)?;
+        state.serialize_field("key", &self.key)?;
+        state.serialize_field("type_id", &self.type_id)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("params", &SerializeParams(&self.params))?;
+        state.end()
+    }
+}
+
+/// Serializes the parameters of a [ValidationError] as a map from
+/// their names to their values.
+#[cfg(feature = "serde")]
+struct SerializeParams<'a>(&'a [(Cow<'static, str>, String)]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerializeParams<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut names: Vec<&str> = Vec::new();
+        for (name, _) in self.0 {
+            if !names.contains(&name.as_ref()) {
+                names.push(name);
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(names.len()))?;
+        for name in names {
+            let values: Vec<&str> = self
+                .0
+                .iter()
+                .filter(|(param_name, _)| *param_name == name)
+                .map(|(_, value)| value.as_str())
+                .collect();
+            match values.as_slice() {
+                [value] => map.serialize_entry(name, value)?,
+                _ => map.serialize_entry(name, &values)?,
+            }
+        }
+        map.end()
+    }
+}
+
+/// Create a [ValidationError] with a formatted message, without
+/// having to clone the values used in the message before moving them
+/// into a [with_message()](ValidationError::with_message()) closure.
+///
+/// The first argument is the name of a variable containing the key
+/// (or a reference to it), which is cloned for the error, the second
+/// is the [type_id](ValidationError::type_id), and the third is the
+/// format string for the message, in which `{key}` refers to the key
+/// that the message is produced for. The values of any other variables
+/// used in the format string are moved into the message, and
+/// additional `name = expression` arguments are evaluated immediately
+/// (for example to copy a value from behind a reference), and can be
+/// used in the format string as `{name}`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validation_error, Validation, ValidationErrors, ValidatorFn};
+///
+/// let v: ValidatorFn<i32, String> = ValidatorFn::new(|value: &i32, key: &String| {
+///     if *value < 0 {
+///         Err(validation_error!(
+///             key,
+///             "NOT_LESS_THAN_0",
+///             "The value of {key} ({value}) cannot be less than 0",
+///             value = *value
+///         )
+///         .into())
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// let errors: ValidationErrors<String> = v.validate_value(&-1, &"field1".to_string()).unwrap_err();
+/// assert_eq!("NOT_LESS_THAN_0", errors.errors[0].type_id);
+/// assert_eq!(
+///     "The value of field1 (-1) cannot be less than 0",
+///     errors.errors[0].to_string()
+/// );
+///
+/// let limit = 10;
+/// let key = "field2";
+/// let error = validation_error!(key, "TOO_BIG", "{key} must be at most {limit}");
+/// assert_eq!("field2 must be at most 10", error.to_string());
+/// ```
+#[macro_export]
+macro_rules! validation_error {
+    ($key:ident, $type_id:expr, $format:literal $(, $name:ident = $value:expr)* $(,)?) => {{
+        $(let $name = $value;)*
+        $crate::ValidationError::new($key.clone(), $type_id)
+            .with_message(move |$key| format!($format))
+    }};
+}
+
 /// A collection of [ValidationError](ValidationError)s as a result of
 /// validating the fields of a form.
+///
+/// The errors are stored in the order in which they were produced, in
+/// the public `errors` list, rather than being grouped by key, so
+/// looking up the errors for a field with [ValidationErrors::get()]
+/// takes time proportional to the total number of errors. To look up
+/// the errors for many fields, group them once using
+/// [ValidationErrors::by_key()].
 #[derive(Debug, Clone)]
 pub struct ValidationErrors<Key> {
     pub errors: Vec<ValidationError<Key>>,
+    /// The maximum number of errors to hold, see
+    /// [ValidationErrors::with_limit()].
+    limit: Option<usize>,
+    /// The number of errors which were discarded because the limit
+    /// was reached.
+    overflow: usize,
+}
+
+/// Serializes the errors as a list, see the `Serialize`
+/// implementation of [ValidationError].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Key> serde::Serialize for ValidationErrors<Key>
+where
+    Key: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(&self.errors)
+    }
 }
 
 impl<Key> PartialEq for ValidationErrors<Key>
@@ -124,24 +966,28 @@ where
     }
 }
 
+impl<Key> Eq for ValidationErrors<Key> where Key: Eq {}
+
 impl<Key> ValidationErrors<Key>
 where
     Key: PartialEq + Clone,
 {
     /// Create a new `ValidationErrors`.
     pub fn new(errors: Vec<ValidationError<Key>>) -> Self {
-        Self { errors }
+        Self {
+            errors,
+            ..Self::default()
+        }
     }
 
     /// Get errors associated with the specified field key, or `None`
-    /// if there are no errors for that field.
+    /// if there are no errors for that field. This scans all of the
+    /// errors and clones the matching ones, to borrow them instead use
+    /// [ValidationErrors::iter_for_key()], and to look up the errors
+    /// for many fields (for example when rendering a form) use
+    /// [ValidationErrors::by_key()], which groups them by key once.
     pub fn get(&self, key: &Key) -> Option<ValidationErrors<Key>> {
-        let errors: Vec<ValidationError<Key>> = self
-            .errors
-            .iter()
-            .filter(|error| &error.key == key)
-            .map(|error| (*error).clone())
-            .collect();
+        let errors: Vec<ValidationError<Key>> = self.iter_for_key(key).cloned().collect();
 
         if !errors.is_empty() {
             Some(ValidationErrors::new(errors))
@@ -150,6 +996,195 @@ where
         }
     }
 
+    /// Given the order that the fields are displayed in the form,
+    /// returns the key for the first field which has errors. This is
+    /// useful for scrolling to the first error when the form is
+    /// submitted. Errors for keys which are not present in
+    /// `field_order` are considered to come after all those which
+    /// are, in the order that they appear in this collection.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("password", "TOO_SHORT"),
+    ///     ValidationError::new("email", "INVALID_EMAIL"),
+    /// ]);
+    ///
+    /// assert_eq!(Some(&"email"), errors.first_by(&["name", "email", "password"]));
+    /// assert_eq!(Some(&"password"), errors.first_by(&["name"]));
+    /// ```
+    pub fn first_by(&self, field_order: &[Key]) -> Option<&Key> {
+        field_order
+            .iter()
+            .find_map(|key| self.errors.iter().find(|error| &error.key == key))
+            .or_else(|| self.errors.first())
+            .map(|error| &error.key)
+    }
+
+    /// Compare these errors with the errors from a `previous`
+    /// validation run, so that a user interface can animate newly
+    /// appearing errors and precisely clear resolved ones, or a test
+    /// can assert on what a code change fixed. Errors are considered
+    /// the same if they have the same
+    /// [key](ValidationError::key) and
+    /// [type_id](ValidationError::type_id), regardless of their
+    /// message.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let previous = ValidationErrors::new(vec![
+    ///     ValidationError::new("username", "REQUIRED"),
+    ///     ValidationError::new("email", "INVALID_EMAIL"),
+    /// ]);
+    /// let current = ValidationErrors::new(vec![
+    ///     ValidationError::new("email", "INVALID_EMAIL"),
+    ///     ValidationError::new("password", "TOO_SHORT"),
+    /// ]);
+    ///
+    /// let diff = current.diff(&previous);
+    /// assert_eq!("password", diff.added[0].key);
+    /// assert_eq!("username", diff.removed[0].key);
+    /// assert_eq!("email", diff.unchanged[0].key);
+    /// ```
+    pub fn diff(&self, previous: &ValidationErrors<Key>) -> ErrorsDiff<Key> {
+        let (unchanged, added) = self
+            .errors
+            .iter()
+            .cloned()
+            .partition(|error| previous.errors.contains(error));
+        let removed = previous
+            .errors
+            .iter()
+            .filter(|previous| !self.errors.contains(previous))
+            .cloned()
+            .collect();
+
+        ErrorsDiff {
+            added,
+            removed,
+            unchanged,
+        }
+    }
+}
+
+/// The difference between the errors produced by two validation
+/// runs, see [ValidationErrors::diff()].
+#[derive(Debug, Clone)]
+pub struct ErrorsDiff<Key> {
+    /// Errors which are present now, but were not previously.
+    pub added: Vec<ValidationError<Key>>,
+    /// Errors which were present previously, but are not now.
+    pub removed: Vec<ValidationError<Key>>,
+    /// Errors which were present previously, and are still present.
+    pub unchanged: Vec<ValidationError<Key>>,
+}
+
+impl<Key> ErrorsDiff<Key> {
+    /// Whether there are no added or removed errors.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl<Key> ValidationErrors<Key> {
+    /// Create a new empty `ValidationErrors` with space for at least
+    /// `capacity` errors without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            errors: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Create a new empty `ValidationErrors` which holds at most
+    /// `limit` errors. Errors added using
+    /// [extend()](ValidationErrors::extend()) or
+    /// [push()](ValidationErrors::push()) beyond the limit are
+    /// discarded, and counted by
+    /// [overflow()](ValidationErrors::overflow()). This allows bulk
+    /// validation of large datasets to report "too many problems"
+    /// without holding every error in memory.
+    ///
+    /// The limit applies to the errors currently held, so errors can
+    /// be streamed out of the collection (for example to a file)
+    /// using [drain()](ValidationErrors::drain()), to make room for
+    /// more.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, ValidationErrors, Validator};
+    ///
+    /// let v: Validator<i32, usize> = Validator::new()
+    ///     .validation(|value: &i32, key: &usize| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(*key, "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let rows: Vec<i32> = (0..1000).map(|i| -i).collect();
+    ///
+    /// let mut errors = ValidationErrors::with_limit(100);
+    /// for (row, value) in rows.iter().enumerate() {
+    ///     v.validate_value_into(value, &row, &mut errors);
+    /// }
+    /// assert_eq!(100, errors.len());
+    /// assert_eq!(899, errors.overflow());
+    ///
+    /// // streaming the errors out in pages
+    /// let mut errors = ValidationErrors::with_limit(100);
+    /// let mut written = 0;
+    /// for (row, value) in rows.iter().enumerate() {
+    ///     v.validate_value_into(value, &row, &mut errors);
+    ///     if errors.len() == 100 {
+    ///         written += errors.drain().count();
+    ///     }
+    /// }
+    /// written += errors.drain().count();
+    /// assert_eq!(999, written);
+    /// assert_eq!(0, errors.overflow());
+    /// ```
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// The maximum number of errors this collection holds, see
+    /// [with_limit()](ValidationErrors::with_limit()).
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// The number of errors which were discarded because the
+    /// [limit](ValidationErrors::with_limit()) was reached.
+    pub fn overflow(&self) -> usize {
+        self.overflow
+    }
+
+    /// Add an `error` to this collection, or count it as
+    /// [overflow](ValidationErrors::overflow()) if the
+    /// [limit](ValidationErrors::with_limit()) has been reached.
+    pub fn push(&mut self, error: ValidationError<Key>) {
+        match self.limit {
+            Some(limit) if self.errors.len() >= limit => self.overflow += 1,
+            _ => self.errors.push(error),
+        }
+    }
+
+    /// Remove and return all the errors currently held in this
+    /// collection, to stream them elsewhere. The
+    /// [overflow](ValidationErrors::overflow()) count is retained.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, ValidationError<Key>> {
+        self.errors.drain(..)
+    }
+
     /// Returns true if there are no errors in this collection.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
@@ -158,18 +1193,393 @@ where
     /// Extend this collection of errors with the contents of another
     /// collection.
     pub fn extend(&mut self, errors: ValidationErrors<Key>) {
-        self.errors.extend(errors.errors)
+        self.overflow += errors.overflow;
+        match self.limit {
+            Some(limit) => {
+                for error in errors.errors {
+                    if self.errors.len() >= limit {
+                        self.overflow += 1;
+                    } else {
+                        self.errors.push(error);
+                    }
+                }
+            }
+            None => self.errors.extend(errors.errors),
+        }
+    }
+
+    /// Discard the errors after the first `len`, and restore the
+    /// [overflow](ValidationErrors::overflow()) count to `overflow`,
+    /// undoing a validation which didn't complete.
+    pub(crate) fn truncate(&mut self, len: usize, overflow: usize) {
+        self.errors.truncate(len);
+        self.overflow = overflow;
     }
 
     /// The number of errors in this collection.
     pub fn len(&self) -> usize {
         self.errors.len()
     }
+
+    /// Remove all the errors from this collection, retaining the
+    /// allocated capacity so that it can be reused for another
+    /// validation with [Validation::validate_value_into()](crate::Validation::validate_value_into()),
+    /// and resetting the [overflow](ValidationErrors::overflow()) count.
+    pub fn clear(&mut self) {
+        self.errors.clear();
+        self.overflow = 0;
+    }
+
+    /// Acknowledge (dismiss) the errors associated with the
+    /// specified `key` which have the specified `type_id`, without
+    /// removing them. This supports "I understand, continue anyway"
+    /// flows. The acknowledgement can be undone with
+    /// [unacknowledge()](ValidationErrors::unacknowledge()). Returns
+    /// `true` if any errors were acknowledged.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let mut errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("username", "UNUSUAL_USERNAME"),
+    ///     ValidationError::new("password", "TOO_SHORT"),
+    /// ]);
+    ///
+    /// assert!(errors.acknowledge(&"username", "UNUSUAL_USERNAME"));
+    /// assert_eq!(2, errors.len());
+    /// assert_eq!(1, errors.unacknowledged().len());
+    ///
+    /// errors.unacknowledge(&"username", "UNUSUAL_USERNAME");
+    /// assert_eq!(2, errors.unacknowledged().len());
+    /// ```
+    pub fn acknowledge(&mut self, key: &Key, type_id: &str) -> bool
+    where
+        Key: PartialEq,
+    {
+        self.set_acknowledged(key, type_id, true)
+    }
+
+    /// Undo the acknowledgement of errors performed using
+    /// [acknowledge()](ValidationErrors::acknowledge()). Returns
+    /// `true` if any errors were unacknowledged.
+    pub fn unacknowledge(&mut self, key: &Key, type_id: &str) -> bool
+    where
+        Key: PartialEq,
+    {
+        self.set_acknowledged(key, type_id, false)
+    }
+
+    fn set_acknowledged(&mut self, key: &Key, type_id: &str, acknowledged: bool) -> bool
+    where
+        Key: PartialEq,
+    {
+        let mut changed = false;
+        for error in &mut self.errors {
+            if &error.key == key && error.type_id == type_id && error.acknowledged != acknowledged {
+                error.acknowledged = acknowledged;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// The errors in this collection which have not been
+    /// acknowledged by the user.
+    pub fn unacknowledged(&self) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        ValidationErrors {
+            errors: self
+                .errors
+                .iter()
+                .filter(|error| !error.acknowledged)
+                .cloned()
+                .collect(),
+            limit: self.limit,
+            overflow: self.overflow,
+        }
+    }
+
+    /// Convert the result of a validation into one which only fails
+    /// if there are blocking errors (with the [Severity::Error]
+    /// severity). If there are none, returns `Ok` with the remaining
+    /// non-blocking warnings and informational notices (which may be
+    /// empty), otherwise returns `Err` with all of the errors,
+    /// including the warnings, so they can still be displayed.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, ValidationError, ValidationErrors};
+    ///
+    /// let result: Result<(), ValidationErrors<&str>> = Err(
+    ///     ValidationError::new("password", "WEAK_PASSWORD")
+    ///         .with_severity(Severity::Warning)
+    ///         .into(),
+    /// );
+    /// let warnings = ValidationErrors::partition_result(result).unwrap();
+    /// assert_eq!("WEAK_PASSWORD", warnings.errors[0].type_id);
+    ///
+    /// assert!(ValidationErrors::<&str>::partition_result(Ok(())).unwrap().is_empty());
+    /// ```
+    pub fn partition_result(
+        result: Result<(), ValidationErrors<Key>>,
+    ) -> Result<ValidationErrors<Key>, ValidationErrors<Key>> {
+        match result {
+            Ok(()) => Ok(ValidationErrors::default()),
+            Err(errors) if errors.has_errors() => Err(errors),
+            Err(warnings) => Ok(warnings),
+        }
+    }
+
+    /// Record the time `now` as the
+    /// [timestamp](ValidationError::with_timestamp()) of each of the
+    /// errors in this collection which doesn't already have one, for
+    /// example when the result of an asynchronous validation is
+    /// received.
+    pub fn stamp(&mut self, now: Duration) {
+        for error in &mut self.errors {
+            error.timestamp.get_or_insert(now);
+        }
+    }
+
+    /// Remove the errors in this collection which are
+    /// [stale](ValidationError::is_stale()) at the time `now`.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    /// use std::time::Duration;
+    ///
+    /// let mut errors: ValidationErrors<&str> =
+    ///     ValidationError::new("username", "USERNAME_TAKEN").into();
+    /// errors.stamp(Duration::from_secs(10));
+    /// errors.push(ValidationError::new("email", "REQUIRED").with_timestamp(Duration::from_secs(18)));
+    ///
+    /// errors.remove_stale(Duration::from_secs(20), Duration::from_secs(5));
+    /// assert_eq!(1, errors.len());
+    /// assert_eq!("REQUIRED", errors.errors[0].type_id);
+    /// ```
+    pub fn remove_stale(&mut self, now: Duration, max_age: Duration) {
+        self.retain(|error| !error.is_stale(now, max_age));
+    }
+
+    /// Remove the errors for the field with the specified `key`, for
+    /// example when the user edits the field, returning the removed
+    /// errors, or `None` if there were no errors for that field.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let mut errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("email", "REQUIRED"),
+    ///     ValidationError::new("username", "TOO_SHORT"),
+    ///     ValidationError::new("username", "INVALID_IDENTIFIER"),
+    /// ]);
+    ///
+    /// let removed = errors.remove(&"username").unwrap();
+    /// assert_eq!(2, removed.len());
+    /// assert_eq!(1, errors.len());
+    /// assert!(errors.remove(&"username").is_none());
+    ///
+    /// errors.retain(|error| error.type_id != "REQUIRED");
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &Key) -> Option<ValidationErrors<Key>>
+    where
+        Key: PartialEq,
+    {
+        let (removed, kept): (Vec<_>, Vec<_>) =
+            self.errors.drain(..).partition(|error| &error.key == key);
+        self.errors = kept;
+
+        if removed.is_empty() {
+            None
+        } else {
+            Some(ValidationErrors {
+                errors: removed,
+                ..ValidationErrors::default()
+            })
+        }
+    }
+
+    /// Retain only the errors for which `predicate` returns `true`.
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: FnMut(&ValidationError<Key>) -> bool,
+    {
+        self.errors.retain(predicate);
+    }
+
+    /// Whether this collection contains any errors with the
+    /// [Severity::Error] severity, which make the form invalid.
+    pub fn has_errors(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|error| error.severity == Severity::Error)
+    }
+
+    /// Whether this collection contains an error with the specified
+    /// [type_id](ValidationError::type_id), such as one of the
+    /// [codes](crate::codes) of the built-in validators.
+    pub fn has_code(&self, type_id: &str) -> bool {
+        self.errors.iter().any(|error| error.type_id == type_id)
+    }
+
+    /// Whether this collection contains an error for the field with
+    /// the specified `key`, with the specified
+    /// [type_id](ValidationError::type_id).
+    pub fn has_code_for(&self, key: &Key, type_id: &str) -> bool
+    where
+        Key: PartialEq,
+    {
+        self.errors
+            .iter()
+            .any(|error| &error.key == key && error.type_id == type_id)
+    }
+
+    /// The errors in this collection with the specified
+    /// [type_id](ValidationError::type_id), such as one of the
+    /// [codes](crate::codes) of the built-in validators.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{codes, ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("email", codes::REQUIRED),
+    ///     ValidationError::new("username", codes::LENGTH_OUT_OF_RANGE),
+    ///     ValidationError::new("name", codes::REQUIRED),
+    /// ]);
+    ///
+    /// let required = errors.filter_type_id(codes::REQUIRED);
+    /// assert_eq!(vec![&"email", &"name"], required.keys().collect::<Vec<_>>());
+    /// ```
+    pub fn filter_type_id(&self, type_id: &str) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        ValidationErrors {
+            errors: self
+                .errors
+                .iter()
+                .filter(|error| error.type_id == type_id)
+                .cloned()
+                .collect(),
+            limit: self.limit,
+            overflow: self.overflow,
+        }
+    }
+
+    /// The errors in this collection with the specified `severity`.
+    pub fn of_severity(&self, severity: Severity) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        ValidationErrors {
+            errors: self
+                .errors
+                .iter()
+                .filter(|error| error.severity == severity)
+                .cloned()
+                .collect(),
+            limit: self.limit,
+            overflow: self.overflow,
+        }
+    }
+
+    /// The errors in this collection with the [Severity::Error]
+    /// severity, which make the form invalid.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("username", "UNUSUAL_USERNAME").with_severity(Severity::Warning),
+    ///     ValidationError::new("email", "REQUIRED"),
+    /// ]);
+    ///
+    /// assert_eq!("REQUIRED", errors.errors_only().errors[0].type_id);
+    /// assert_eq!("UNUSUAL_USERNAME", errors.warnings_only().errors[0].type_id);
+    /// assert!(errors.has_errors());
+    /// ```
+    pub fn errors_only(&self) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        self.of_severity(Severity::Error)
+    }
+
+    /// The errors in this collection with the [Severity::Warning]
+    /// severity.
+    pub fn warnings_only(&self) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        self.of_severity(Severity::Warning)
+    }
+
+    /// The errors in this collection ordered by their
+    /// [priority](ValidationError::priority()), highest first, so that
+    /// the most relevant message for a field is consistently
+    /// displayed first. Errors with the same priority keep the order
+    /// in which they were produced.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("name", "TOO_SHORT"),
+    ///     ValidationError::new("name", "REQUIRED").with_priority(10),
+    /// ]);
+    ///
+    /// let sorted = errors.sorted();
+    /// assert_eq!("REQUIRED", sorted.errors[0].type_id);
+    /// assert_eq!("TOO_SHORT", sorted.errors[1].type_id);
+    /// ```
+    pub fn sorted(&self) -> ValidationErrors<Key>
+    where
+        Key: Clone,
+    {
+        let mut errors = self.errors.clone();
+        errors.sort_by_key(|error| std::cmp::Reverse(error.priority));
+        ValidationErrors {
+            errors,
+            limit: self.limit,
+            overflow: self.overflow,
+        }
+    }
+
+    /// Convert these errors into errors associated with a different
+    /// key type, using [ValidationError::map_key()].
+    pub fn map_keys<NewKey, F>(self, mut map_fn: F) -> ValidationErrors<NewKey>
+    where
+        Key: MaybeSendSync + 'static,
+        F: FnMut(&Key) -> NewKey,
+    {
+        ValidationErrors {
+            errors: self
+                .errors
+                .into_iter()
+                .map(|error| error.map_key(&mut map_fn))
+                .collect(),
+            limit: self.limit,
+            overflow: self.overflow,
+        }
+    }
 }
 
 impl<Key> Default for ValidationErrors<Key> {
     fn default() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limit: None,
+            overflow: 0,
+        }
     }
 }
 
@@ -190,3 +1600,158 @@ where
         ValidationErrors::new(vec![err])
     }
 }
+
+impl<Key> ValidationErrors<Key> {
+    /// An iterator over the errors in this collection.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, ValidationError, ValidationErrors};
+    ///
+    /// let errors: ValidationErrors<&str> = vec![
+    ///     ValidationError::new("email", "REQUIRED"),
+    ///     ValidationError::new("username", "UNUSUAL_USERNAME").with_severity(Severity::Warning),
+    ///     ValidationError::new("age", "OUT_OF_RANGE"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let blocking: ValidationErrors<&str> = errors
+    ///     .iter()
+    ///     .filter(|error| error.severity() == Severity::Error)
+    ///     .cloned()
+    ///     .collect();
+    /// assert_eq!(2, blocking.len());
+    ///
+    /// let mut keys = Vec::new();
+    /// for error in &errors {
+    ///     keys.push(error.key);
+    /// }
+    /// assert_eq!(vec!["email", "username", "age"], keys);
+    ///
+    /// // `Extend` respects the limit of the collection
+    /// let mut limited: ValidationErrors<&str> = ValidationErrors::with_limit(1);
+    /// Extend::extend(&mut limited, errors);
+    /// assert_eq!(1, limited.len());
+    /// assert_eq!(2, limited.overflow());
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationError<Key>> {
+        self.errors.iter()
+    }
+
+    /// A mutable iterator over the errors in this collection.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, ValidationError<Key>> {
+        self.errors.iter_mut()
+    }
+
+    /// The keys of the errors in this collection, with a key for
+    /// each error (so keys for fields with more than one error are
+    /// repeated), see also [ValidationErrors::unique_keys()].
+    pub fn keys(&self) -> impl Iterator<Item = &Key> + '_ {
+        self.errors.iter().map(|error| &error.key)
+    }
+
+    /// The keys of the fields which have errors, without duplicates,
+    /// in the order in which they first appear in this collection.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("email", "REQUIRED").message("Email is required"),
+    ///     ValidationError::new("username", "TOO_SHORT").message("Username is too short"),
+    ///     ValidationError::new("email", "INVALID_EMAIL").message("Email is invalid"),
+    /// ]);
+    ///
+    /// assert_eq!(3, errors.keys().count());
+    /// assert_eq!(
+    ///     vec![&"email", &"username"],
+    ///     errors.unique_keys().collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(
+    ///     vec!["Email is required", "Username is too short", "Email is invalid"],
+    ///     errors.messages().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn unique_keys(&self) -> impl Iterator<Item = &Key> + '_
+    where
+        Key: PartialEq,
+    {
+        self.errors
+            .iter()
+            .enumerate()
+            .filter(move |(index, error)| {
+                !self.errors[..*index]
+                    .iter()
+                    .any(|previous| previous.key == error.key)
+            })
+            .map(|(_, error)| &error.key)
+    }
+
+    /// The messages of the errors in this collection, rendered using
+    /// their `Display` implementation.
+    pub fn messages(&self) -> impl Iterator<Item = String> + '_ {
+        self.errors.iter().map(|error| error.to_string())
+    }
+}
+
+impl<Key> IntoIterator for ValidationErrors<Key> {
+    type Item = ValidationError<Key>;
+    type IntoIter = std::vec::IntoIter<ValidationError<Key>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, Key> IntoIterator for &'a ValidationErrors<Key> {
+    type Item = &'a ValidationError<Key>;
+    type IntoIter = std::slice::Iter<'a, ValidationError<Key>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<Key> std::iter::FromIterator<ValidationError<Key>> for ValidationErrors<Key> {
+    fn from_iter<I: IntoIterator<Item = ValidationError<Key>>>(iter: I) -> Self {
+        let mut errors = ValidationErrors::default();
+        Extend::extend(&mut errors, iter);
+        errors
+    }
+}
+
+/// Appends the errors using [ValidationErrors::push()], respecting the
+/// [limit](ValidationErrors::with_limit()) of the collection.
+impl<Key> Extend<ValidationError<Key>> for ValidationErrors<Key> {
+    fn extend<I: IntoIterator<Item = ValidationError<Key>>>(&mut self, iter: I) {
+        for error in iter {
+            self.push(error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ValidationError, ValidationErrors};
+
+    /// Errors are compared by their key and type id only, not by their
+    /// messages.
+    #[test]
+    fn errors_with_different_messages_are_equal() {
+        let required = ValidationError::new("email", "REQUIRED").message("Email is required");
+        let reworded = ValidationError::new("email", "REQUIRED")
+            .with_message(|key| format!("Please enter your {}", key));
+
+        assert_ne!(required.to_string(), reworded.to_string());
+        assert_eq!(required, reworded);
+        assert_eq!(
+            ValidationErrors::from(required.clone()),
+            ValidationErrors::from(reworded)
+        );
+
+        assert_ne!(required, ValidationError::new("email", "INVALID_EMAIL"));
+        assert_ne!(required, ValidationError::new("username", "REQUIRED"));
+    }
+}
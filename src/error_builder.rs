@@ -0,0 +1,172 @@
+use crate::{MaybeSendSync, Severity, ValidationError};
+use std::{borrow::Cow, fmt::Display, marker::PhantomData};
+
+/// The state of a part of a [ValidationErrorBuilder] which hasn't
+/// been set yet.
+#[derive(Debug, Clone, Copy)]
+pub struct Unset;
+
+/// The state of a part of a [ValidationErrorBuilder] which has been
+/// set.
+#[derive(Debug, Clone, Copy)]
+pub struct Set;
+
+/// A builder for a [ValidationError], obtained using
+/// [ValidationError::builder()]. The
+/// [build()](ValidationErrorBuilder::build()) method only exists once
+/// both the [type_id](ValidationErrorBuilder::code()) and the message
+/// have been set, so that errors can't accidentally be produced with
+/// the generic `"Validation error"` message.
+///
+/// ## Example
+/// ```
+/// use form_validation::{Severity, ValidationError};
+///
+/// let error = ValidationError::builder("age")
+///     .code("TOO_YOUNG")
+///     .with_param("min", 18)
+///     .message("You must be at least 18")
+///     .with_severity(Severity::Warning)
+///     .build();
+///
+/// assert_eq!("TOO_YOUNG", error.type_id);
+/// assert_eq!("You must be at least 18", error.to_string());
+/// ```
+///
+/// Without a message, the error can't be built:
+///
+/// ```compile_fail
+/// use form_validation::ValidationError;
+///
+/// let error = ValidationError::builder("age").code("TOO_YOUNG").build();
+/// ```
+pub struct ValidationErrorBuilder<Key, Code = Unset, Message = Unset> {
+    error: ValidationError<Key>,
+    state: PhantomData<(Code, Message)>,
+}
+
+impl<Key> ValidationError<Key> {
+    /// Create a [ValidationErrorBuilder] for an error associated with
+    /// the field with the specified `key`.
+    pub fn builder(key: Key) -> ValidationErrorBuilder<Key> {
+        ValidationErrorBuilder {
+            error: ValidationError::new(key, ""),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<Key, Code, Message> ValidationErrorBuilder<Key, Code, Message> {
+    fn transition<NewCode, NewMessage>(
+        error: ValidationError<Key>,
+    ) -> ValidationErrorBuilder<Key, NewCode, NewMessage> {
+        ValidationErrorBuilder {
+            error,
+            state: PhantomData,
+        }
+    }
+
+    /// See [ValidationError::with_param()].
+    pub fn with_param<V: ToString>(mut self, name: &'static str, value: V) -> Self {
+        self.error = self.error.with_param(name, value);
+        self
+    }
+
+    /// See [ValidationError::with_severity()].
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.error = self.error.with_severity(severity);
+        self
+    }
+
+    /// See [ValidationError::with_help_text()].
+    pub fn with_help_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.error = self.error.with_help_text(text);
+        self
+    }
+
+    /// See [ValidationError::with_help_url()].
+    pub fn with_help_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.error = self.error.with_help_url(url);
+        self
+    }
+
+    /// See [ValidationError::with_suggestion()].
+    pub fn with_suggestion<S: Into<String>>(mut self, suggestion: S) -> Self {
+        self.error = self.error.with_suggestion(suggestion);
+        self
+    }
+
+    /// See [ValidationError::with_priority()].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.error = self.error.with_priority(priority);
+        self
+    }
+}
+
+impl<Key, Message> ValidationErrorBuilder<Key, Unset, Message> {
+    /// Set the [type_id](ValidationError::type_id) of the error.
+    pub fn code<T: Into<Cow<'static, str>>>(
+        self,
+        type_id: T,
+    ) -> ValidationErrorBuilder<Key, Set, Message> {
+        let mut error = self.error;
+        error.type_id = type_id.into();
+        Self::transition(error)
+    }
+}
+
+impl<Key, Code> ValidationErrorBuilder<Key, Code, Unset> {
+    /// Set the message of the error, see [ValidationError::message()].
+    pub fn message<S: Into<Cow<'static, str>>>(
+        self,
+        message: S,
+    ) -> ValidationErrorBuilder<Key, Code, Set> {
+        Self::transition(self.error.message(message))
+    }
+
+    /// Set the message of the error from a function, see
+    /// [ValidationError::with_message()].
+    pub fn with_message<F>(self, message_fn: F) -> ValidationErrorBuilder<Key, Code, Set>
+    where
+        F: Fn(&Key) -> String + MaybeSendSync + 'static,
+    {
+        Self::transition(self.error.with_message(message_fn))
+    }
+
+    /// Set the message of the error from a function which writes it
+    /// into a formatter, see [ValidationError::with_message_fmt()].
+    pub fn with_message_fmt<F>(self, fmt_fn: F) -> ValidationErrorBuilder<Key, Code, Set>
+    where
+        F: Fn(&Key, &mut std::fmt::Formatter<'_>) -> std::fmt::Result + MaybeSendSync + 'static,
+    {
+        Self::transition(self.error.with_message_fmt(fmt_fn))
+    }
+
+    /// Set the message of the error from a function which is given
+    /// the label of the field, see
+    /// [ValidationError::with_labelled_message()].
+    pub fn with_labelled_message<F>(self, message_fn: F) -> ValidationErrorBuilder<Key, Code, Set>
+    where
+        Key: Display,
+        F: Fn(&str) -> String + MaybeSendSync + 'static,
+    {
+        Self::transition(self.error.with_labelled_message(message_fn))
+    }
+
+    /// Set the message of the error from a function which is given a
+    /// [MessageContext](crate::MessageContext), see
+    /// [ValidationError::with_contextual_message()].
+    pub fn with_contextual_message<F>(self, message_fn: F) -> ValidationErrorBuilder<Key, Code, Set>
+    where
+        F: Fn(&Key, &crate::MessageContext) -> String + MaybeSendSync + 'static,
+    {
+        Self::transition(self.error.with_contextual_message(message_fn))
+    }
+}
+
+impl<Key> ValidationErrorBuilder<Key, Set, Set> {
+    /// Build the [ValidationError].
+    pub fn build(self) -> ValidationError<Key> {
+        self.error
+    }
+}
@@ -0,0 +1,199 @@
+use crate::ValidationErrors;
+use std::{fmt::Debug, rc::Rc};
+
+#[cfg(feature = "async")]
+use futures::channel::mpsc;
+
+/// An identifier for a subscription registered with
+/// [ErrorsSubscriptions], used to
+/// [unsubscribe()](ErrorsSubscriptions::unsubscribe()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+type SubscriptionCallback<Key> = Rc<dyn Fn(&Key, &ValidationErrors<Key>)>;
+
+struct Subscription<Key> {
+    id: SubscriptionId,
+    /// The key of the field this subscription is for, or `None` for
+    /// a subscription to all fields.
+    key: Option<Key>,
+    callback: SubscriptionCallback<Key>,
+}
+
+/// A registry of callbacks which are notified with the updated
+/// errors for a field, whenever the errors for that field change.
+/// This allows user interface frameworks with fine-grained
+/// reactivity (such as Leptos or Sycamore) to update a signal per
+/// field, instead of re-reading the whole error collection after
+/// each validation run.
+///
+/// Errors are considered to have changed when errors with a new
+/// [type_id](crate::ValidationError::type_id) appear for a field, or
+/// existing ones are resolved (see [ValidationErrors::diff()]).
+/// Usually this is used via [FormState::subscribe()](crate::FormState::subscribe()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{ErrorsSubscriptions, ValidationError, ValidationErrors};
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// let mut subscriptions = ErrorsSubscriptions::new();
+///
+/// let username_errors = Rc::new(RefCell::new(0));
+/// let username_errors_subscriber = username_errors.clone();
+/// subscriptions.subscribe("username", move |errors: &ValidationErrors<&'static str>| {
+///     *username_errors_subscriber.borrow_mut() = errors.len();
+/// });
+///
+/// let previous = ValidationErrors::default();
+/// let current = ValidationErrors::new(vec![ValidationError::new("username", "REQUIRED")]);
+/// subscriptions.publish(&previous, &current);
+/// assert_eq!(1, *username_errors.borrow());
+///
+/// subscriptions.publish(&current, &ValidationErrors::default());
+/// assert_eq!(0, *username_errors.borrow());
+/// ```
+pub struct ErrorsSubscriptions<Key> {
+    subscriptions: Vec<Subscription<Key>>,
+    next_id: usize,
+}
+
+impl<Key> ErrorsSubscriptions<Key>
+where
+    Key: Clone + PartialEq,
+{
+    /// Create a new `ErrorsSubscriptions` without any subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe to changes of the errors for the field with the
+    /// specified `key`. The `callback` is called with all the current
+    /// errors for the field (which are empty once the field is
+    /// valid).
+    pub fn subscribe<F>(&mut self, key: Key, callback: F) -> SubscriptionId
+    where
+        F: Fn(&ValidationErrors<Key>) + 'static,
+        Key: 'static,
+    {
+        self.insert(
+            Some(key),
+            Rc::new(move |_: &Key, errors: &ValidationErrors<Key>| callback(errors)),
+        )
+    }
+
+    /// Subscribe to changes of the errors for all the fields. The
+    /// `callback` is called once for each field whose errors have
+    /// changed, with the key of the field and all its current errors.
+    pub fn subscribe_all<F>(&mut self, callback: F) -> SubscriptionId
+    where
+        F: Fn(&Key, &ValidationErrors<Key>) + 'static,
+        Key: 'static,
+    {
+        self.insert(None, Rc::new(callback))
+    }
+
+    /// Subscribe to changes of the errors for the field with the
+    /// specified `key`, receiving the updates on a channel, for
+    /// example to drive an asynchronous task per field. Updates which
+    /// are published after the receiver has been dropped are
+    /// discarded.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ErrorsSubscriptions, ValidationError, ValidationErrors};
+    /// use futures::{executor::block_on, StreamExt};
+    ///
+    /// let mut subscriptions = ErrorsSubscriptions::new();
+    /// let mut username_errors = subscriptions.channel("username");
+    ///
+    /// let current = ValidationErrors::new(vec![ValidationError::new("username", "REQUIRED")]);
+    /// subscriptions.publish(&ValidationErrors::default(), &current);
+    ///
+    /// let errors = block_on(username_errors.next()).unwrap();
+    /// assert_eq!("REQUIRED", errors.errors[0].type_id);
+    /// ```
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn channel(&mut self, key: Key) -> mpsc::UnboundedReceiver<ValidationErrors<Key>>
+    where
+        Key: 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribe(key, move |errors: &ValidationErrors<Key>| {
+            // the receiver may have been dropped, in which case there
+            // is nobody interested in the update.
+            let _ = sender.unbounded_send(errors.clone());
+        });
+        receiver
+    }
+
+    /// Remove the subscription with the specified `id`, returning
+    /// `true` if it was found.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len_before = self.subscriptions.len();
+        self.subscriptions
+            .retain(|subscription| subscription.id != id);
+        self.subscriptions.len() != len_before
+    }
+
+    /// Whether there are no subscriptions.
+    pub fn is_empty(&self) -> bool {
+        self.subscriptions.is_empty()
+    }
+
+    /// Notify the subscribers of each field whose errors differ
+    /// between the `previous` and the `current` errors.
+    pub fn publish(&self, previous: &ValidationErrors<Key>, current: &ValidationErrors<Key>) {
+        if self.subscriptions.is_empty() {
+            return;
+        }
+
+        let diff = current.diff(previous);
+        let mut changed_keys: Vec<&Key> = Vec::new();
+        for error in diff.added.iter().chain(diff.removed.iter()) {
+            if !changed_keys.contains(&&error.key) {
+                changed_keys.push(&error.key);
+            }
+        }
+
+        for key in changed_keys {
+            let errors = current.get(key).unwrap_or_default();
+            for subscription in &self.subscriptions {
+                let matches = match &subscription.key {
+                    Some(subscription_key) => subscription_key == key,
+                    None => true,
+                };
+                if matches {
+                    (subscription.callback)(key, &errors);
+                }
+            }
+        }
+    }
+
+    fn insert(&mut self, key: Option<Key>, callback: SubscriptionCallback<Key>) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.subscriptions.push(Subscription { id, key, callback });
+        id
+    }
+}
+
+impl<Key> Default for ErrorsSubscriptions<Key> {
+    fn default() -> Self {
+        Self {
+            subscriptions: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<Key> Debug for ErrorsSubscriptions<Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ErrorsSubscriptions {{ len: {} }}",
+            self.subscriptions.len()
+        )
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{ValidationError, ValidationErrors};
+use std::{collections::HashMap, hash::Hash};
+
+/// An index of the errors in a [ValidationErrors] grouped by the key
+/// of their field, obtained using [ValidationErrors::by_key()]. Build
+/// it once (for example each time the form is rendered), which scans
+/// the errors a single time, and then look up the errors for each
+/// field in constant time without cloning them, rather than calling
+/// [ValidationErrors::get()](ValidationErrors::get()) for each field,
+/// which scans and clones the errors every time.
+///
+/// The keys are kept in the order in which they first appear in the
+/// collection, and the errors for each key in the order in which they
+/// were produced.
+///
+/// ## Example
+/// ```
+/// use form_validation::{ValidationError, ValidationErrors};
+///
+/// let errors = ValidationErrors::new(vec![
+///     ValidationError::new("email", "REQUIRED"),
+///     ValidationError::new("username", "TOO_SHORT"),
+///     ValidationError::new("email", "INVALID_EMAIL"),
+/// ]);
+///
+/// let by_key = errors.by_key();
+/// let email_errors = by_key.get(&"email");
+/// assert_eq!(2, email_errors.len());
+/// assert_eq!("INVALID_EMAIL", email_errors[1].type_id);
+/// assert!(by_key.get(&"password").is_empty());
+/// assert_eq!(vec![&"email", &"username"], by_key.keys().collect::<Vec<_>>());
+/// ```
+#[derive(Debug)]
+pub struct ErrorsByKey<'a, Key> {
+    groups: Vec<(&'a Key, Vec<&'a ValidationError<Key>>)>,
+    index: HashMap<&'a Key, usize>,
+}
+
+impl<'a, Key> ErrorsByKey<'a, Key>
+where
+    Key: Hash + Eq,
+{
+    fn new(errors: &'a ValidationErrors<Key>) -> Self {
+        let mut groups: Vec<(&'a Key, Vec<&'a ValidationError<Key>>)> = Vec::new();
+        let mut index: HashMap<&'a Key, usize> = HashMap::new();
+
+        for error in &errors.errors {
+            let group = *index.entry(&error.key).or_insert_with(|| {
+                groups.push((&error.key, Vec::new()));
+                groups.len() - 1
+            });
+            groups[group].1.push(error);
+        }
+
+        Self { groups, index }
+    }
+
+    /// The errors for the field with the specified `key`, which is
+    /// empty if there are none.
+    pub fn get(&self, key: &Key) -> &[&'a ValidationError<Key>] {
+        match self.index.get(key) {
+            Some(group) => &self.groups[*group].1,
+            None => &[],
+        }
+    }
+
+    /// Whether there are any errors for the field with the specified
+    /// `key`.
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// The keys of the fields which have errors.
+    pub fn keys(&self) -> impl Iterator<Item = &'a Key> + '_ {
+        self.groups.iter().map(|(key, _)| *key)
+    }
+
+    /// The keys of the fields which have errors, along with their
+    /// errors.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Key, &[&'a ValidationError<Key>])> + '_ {
+        self.groups
+            .iter()
+            .map(|(key, errors)| (*key, errors.as_slice()))
+    }
+
+    /// The number of fields which have errors.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Whether there are no fields with errors.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl<Key> ValidationErrors<Key> {
+    /// Group these errors by the key of their field, see
+    /// [ErrorsByKey].
+    pub fn by_key(&self) -> ErrorsByKey<'_, Key>
+    where
+        Key: Hash + Eq,
+    {
+        ErrorsByKey::new(self)
+    }
+
+    /// Convert these errors into a map from the key of each field to
+    /// the messages of its errors, for example for a templating engine,
+    /// or the JSON response of an API.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, ValidationErrors};
+    ///
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new("email", "REQUIRED").message("Email is required"),
+    ///     ValidationError::new("email", "INVALID_EMAIL").message("Email is invalid"),
+    /// ]);
+    ///
+    /// let map = errors.to_map();
+    /// assert_eq!(
+    ///     &vec!["Email is required".to_string(), "Email is invalid".to_string()],
+    ///     map.get("email").unwrap()
+    /// );
+    /// assert_eq!(2, errors.to_error_map()[&"email"].len());
+    /// ```
+    pub fn to_map(&self) -> HashMap<Key, Vec<String>>
+    where
+        Key: Hash + Eq + Clone,
+    {
+        self.by_key()
+            .iter()
+            .map(|(key, errors)| {
+                let messages = errors.iter().map(|error| error.to_string()).collect();
+                (key.clone(), messages)
+            })
+            .collect()
+    }
+
+    /// Convert these errors into a map from the key of each field to
+    /// its errors, see [ValidationErrors::to_map()].
+    pub fn to_error_map(&self) -> HashMap<Key, Vec<ValidationError<Key>>>
+    where
+        Key: Hash + Eq + Clone,
+    {
+        self.by_key()
+            .iter()
+            .map(|(key, errors)| {
+                let errors = errors.iter().map(|error| (*error).clone()).collect();
+                (key.clone(), errors)
+            })
+            .collect()
+    }
+
+    /// The errors associated with the specified field `key`, without
+    /// cloning them as [ValidationErrors::get()] does.
+    pub fn iter_for_key<'a>(
+        &'a self,
+        key: &'a Key,
+    ) -> impl Iterator<Item = &'a ValidationError<Key>> + 'a
+    where
+        Key: PartialEq,
+    {
+        self.errors.iter().filter(move |error| &error.key == key)
+    }
+}
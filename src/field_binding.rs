@@ -0,0 +1,129 @@
+use crate::{Validation, ValidationErrors, Validator};
+
+/// The state of a single form field, independent of any particular
+/// user interface framework. It bundles the current value of the
+/// field, the [Validator] used to validate it, the key for the field,
+/// whether the user has interacted with the field yet (whether it is
+/// "touched"), and the errors from the most recent validation.
+///
+/// The field is validated when it loses focus ([on_blur()]), and
+/// after that, every time its value is changed
+/// ([set_value()](FieldBinding::set_value())). This avoids showing
+/// errors to the user before they have finished entering the value
+/// for the first time.
+///
+/// [on_blur()]: FieldBinding::on_blur()
+///
+/// ## Example
+/// ```
+/// use form_validation::{FieldBinding, ValidationError, Validator};
+///
+/// let validator: Validator<String, &str> = Validator::new()
+///     .validation(|value: &String, key: &&'static str| {
+///         if value.is_empty() {
+///             Err(ValidationError::new(*key, "REQUIRED").into())
+///         } else {
+///             Ok(())
+///         }
+///     });
+///
+/// let mut field = FieldBinding::new("name", String::new(), validator);
+///
+/// // not validated until the field has been touched
+/// field.set_value("".to_string());
+/// assert!(field.errors().is_empty());
+///
+/// field.on_blur();
+/// assert!(field.is_touched());
+/// assert_eq!(1, field.errors().len());
+///
+/// // once touched, changes are validated immediately
+/// field.set_value("Alice".to_string());
+/// assert!(field.errors().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct FieldBinding<Value, Key> {
+    key: Key,
+    value: Value,
+    validator: Validator<Value, Key>,
+    touched: bool,
+    errors: ValidationErrors<Key>,
+}
+
+impl<Value, Key> FieldBinding<Value, Key>
+where
+    Key: Clone + PartialEq,
+{
+    /// Create a new `FieldBinding` for the field with the specified
+    /// `key`, which has not yet been touched.
+    pub fn new(key: Key, value: Value, validator: Validator<Value, Key>) -> Self {
+        Self {
+            key,
+            value,
+            validator,
+            touched: false,
+            errors: ValidationErrors::default(),
+        }
+    }
+
+    /// The key for this field.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// The current value of this field.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// The validator used to validate this field.
+    pub fn validator(&self) -> &Validator<Value, Key> {
+        &self.validator
+    }
+
+    /// Whether the user has interacted with this field.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// The errors produced by the most recent validation of this
+    /// field.
+    pub fn errors(&self) -> &ValidationErrors<Key> {
+        &self.errors
+    }
+
+    /// Set the value of this field (for example when the user types
+    /// into it). The field is validated if it has been touched.
+    pub fn set_value(&mut self, value: Value) {
+        self.value = value;
+
+        if self.touched {
+            self.validate();
+        }
+    }
+
+    /// Call when the field loses focus. Marks the field as touched
+    /// and validates it.
+    pub fn on_blur(&mut self) {
+        self.touched = true;
+        self.validate();
+    }
+
+    /// Validate the current value of this field, storing the errors
+    /// (available via [errors()](FieldBinding::errors())), and
+    /// returning `true` if the value is valid.
+    pub fn validate(&mut self) -> bool {
+        self.errors.clear();
+        self.validator
+            .validate_value_into(&self.value, &self.key, &mut self.errors);
+        !self.errors.has_errors()
+    }
+
+    /// Reset this field to the specified `value`, clearing the
+    /// touched flag and any errors.
+    pub fn reset(&mut self, value: Value) {
+        self.value = value;
+        self.touched = false;
+        self.errors.clear();
+    }
+}
@@ -0,0 +1,156 @@
+use crate::ValidationErrors;
+use std::{borrow::Cow, fmt::Display};
+
+/// A segment of a [FieldPath].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PathSegment {
+    /// A named field, or section of a form.
+    Field(Cow<'static, str>),
+    /// An index into a collection of items.
+    Index(usize),
+}
+
+/// A key for a field within a nested form, made up of a sequence of
+/// named fields and collection indices, for example
+/// `billing.address.street` or `items[2].sku`.
+///
+/// ## Example
+/// ```
+/// use form_validation::FieldPath;
+///
+/// let path = FieldPath::new().field("items").index(2).field("sku");
+/// assert_eq!("items[2].sku", path.to_string());
+/// assert!(path.starts_with(&FieldPath::new().field("items")));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FieldPath {
+    segments: Vec<PathSegment>,
+}
+
+impl FieldPath {
+    /// Create a new empty `FieldPath`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Factory method to append a named field segment to this path.
+    pub fn field<S: Into<Cow<'static, str>>>(mut self, name: S) -> Self {
+        self.segments.push(PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Factory method to append a collection index segment to this
+    /// path.
+    pub fn index(mut self, index: usize) -> Self {
+        self.segments.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Factory method to append all the segments of `other` to this
+    /// path.
+    pub fn join(mut self, other: &FieldPath) -> Self {
+        self.segments.extend(other.segments.iter().cloned());
+        self
+    }
+
+    /// The segments which make up this path.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Whether this path is `prefix`, or is nested within `prefix`.
+    pub fn starts_with(&self, prefix: &FieldPath) -> bool {
+        self.segments.starts_with(&prefix.segments)
+    }
+}
+
+impl From<&'static str> for FieldPath {
+    fn from(name: &'static str) -> Self {
+        FieldPath::new().field(name)
+    }
+}
+
+impl From<String> for FieldPath {
+    fn from(name: String) -> Self {
+        FieldPath::new().field(name)
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) => {
+                    if i > 0 {
+                        write!(f, ".")?;
+                    }
+                    write!(f, "{}", name)?;
+                }
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A key which can identify a field nested within the field or
+/// section of a form identified by another key. This is used by
+/// `#[derive(Validatable)]` to prefix the keys of the errors of a
+/// `#[validate(nested)]` form with the key of the field containing
+/// it.
+///
+/// ## Example
+/// ```
+/// use form_validation::{FieldPath, NestedKey};
+///
+/// let street = FieldPath::from("address").nested(&FieldPath::from("street"));
+/// assert_eq!("address.street", street.to_string());
+///
+/// let street = "address".to_string().nested(&"street".to_string());
+/// assert_eq!("address.street", street);
+/// ```
+pub trait NestedKey {
+    /// The key for the `child` field nested within this one.
+    fn nested(&self, child: &Self) -> Self;
+}
+
+impl NestedKey for FieldPath {
+    fn nested(&self, child: &Self) -> Self {
+        self.clone().join(child)
+    }
+}
+
+impl NestedKey for String {
+    fn nested(&self, child: &Self) -> Self {
+        format!("{}.{}", self, child)
+    }
+}
+
+impl ValidationErrors<FieldPath> {
+    /// The number of errors for fields within the section of the form
+    /// at `prefix`, for example to display a "Billing (2 errors)"
+    /// badge on a tab. Use
+    /// [ValidationErrors::unacknowledged()] first, to only count the
+    /// errors which the user hasn't dismissed.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FieldPath, ValidationError, ValidationErrors};
+    ///
+    /// let billing = FieldPath::new().field("billing");
+    /// let errors = ValidationErrors::new(vec![
+    ///     ValidationError::new(billing.clone().field("street"), "REQUIRED"),
+    ///     ValidationError::new(billing.clone().field("postcode"), "REQUIRED"),
+    ///     ValidationError::new(FieldPath::from("email"), "REQUIRED"),
+    /// ]);
+    ///
+    /// assert_eq!(2, errors.count_by_prefix(&billing));
+    /// assert_eq!(0, errors.count_by_prefix(&FieldPath::from("shipping")));
+    /// ```
+    pub fn count_by_prefix(&self, prefix: &FieldPath) -> usize {
+        self.errors
+            .iter()
+            .filter(|error| error.key.starts_with(prefix))
+            .count()
+    }
+}
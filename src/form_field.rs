@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+/// A field of a form, used as a strongly typed key for its errors
+/// instead of a string, so that typos in keys are caught at compile
+/// time. Usually this is implemented for an enum with a variant per
+/// field, generated using `#[derive(FormFields)]` (which requires the
+/// `"derive"` feature), which also implements `Display`, `FromStr`,
+/// and conversions into `&'static str` and [FieldPath](crate::FieldPath).
+///
+/// ## Example
+/// ```
+/// use form_validation::FormField;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum SignupField {
+///     Username,
+///     Email,
+/// }
+///
+/// impl FormField for SignupField {
+///     const ALL: &'static [Self] = &[SignupField::Username, SignupField::Email];
+///
+///     fn name(&self) -> &'static str {
+///         match self {
+///             SignupField::Username => "username",
+///             SignupField::Email => "email",
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Some(SignupField::Email), SignupField::from_name("email"));
+/// assert_eq!(None, SignupField::from_name("password"));
+/// ```
+pub trait FormField: Copy + Eq + 'static {
+    /// All the fields of the form, in the order they are declared.
+    const ALL: &'static [Self];
+
+    /// The name of this field.
+    fn name(&self) -> &'static str;
+
+    /// The field with the specified `name`, if there is one.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|field| field.name() == name)
+    }
+}
+
+/// The error produced when parsing the name of a field which doesn't
+/// exist, for example by the `FromStr` implementation generated by
+/// `#[derive(FormFields)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    /// The name which was parsed.
+    pub name: String,
+}
+
+impl Display for UnknownField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown field {:?}", self.name)
+    }
+}
+
+impl std::error::Error for UnknownField {}
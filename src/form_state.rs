@@ -0,0 +1,1109 @@
+use crate::{
+    scheduler::Clock, ErrorsMiddleware, ErrorsPipeline, ErrorsSubscriptions, MaybeSendSync,
+    RevalidationScheduler, Severity, SubscriptionId, Validatable, ValidationErrors,
+};
+use std::{borrow::Cow, time::Duration};
+
+#[cfg(feature = "serde")]
+use crate::{Help, ValidationError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// When the fields of a [FormState] are revalidated in response to
+/// the user interacting with them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RevalidationStrategy {
+    /// Validate a field every time its value changes, and when it
+    /// loses focus.
+    OnChange,
+    /// Validate a field only when it loses focus.
+    OnBlur,
+    /// Only validate the form when it is submitted (or
+    /// [FormState::validate()] is called).
+    OnSubmit,
+    /// "Reward early, punish late": a field is validated when it
+    /// loses focus, and while it has errors, it is also validated
+    /// every time its value changes, so that the errors disappear as
+    /// soon as they are fixed. A valid field is not validated while
+    /// the user is still typing in it.
+    #[default]
+    Smart,
+}
+
+/// The validation status of a field in a [FormState].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldStatus {
+    /// The field has not been validated yet.
+    NotValidated,
+    /// The field is currently being validated asynchronously.
+    Validating,
+    /// The field was valid when it was last validated.
+    Valid,
+    /// The field was invalid when it was last validated (it has
+    /// errors with the [Severity::Error] severity).
+    Invalid,
+}
+
+/// The state of a whole form, independent of any particular user
+/// interface framework. It holds the `Form` itself (typically a
+/// struct implementing [Validatable]), the keys for all the fields in
+/// the form (in the order they are displayed), which fields the user
+/// has interacted with (which are "touched"), and the errors from
+/// the most recent validation.
+///
+/// ## Example
+/// ```
+/// use form_validation::{FormState, Validatable, ValidationError, ValidationErrors};
+///
+/// struct Signup {
+///     username: String,
+/// }
+///
+/// impl Validatable<&'static str> for Signup {
+///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+///         if self.username.is_empty() {
+///             Err(ValidationError::new("username", "REQUIRED").into())
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// let mut state = FormState::new(Signup { username: String::new() }, vec!["username"]);
+///
+/// assert!(!state.validate());
+/// assert_eq!(1, state.errors().len());
+///
+/// state.form_mut().username = "alice".to_string();
+/// assert!(state.validate());
+/// ```
+#[derive(Debug)]
+pub struct FormState<Form, Key> {
+    form: Form,
+    fields: Vec<Key>,
+    touched: Vec<Key>,
+    pub(crate) errors: ValidationErrors<Key>,
+    pub(crate) submitting: bool,
+    strategy: RevalidationStrategy,
+    validated: Vec<Key>,
+    pub(crate) validating: Vec<Key>,
+    middleware: ErrorsPipeline<Key>,
+    subscriptions: ErrorsSubscriptions<Key>,
+    scheduler: RevalidationScheduler<Key>,
+    acknowledged: Vec<(Key, Cow<'static, str>)>,
+    clock: Option<Clock>,
+}
+
+impl<Form, Key> FormState<Form, Key>
+where
+    Key: Clone + PartialEq,
+{
+    /// Create a new `FormState` for the `form`, which contains the
+    /// specified `fields`. None of the fields are touched.
+    pub fn new(form: Form, fields: Vec<Key>) -> Self {
+        Self {
+            form,
+            fields,
+            touched: Vec::new(),
+            errors: ValidationErrors::default(),
+            submitting: false,
+            strategy: RevalidationStrategy::default(),
+            validated: Vec::new(),
+            validating: Vec::new(),
+            middleware: ErrorsPipeline::new(),
+            subscriptions: ErrorsSubscriptions::new(),
+            scheduler: RevalidationScheduler::new(Duration::ZERO),
+            acknowledged: Vec::new(),
+            clock: None,
+        }
+    }
+
+    /// A factory method to install an [ErrorsMiddleware] on this form,
+    /// which is applied to all new errors for the form (from
+    /// validation, [set_errors()](FormState::set_errors()), remote
+    /// errors and submission), before they are stored. See
+    /// [ErrorsPipeline].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, Validatable, ValidationError, ValidationErrors};
+    ///
+    /// struct Signup;
+    ///
+    /// impl Validatable<&'static str> for Signup {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         Err(ValidationError::new("beta_feature", "REQUIRED").into())
+    ///     }
+    /// }
+    ///
+    /// let mut state = FormState::new(Signup, vec!["beta_feature"])
+    ///     .middleware(|error: ValidationError<&'static str>| {
+    ///         if error.key == "beta_feature" {
+    ///             None
+    ///         } else {
+    ///             Some(error)
+    ///         }
+    ///     });
+    ///
+    /// assert!(state.validate());
+    /// ```
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: ErrorsMiddleware<Key> + 'static,
+    {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Subscribe to changes of the errors for the field with the
+    /// specified `key`, so that a user interface with fine-grained
+    /// reactivity can update just that field. See
+    /// [ErrorsSubscriptions].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, Validatable, ValidationError, ValidationErrors};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// struct Signup {
+    ///     username: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Signup {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         if self.username.is_empty() {
+    ///             Err(ValidationError::new("username", "REQUIRED").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut state = FormState::new(Signup { username: String::new() }, vec!["username"]);
+    ///
+    /// let updates = Rc::new(Cell::new(0));
+    /// let updates_subscriber = updates.clone();
+    /// state.subscribe("username", move |_errors: &ValidationErrors<&'static str>| {
+    ///     updates_subscriber.set(updates_subscriber.get() + 1);
+    /// });
+    ///
+    /// state.validate();
+    /// assert_eq!(1, updates.get());
+    ///
+    /// // the errors for the field haven't changed
+    /// state.validate();
+    /// assert_eq!(1, updates.get());
+    ///
+    /// state.form_mut().username = "alice".to_string();
+    /// state.validate();
+    /// assert_eq!(2, updates.get());
+    /// ```
+    pub fn subscribe<F>(&mut self, key: Key, callback: F) -> SubscriptionId
+    where
+        F: Fn(&ValidationErrors<Key>) + 'static,
+        Key: 'static,
+    {
+        self.subscriptions.subscribe(key, callback)
+    }
+
+    /// Subscribe to changes of the errors for all the fields, see
+    /// [ErrorsSubscriptions::subscribe_all()].
+    pub fn subscribe_all<F>(&mut self, callback: F) -> SubscriptionId
+    where
+        F: Fn(&Key, &ValidationErrors<Key>) + 'static,
+        Key: 'static,
+    {
+        self.subscriptions.subscribe_all(callback)
+    }
+
+    /// Subscribe to changes of the errors for the field with the
+    /// specified `key`, receiving the updates on a channel, see
+    /// [ErrorsSubscriptions::channel()].
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn errors_channel(
+        &mut self,
+        key: Key,
+    ) -> futures::channel::mpsc::UnboundedReceiver<ValidationErrors<Key>>
+    where
+        Key: 'static,
+    {
+        self.subscriptions.channel(key)
+    }
+
+    /// Remove a subscription created using
+    /// [subscribe()](FormState::subscribe()),
+    /// [subscribe_all()](FormState::subscribe_all()) or
+    /// [errors_channel()](FormState::errors_channel()), returning
+    /// `true` if it was found.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        self.subscriptions.unsubscribe(id)
+    }
+
+    /// A factory method to set the [RevalidationStrategy] for this
+    /// form (the default is [RevalidationStrategy::Smart]).
+    pub fn strategy(mut self, strategy: RevalidationStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// A factory method to set the quiet period used by
+    /// [schedule_field_changed()](FormState::schedule_field_changed())
+    /// to coalesce bursts of field changes into a single validation
+    /// pass (the default is zero).
+    pub fn revalidation_delay(mut self, delay: Duration) -> Self {
+        self.scheduler = RevalidationScheduler::new(delay);
+        self
+    }
+
+    /// A factory method to provide the current time (as the duration
+    /// since an arbitrary fixed point, for example the value of
+    /// `performance.now()`), which is recorded as the
+    /// [timestamp](crate::ValidationError::timestamp()) of the errors
+    /// received using
+    /// [finish_validating()](FormState::finish_validating()), so that
+    /// the results of asynchronous validations which are received
+    /// after the user has moved on can be detected using
+    /// [ValidationError::is_stale()](crate::ValidationError::is_stale()).
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, ValidationError};
+    /// use std::time::Duration;
+    ///
+    /// let mut state: FormState<(), &str> =
+    ///     FormState::new((), vec!["username"]).clock(|| Duration::from_secs(10));
+    ///
+    /// state.begin_validating("username");
+    /// state.finish_validating(
+    ///     &"username",
+    ///     ValidationError::new("username", "USERNAME_TAKEN").into(),
+    /// );
+    ///
+    /// let error = &state.errors().errors[0];
+    /// assert_eq!(Some(Duration::from_secs(10)), error.timestamp());
+    /// assert!(error.is_stale(Duration::from_secs(20), Duration::from_secs(5)));
+    /// ```
+    pub fn clock<F>(mut self, now: F) -> Self
+    where
+        F: Fn() -> Duration + 'static,
+    {
+        self.clock = Some(Clock::new(now));
+        self
+    }
+
+    /// The time at which the fields changed using
+    /// [schedule_field_changed()](FormState::schedule_field_changed())
+    /// are due to be revalidated, or `None` if there are no such
+    /// changes.
+    pub fn scheduled_revalidation(&self) -> Option<Duration> {
+        self.scheduler.deadline()
+    }
+
+    /// The [RevalidationStrategy] for this form.
+    pub fn revalidation_strategy(&self) -> RevalidationStrategy {
+        self.strategy
+    }
+
+    /// The form.
+    pub fn form(&self) -> &Form {
+        &self.form
+    }
+
+    /// The form, for modification.
+    pub fn form_mut(&mut self) -> &mut Form {
+        &mut self.form
+    }
+
+    /// The keys for all the fields in the form.
+    pub fn fields(&self) -> &[Key] {
+        &self.fields
+    }
+
+    /// Whether the user has interacted with the field with the
+    /// specified `key`.
+    pub fn is_touched(&self, key: &Key) -> bool {
+        self.touched.contains(key)
+    }
+
+    /// Mark the field with the specified `key` as touched.
+    pub fn touch(&mut self, key: Key) {
+        if !self.touched.contains(&key) {
+            self.touched.push(key);
+        }
+    }
+
+    /// Mark all the fields in the form as touched.
+    pub fn touch_all(&mut self) {
+        for key in self.fields.clone() {
+            self.touch(key);
+        }
+    }
+
+    /// The errors for the form.
+    pub fn errors(&self) -> &ValidationErrors<Key> {
+        &self.errors
+    }
+
+    /// Acknowledge (dismiss) the errors for the field with the
+    /// specified `key` which have the specified `type_id`, see
+    /// [ValidationErrors::acknowledge()]. Returns `true` if any
+    /// errors were acknowledged.
+    ///
+    /// The acknowledgement is remembered by the form, and reapplied
+    /// to the errors produced when the field is revalidated, for as
+    /// long as the field continues to have an error with that
+    /// `type_id`. Once the error is fixed, the acknowledgement is
+    /// forgotten, so that the user is warned again if the error
+    /// returns.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, Severity, Validatable, ValidationError, ValidationErrors};
+    ///
+    /// struct Signup {
+    ///     password: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Signup {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         if self.password.len() < 12 {
+    ///             Err(ValidationError::new("password", "WEAK_PASSWORD")
+    ///                 .with_severity(Severity::Warning)
+    ///                 .into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut state = FormState::new(Signup { password: "hunter2".to_string() }, vec!["password"]);
+    /// state.validate();
+    ///
+    /// // "I understand, continue anyway"
+    /// assert!(state.acknowledge(&"password", "WEAK_PASSWORD"));
+    ///
+    /// // the acknowledgement survives revalidation
+    /// state.form_mut().password = "hunter22".to_string();
+    /// state.field_blurred(&"password");
+    /// assert!(state.errors().errors[0].is_acknowledged());
+    /// assert!(state.errors().unacknowledged().is_empty());
+    ///
+    /// state.validate();
+    /// assert!(state.errors().errors[0].is_acknowledged());
+    ///
+    /// // once the error is fixed, the acknowledgement is forgotten
+    /// state.form_mut().password = "correct horse battery".to_string();
+    /// state.validate();
+    /// state.form_mut().password = "hunter2".to_string();
+    /// state.validate();
+    /// assert!(!state.errors().errors[0].is_acknowledged());
+    /// ```
+    pub fn acknowledge(&mut self, key: &Key, type_id: &str) -> bool {
+        let acknowledged = self.errors.acknowledge(key, type_id);
+        let has_error = self
+            .errors
+            .iter_for_key(key)
+            .any(|error| error.type_id == type_id);
+        if has_error && !self.is_acknowledged(key, type_id) {
+            self.acknowledged
+                .push((key.clone(), Cow::Owned(type_id.to_string())));
+        }
+        acknowledged
+    }
+
+    /// Undo the acknowledgement of errors performed using
+    /// [acknowledge()](FormState::acknowledge()).
+    pub fn unacknowledge(&mut self, key: &Key, type_id: &str) -> bool {
+        self.acknowledged
+            .retain(|(ack_key, ack_type_id)| !(ack_key == key && ack_type_id == type_id));
+        self.errors.unacknowledge(key, type_id)
+    }
+
+    fn is_acknowledged(&self, key: &Key, type_id: &str) -> bool {
+        self.acknowledged
+            .iter()
+            .any(|(ack_key, ack_type_id)| ack_key == key && ack_type_id == type_id)
+    }
+
+    /// Replace the errors for the form.
+    pub fn set_errors(&mut self, errors: ValidationErrors<Key>) {
+        let previous = self.errors_before_update();
+        self.errors = self.middleware.apply(errors);
+        self.errors_updated(previous);
+    }
+
+    /// Append new `errors` for the form, after applying the
+    /// middleware.
+    #[cfg(feature = "async")]
+    pub(crate) fn extend_errors(&mut self, errors: ValidationErrors<Key>) {
+        let previous = self.errors_before_update();
+        let errors = self.middleware.apply(errors);
+        self.errors.extend(errors);
+        self.errors_updated(previous);
+    }
+
+    /// Apply errors for the fields of this form which were produced
+    /// remotely (for example in a `422 Unprocessable Entity` response
+    /// from the server), where the remote key type differs from the
+    /// key type used in this form. `map_key` converts the remote keys
+    /// into keys for this form, returning `None` if the remote key
+    /// doesn't correspond to a field in this form.
+    ///
+    /// The errors replace any existing errors for the affected
+    /// fields, and the affected fields are marked as touched so that
+    /// the errors will be displayed. Errors which could not be mapped
+    /// to a field are returned, so that they can be displayed
+    /// elsewhere.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, ValidationError, ValidationErrors};
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Debug)]
+    /// enum Field {
+    ///     EmailAddress,
+    /// }
+    ///
+    /// let mut state = FormState::new((), vec![Field::EmailAddress]);
+    ///
+    /// let remote_errors: ValidationErrors<String> = ValidationErrors::new(vec![
+    ///     ValidationError::new("email_address".to_string(), "EMAIL_TAKEN"),
+    ///     ValidationError::new("account".to_string(), "ACCOUNT_LOCKED"),
+    /// ]);
+    ///
+    /// let unmapped = state.apply_remote_errors(remote_errors, |key| match key.as_str() {
+    ///     "email_address" => Some(Field::EmailAddress),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!("EMAIL_TAKEN", state.errors().get(&Field::EmailAddress).unwrap().errors[0].type_id);
+    /// assert!(state.is_touched(&Field::EmailAddress));
+    /// assert_eq!("ACCOUNT_LOCKED", unmapped.errors[0].type_id);
+    /// ```
+    pub fn apply_remote_errors<RemoteKey, F>(
+        &mut self,
+        errors: ValidationErrors<RemoteKey>,
+        mut map_key: F,
+    ) -> ValidationErrors<RemoteKey>
+    where
+        RemoteKey: MaybeSendSync + 'static,
+        F: FnMut(&RemoteKey) -> Option<Key>,
+    {
+        let mut mapped: ValidationErrors<Key> = ValidationErrors::default();
+        let mut unmapped: ValidationErrors<RemoteKey> = ValidationErrors::default();
+
+        for error in errors.errors {
+            match map_key(&error.key) {
+                Some(key) => mapped.errors.push(error.map_key(|_| key)),
+                None => unmapped.errors.push(error),
+            }
+        }
+
+        let previous = self.errors_before_update();
+        self.errors
+            .errors
+            .retain(|error| !mapped.errors.iter().any(|mapped| mapped.key == error.key));
+
+        for error in self.middleware.apply(mapped).errors {
+            self.touch(error.key.clone());
+            self.errors.errors.push(error);
+        }
+        self.errors_updated(previous);
+
+        unmapped
+    }
+
+    /// The [FieldStatus] of the field with the specified `key`.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FieldStatus, FormState, ValidationError, ValidationErrors};
+    ///
+    /// let mut state = FormState::new((), vec!["username"]);
+    /// assert_eq!(FieldStatus::NotValidated, state.field_status(&"username"));
+    ///
+    /// // a remote check for whether the username is available
+    /// state.begin_validating("username");
+    /// assert_eq!(FieldStatus::Validating, state.field_status(&"username"));
+    /// assert!(state.is_validating());
+    ///
+    /// state.finish_validating(
+    ///     &"username",
+    ///     ValidationError::new("username", "USERNAME_TAKEN").into(),
+    /// );
+    /// assert_eq!(FieldStatus::Invalid, state.field_status(&"username"));
+    /// assert!(!state.is_validating());
+    /// ```
+    pub fn field_status(&self, key: &Key) -> FieldStatus {
+        if self.validating.contains(key) {
+            FieldStatus::Validating
+        } else if self
+            .errors
+            .errors
+            .iter()
+            .any(|error| &error.key == key && error.severity() == Severity::Error)
+        {
+            FieldStatus::Invalid
+        } else if self.validated.contains(key) {
+            FieldStatus::Valid
+        } else {
+            FieldStatus::NotValidated
+        }
+    }
+
+    /// Whether any of the fields are currently being validated
+    /// asynchronously. Useful for disabling the submit button while
+    /// remote validation is outstanding.
+    pub fn is_validating(&self) -> bool {
+        !self.validating.is_empty()
+    }
+
+    /// Mark the field with the specified `key` as being validated
+    /// asynchronously, until
+    /// [finish_validating()](FormState::finish_validating()) is
+    /// called.
+    pub fn begin_validating(&mut self, key: Key) {
+        if !self.validating.contains(&key) {
+            self.validating.push(key);
+        }
+    }
+
+    /// Complete the asynchronous validation of the field with the
+    /// specified `key`, replacing the errors for that field with
+    /// `errors`.
+    pub fn finish_validating(&mut self, key: &Key, errors: ValidationErrors<Key>) {
+        self.validating
+            .retain(|validating_key| validating_key != key);
+        let previous = self.errors_before_update();
+        self.errors.errors.retain(|error| &error.key != key);
+        let mut errors = self.middleware.apply(errors);
+        if let Some(clock) = &self.clock {
+            errors.stamp(clock.now());
+        }
+        self.errors.extend(errors);
+        self.errors_updated(previous);
+        self.mark_validated(key.clone());
+    }
+
+    /// A copy of the errors before they are updated, to
+    /// [errors_updated()](FormState::errors_updated()) against, if
+    /// there are any subscriptions.
+    fn errors_before_update(&self) -> Option<ValidationErrors<Key>> {
+        if self.subscriptions.is_empty() {
+            None
+        } else {
+            Some(self.errors.clone())
+        }
+    }
+
+    /// Reapply the acknowledgements to the updated errors, forgetting
+    /// those which no longer have a matching error, and then notify
+    /// the subscriptions of the fields whose errors have changed since
+    /// the `previous` errors.
+    fn errors_updated(&mut self, previous: Option<ValidationErrors<Key>>) {
+        let errors = &mut self.errors;
+        self.acknowledged.retain(|(key, type_id)| {
+            errors.acknowledge(key, type_id);
+            errors
+                .iter_for_key(key)
+                .any(|error| &error.type_id == type_id)
+        });
+
+        if let Some(previous) = previous {
+            self.subscriptions.publish(&previous, &self.errors);
+        }
+    }
+
+    fn mark_validated(&mut self, key: Key) {
+        if !self.validated.contains(&key) {
+            self.validated.push(key);
+        }
+    }
+
+    /// Whether the form is currently being submitted.
+    pub fn is_submitting(&self) -> bool {
+        self.submitting
+    }
+}
+
+impl<Form, Key> FormState<Form, Key>
+where
+    Form: Validatable<Key>,
+    Key: Clone + PartialEq,
+{
+    /// Validate the form, storing the errors (available via
+    /// [errors()](FormState::errors())), and returning `true` if the
+    /// form is valid.
+    pub fn validate(&mut self) -> bool {
+        let previous = self.errors_before_update();
+        self.errors = self.middleware.apply(self.form.validate_or_empty());
+        self.errors_updated(previous);
+        self.validated = self.fields.clone();
+        !self.errors.has_errors()
+    }
+
+    /// Validate the form, but only update the errors for the field
+    /// with the specified `key`, returning `true` if that field is
+    /// valid.
+    pub fn validate_field(&mut self, key: &Key) -> bool {
+        self.validate_fields(std::slice::from_ref(key))
+    }
+
+    /// Validate the form once, but only update the errors for the
+    /// fields with the specified `keys`, returning `true` if all of
+    /// those fields are valid.
+    pub fn validate_fields(&mut self, keys: &[Key]) -> bool {
+        let new_errors = self.middleware.apply(self.form.validate_or_empty());
+        let previous = self.errors_before_update();
+        self.errors
+            .errors
+            .retain(|error| !keys.contains(&error.key));
+        for key in keys {
+            self.mark_validated(key.clone());
+        }
+
+        let mut valid = true;
+        for error in new_errors.errors {
+            if keys.contains(&error.key) {
+                valid &= error.severity() != Severity::Error;
+                self.errors.errors.push(error);
+            }
+        }
+        self.errors_updated(previous);
+        valid
+    }
+
+    /// Call after the value of the field with the specified `key` has
+    /// been changed (for example when the user types into it), to
+    /// revalidate it according to the [RevalidationStrategy].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{
+    ///     FormState, RevalidationStrategy, Validatable, ValidationError, ValidationErrors,
+    /// };
+    ///
+    /// struct Signup {
+    ///     username: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Signup {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         if self.username.len() < 3 {
+    ///             Err(ValidationError::new("username", "TOO_SHORT").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut state = FormState::new(Signup { username: String::new() }, vec!["username"])
+    ///     .strategy(RevalidationStrategy::Smart);
+    ///
+    /// // no errors while the user is still typing
+    /// state.form_mut().username = "a".to_string();
+    /// state.field_changed(&"username");
+    /// assert!(state.errors().is_empty());
+    ///
+    /// // punish late: errors are shown once the field loses focus
+    /// state.field_blurred(&"username");
+    /// assert_eq!(1, state.errors().len());
+    ///
+    /// // reward early: errors disappear as soon as they are fixed
+    /// state.form_mut().username = "abc".to_string();
+    /// state.field_changed(&"username");
+    /// assert!(state.errors().is_empty());
+    /// ```
+    pub fn field_changed(&mut self, key: &Key) {
+        if self.revalidates_on_change(key) {
+            self.validate_field(key);
+        }
+    }
+
+    /// Whether the field with the specified `key` should be
+    /// revalidated after its value has changed, according to the
+    /// [RevalidationStrategy].
+    fn revalidates_on_change(&self, key: &Key) -> bool {
+        match self.strategy {
+            RevalidationStrategy::OnChange => true,
+            RevalidationStrategy::OnBlur | RevalidationStrategy::OnSubmit => false,
+            RevalidationStrategy::Smart => self.errors.errors.iter().any(|error| &error.key == key),
+        }
+    }
+
+    /// Call after the values of the fields with the specified `keys`
+    /// have been replaced all at once, rather than typed by the user,
+    /// for example when a framework integration detects that the
+    /// browser has autofilled the form, or a value has been pasted.
+    /// The fields are marked as touched, and validated immediately in
+    /// a single pass (unless the [RevalidationStrategy] is
+    /// [OnSubmit](RevalidationStrategy::OnSubmit)), so that autofilled
+    /// values receive feedback without waiting for each field to lose
+    /// focus.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormState, Validatable, ValidationError, ValidationErrors};
+    ///
+    /// struct Login {
+    ///     email: String,
+    ///     password: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Login {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         if self.email.contains('@') {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(ValidationError::new("email", "INVALID_EMAIL").into())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let login = Login { email: String::new(), password: String::new() };
+    /// let mut state = FormState::new(login, vec!["email", "password"]);
+    ///
+    /// // the browser autofilled the saved credentials
+    /// state.form_mut().email = "alice.example.com".to_string();
+    /// state.form_mut().password = "hunter2".to_string();
+    /// state.values_replaced(&["email", "password"]);
+    ///
+    /// assert!(state.is_touched(&"email"));
+    /// assert!(state.is_touched(&"password"));
+    /// assert_eq!("INVALID_EMAIL", state.errors().errors[0].type_id);
+    /// ```
+    pub fn values_replaced(&mut self, keys: &[Key]) {
+        for key in keys {
+            self.touch(key.clone());
+        }
+
+        if self.strategy != RevalidationStrategy::OnSubmit {
+            self.validate_fields(keys);
+        }
+    }
+
+    /// An alternative to [field_changed()](FormState::field_changed())
+    /// which coalesces bursts of changes (for example when the
+    /// browser autofills many fields at once) into a single batched
+    /// validation pass. Records that the field with the specified
+    /// `key` changed at the time `now` (see [RevalidationScheduler]),
+    /// and returns the time at which the changed fields are due to be
+    /// revalidated, after the quiet period configured using
+    /// [revalidation_delay()](FormState::revalidation_delay()). The
+    /// caller should set a timer to call
+    /// [run_scheduled()](FormState::run_scheduled()) at that time.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{
+    ///     FormState, RevalidationStrategy, Validatable, ValidationError, ValidationErrors,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// struct Address {
+    ///     street: String,
+    ///     city: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Address {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         let mut errors = ValidationErrors::default();
+    ///         if self.street.is_empty() {
+    ///             errors.push(ValidationError::new("street", "REQUIRED"));
+    ///         }
+    ///         if self.city.is_empty() {
+    ///             errors.push(ValidationError::new("city", "REQUIRED"));
+    ///         }
+    ///         if errors.is_empty() {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(errors)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let address = Address { street: String::new(), city: String::new() };
+    /// let mut state = FormState::new(address, vec!["street", "city"])
+    ///     .strategy(RevalidationStrategy::OnChange)
+    ///     .revalidation_delay(Duration::from_millis(100));
+    ///
+    /// // autofill changes both fields at once
+    /// state.form_mut().street = "1 Main St".to_string();
+    /// state.schedule_field_changed(&"street", Duration::from_millis(0));
+    /// let deadline = state.schedule_field_changed(&"city", Duration::from_millis(5));
+    ///
+    /// assert!(!state.run_scheduled(Duration::from_millis(50)));
+    /// assert!(state.errors().is_empty());
+    ///
+    /// assert!(state.run_scheduled(deadline));
+    /// assert_eq!("city", state.errors().errors[0].key);
+    /// ```
+    pub fn schedule_field_changed(&mut self, key: &Key, now: Duration) -> Duration {
+        self.scheduler.schedule(key.clone(), now)
+    }
+
+    /// Perform the batched validation pass for the fields changed
+    /// using
+    /// [schedule_field_changed()](FormState::schedule_field_changed()),
+    /// if it is due at the time `now`. The changed fields are
+    /// revalidated according to the [RevalidationStrategy], using a
+    /// single validation of the form. Returns `true` if the pass was
+    /// performed.
+    pub fn run_scheduled(&mut self, now: Duration) -> bool {
+        if self.scheduler.is_due(now) {
+            self.flush_scheduled();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Immediately perform the batched validation pass for the fields
+    /// changed using
+    /// [schedule_field_changed()](FormState::schedule_field_changed()),
+    /// regardless of whether it is due, for example before the form
+    /// is submitted.
+    pub fn flush_scheduled(&mut self) {
+        let keys: Vec<Key> = self
+            .scheduler
+            .take()
+            .into_iter()
+            .filter(|key| self.revalidates_on_change(key))
+            .collect();
+
+        if !keys.is_empty() {
+            self.validate_fields(&keys);
+        }
+    }
+
+    /// Call after the field with the specified `key` has lost focus.
+    /// Marks the field as touched, and revalidates it according to
+    /// the [RevalidationStrategy].
+    pub fn field_blurred(&mut self, key: &Key) {
+        self.touch(key.clone());
+
+        if self.strategy != RevalidationStrategy::OnSubmit {
+            self.validate_field(key);
+        }
+    }
+}
+
+/// An error which has been rendered into its message, used to
+/// persist the errors of a [FormState].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct RenderedError<Key> {
+    key: Key,
+    type_id: String,
+    message: String,
+    #[serde(default)]
+    acknowledged: bool,
+    #[serde(default)]
+    severity: Severity,
+    #[serde(default)]
+    priority: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<Help>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    suggestion: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    params: Vec<(Cow<'static, str>, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<Duration>,
+    #[cfg(feature = "serde_json")]
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    meta: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// The persisted representation of a [FormState].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct FormStateSnapshot<Form, Key> {
+    form: Form,
+    fields: Vec<Key>,
+    touched: Vec<Key>,
+    validated: Vec<Key>,
+    errors: Vec<RenderedError<Key>>,
+    strategy: RevalidationStrategy,
+}
+
+/// Serializes the form, the fields, which fields are touched, and
+/// the errors (rendered into their messages, along with their
+/// severity, help, suggestion, parameters and timestamp), so that an
+/// in-progress form can be stashed (for example in
+/// `sessionStorage`) and restored later, including any errors which
+/// were being displayed. Because the parameters are restored, the
+/// messages of the restored errors can be rendered again, for
+/// example using a [MessageCatalog](crate::MessageCatalog).
+///
+/// ## Example
+/// ```
+/// use form_validation::{FormState, ValidationError, ValidationErrors};
+///
+/// let mut state: FormState<String, String> = FormState::new(
+///     "draft".to_string(),
+///     vec!["email".to_string()],
+/// );
+/// state.touch("email".to_string());
+/// state.set_errors(
+///     ValidationError::new("email".to_string(), "INVALID_EMAIL")
+///         .message("Must be a valid email address")
+///         .with_help_url("https://example.com/help/email")
+///         .into(),
+/// );
+///
+/// let json = serde_json::to_string(&state).unwrap();
+/// let restored: FormState<String, String> = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!("draft", restored.form());
+/// assert!(restored.is_touched(&"email".to_string()));
+/// let error = &restored.errors().errors[0];
+/// assert_eq!("INVALID_EMAIL", error.type_id);
+/// assert_eq!("Must be a valid email address", error.to_string());
+/// assert_eq!(
+///     Some("https://example.com/help/email"),
+///     error.help().and_then(|help| help.url.as_deref())
+/// );
+/// ```
+///
+/// The parameters and timestamps of the errors are restored too:
+///
+/// ```
+/// use form_validation::{validators, FormState, MessageCatalog, Validation, ValidatorFn};
+/// use std::time::Duration;
+///
+/// let v: ValidatorFn<String, String> = validators::string_length(3, 16);
+/// let mut errors = v.validate_value(&"al".to_string(), &"username".to_string()).unwrap_err();
+/// errors.stamp(Duration::from_secs(10));
+///
+/// let mut state: FormState<String, String> =
+///     FormState::new("al".to_string(), vec!["username".to_string()]);
+/// state.set_errors(errors);
+///
+/// let json = serde_json::to_string(&state).unwrap();
+/// let restored: FormState<String, String> = serde_json::from_str(&json).unwrap();
+///
+/// let catalog = MessageCatalog::new()
+///     .message("en", "LENGTH_OUT_OF_RANGE", "Must be {min} to {max} characters");
+/// assert_eq!(
+///     vec!["Must be 3 to 16 characters"],
+///     restored.errors().render(&catalog.renderer("en"))
+/// );
+/// assert_eq!(Some(Duration::from_secs(10)), restored.errors().errors[0].timestamp());
+/// assert!(restored.errors().errors[0].is_stale(Duration::from_secs(20), Duration::from_secs(5)));
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Form, Key> Serialize for FormState<Form, Key>
+where
+    Form: Serialize,
+    Key: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let snapshot = FormStateSnapshot {
+            form: &self.form,
+            fields: self.fields.iter().collect(),
+            touched: self.touched.iter().collect(),
+            validated: self.validated.iter().collect(),
+            errors: self
+                .errors
+                .errors
+                .iter()
+                .map(|error| RenderedError {
+                    key: &error.key,
+                    type_id: error.type_id.to_string(),
+                    message: error.to_string(),
+                    acknowledged: error.is_acknowledged(),
+                    severity: error.severity(),
+                    priority: error.priority(),
+                    help: error.help().cloned(),
+                    suggestion: error.suggestion().map(str::to_string),
+                    params: error.params().to_vec(),
+                    timestamp: error.timestamp(),
+                    #[cfg(feature = "serde_json")]
+                    meta: error.metadata().clone(),
+                })
+                .collect(),
+            strategy: self.strategy,
+        };
+
+        snapshot.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, Form, Key> Deserialize<'de> for FormState<Form, Key>
+where
+    Form: Deserialize<'de>,
+    Key: Deserialize<'de> + Clone + PartialEq,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let snapshot: FormStateSnapshot<Form, Key> = FormStateSnapshot::deserialize(deserializer)?;
+
+        let mut acknowledged: Vec<(Key, Cow<'static, str>)> = Vec::new();
+        let errors = snapshot
+            .errors
+            .into_iter()
+            .map(|error| {
+                let type_id: Cow<'static, str> = Cow::Owned(error.type_id);
+                if error.acknowledged
+                    && !acknowledged.contains(&(error.key.clone(), type_id.clone()))
+                {
+                    acknowledged.push((error.key.clone(), type_id.clone()));
+                }
+                let mut restored = ValidationError::new(error.key, type_id)
+                    .message(error.message)
+                    .with_severity(error.severity)
+                    .with_priority(error.priority);
+                if let Some(help) = error.help {
+                    if let Some(text) = help.text {
+                        restored = restored.with_help_text(text);
+                    }
+                    if let Some(url) = help.url {
+                        restored = restored.with_help_url(url);
+                    }
+                }
+                if let Some(suggestion) = error.suggestion {
+                    restored = restored.with_suggestion(suggestion);
+                }
+                for (name, value) in error.params {
+                    restored = restored.with_param(name, value);
+                }
+                if let Some(timestamp) = error.timestamp {
+                    restored = restored.with_timestamp(timestamp);
+                }
+                #[cfg(feature = "serde_json")]
+                for (key, value) in error.meta {
+                    restored = restored.with_meta(key, value);
+                }
+                restored.set_acknowledged(error.acknowledged);
+                restored
+            })
+            .collect();
+
+        Ok(Self {
+            form: snapshot.form,
+            fields: snapshot.fields,
+            touched: snapshot.touched,
+            errors: ValidationErrors::new(errors),
+            submitting: false,
+            strategy: snapshot.strategy,
+            validated: snapshot.validated,
+            validating: Vec::new(),
+            middleware: ErrorsPipeline::new(),
+            subscriptions: ErrorsSubscriptions::new(),
+            scheduler: RevalidationScheduler::new(Duration::ZERO),
+            acknowledged,
+            clock: None,
+        })
+    }
+}
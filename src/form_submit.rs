@@ -0,0 +1,299 @@
+use crate::{AsyncValidatable, FormState, Validatable, ValidationErrors};
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Display},
+    future::Future,
+};
+
+/// The reason that a form submission using
+/// [FormState::submit_with()] did not succeed.
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitError<Key> {
+    /// The form is already being submitted.
+    AlreadySubmitting,
+    /// The form failed validation, so it was not submitted.
+    Invalid(ValidationErrors<Key>),
+    /// The form was submitted, but the submission was rejected with
+    /// errors for the fields of the form (for example by the server).
+    Rejected(ValidationErrors<Key>),
+}
+
+impl<Key> Display for SubmitError<Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::AlreadySubmitting => write!(f, "The form is already being submitted"),
+            SubmitError::Invalid(errors) => write!(f, "The form is invalid: {}", errors),
+            SubmitError::Rejected(errors) => write!(f, "The form was rejected: {}", errors),
+        }
+    }
+}
+
+impl<Key> std::error::Error for SubmitError<Key> where Key: Debug {}
+
+/// Resets [FormState::is_submitting()] when the submission completes,
+/// or is cancelled by dropping its future.
+struct SubmittingGuard<'a, Form, Key> {
+    state: &'a RefCell<FormState<Form, Key>>,
+}
+
+impl<'a, Form, Key> Drop for SubmittingGuard<'a, Form, Key> {
+    fn drop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.submitting = false;
+        state.validating.clear();
+    }
+}
+
+impl<Form, Key> FormState<Form, Key>
+where
+    Form: Validatable<Key> + AsyncValidatable<Key>,
+    Key: Clone + PartialEq + 'static,
+{
+    /// Submit the form using the `submit` function, managing the
+    /// whole submission lifecycle:
+    ///
+    /// 1. Returns [SubmitError::AlreadySubmitting] if a submission is
+    ///    already in progress, guarding against double submission.
+    /// 2. Marks all the fields as touched, so that any errors are
+    ///    displayed.
+    /// 3. Validates the form synchronously, so that its errors are
+    ///    displayed immediately, and then asynchronously, returning
+    ///    [SubmitError::Invalid] if there are errors. The errors of the
+    ///    asynchronous validation replace those of the synchronous
+    ///    validation, so [AsyncValidatable::validate_future()] should
+    ///    perform all of the form's validation (as the derived
+    ///    implementation does). While the asynchronous validation is in
+    ///    progress, all the fields have the status
+    ///    [FieldStatus::Validating](crate::FieldStatus::Validating).
+    /// 4. Submits the form, and if the submission is rejected with
+    ///    field errors (for example from the server), merges them
+    ///    into the errors for the form and returns
+    ///    [SubmitError::Rejected].
+    ///
+    /// The `state` is only borrowed briefly, and not across any
+    /// `await`, so it can be shared with the user interface while the
+    /// submission is in progress (for example to display a spinner
+    /// using [is_submitting()](FormState::is_submitting())). The
+    /// `submit` function is called with a clone of the form, so that
+    /// it can also use the `state`.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{
+    ///     AsyncValidatable, FormState, SubmitError, Validatable, ValidationError, ValidationErrors,
+    /// };
+    /// use futures::{executor::block_on, Future};
+    /// use std::{cell::RefCell, pin::Pin};
+    ///
+    /// #[derive(Clone)]
+    /// struct Signup {
+    ///     username: String,
+    /// }
+    ///
+    /// impl Validatable<&'static str> for Signup {
+    ///     fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+    ///         if self.username.is_empty() {
+    ///             Err(ValidationError::new("username", "REQUIRED").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl AsyncValidatable<&'static str> for Signup {
+    ///     fn validate_future(
+    ///         &self,
+    ///     ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+    ///         // the synchronous validation, followed by any asynchronous checks
+    ///         let result = self.validate();
+    ///         Box::pin(async move { result })
+    ///     }
+    /// }
+    ///
+    /// let state = RefCell::new(FormState::new(
+    ///     Signup { username: String::new() },
+    ///     vec!["username"],
+    /// ));
+    ///
+    /// let submit = |form: &Signup| {
+    ///     let username = form.username.clone();
+    ///     async move {
+    ///         if username == "admin" {
+    ///             Err(ValidationError::new("username", "USERNAME_TAKEN").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// match block_on(FormState::submit_with(&state, submit)) {
+    ///     Err(SubmitError::Invalid(errors)) => assert_eq!(1, errors.len()),
+    ///     _ => panic!("expected the form to be invalid"),
+    /// }
+    /// assert!(state.borrow().is_touched(&"username"));
+    ///
+    /// state.borrow_mut().form_mut().username = "admin".to_string();
+    /// match block_on(FormState::submit_with(&state, submit)) {
+    ///     Err(SubmitError::Rejected(errors)) => {
+    ///         assert_eq!("USERNAME_TAKEN", errors.errors[0].type_id)
+    ///     }
+    ///     _ => panic!("expected the submission to be rejected"),
+    /// }
+    /// assert_eq!(1, state.borrow().errors().len());
+    ///
+    /// state.borrow_mut().form_mut().username = "alice".to_string();
+    /// assert!(block_on(FormState::submit_with(&state, submit)).is_ok());
+    /// assert!(state.borrow().errors().is_empty());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn submit_with<F, Fut>(
+        state: &RefCell<Self>,
+        submit: F,
+    ) -> Result<(), SubmitError<Key>>
+    where
+        Form: Clone,
+        F: FnOnce(&Form) -> Fut,
+        Fut: Future<Output = Result<(), ValidationErrors<Key>>>,
+    {
+        let async_validation = {
+            let mut state_mut = state.borrow_mut();
+            if state_mut.submitting {
+                return Err(SubmitError::AlreadySubmitting);
+            }
+            state_mut.submitting = true;
+            state_mut.touch_all();
+            state_mut.validate();
+            state_mut.validating = state_mut.fields().to_vec();
+            state_mut.form().validate_future_or_empty()
+        };
+
+        let _guard = SubmittingGuard { state };
+
+        let async_errors = async_validation.await;
+
+        let form = {
+            let mut state_mut = state.borrow_mut();
+            state_mut.validating.clear();
+            state_mut.set_errors(async_errors);
+
+            if state_mut.errors.has_errors() {
+                return Err(SubmitError::Invalid(state_mut.errors.clone()));
+            }
+
+            state_mut.form().clone()
+        };
+
+        match submit(&form).await {
+            Ok(()) => Ok(()),
+            Err(rejected_errors) => {
+                let mut state_mut = state.borrow_mut();
+                state_mut.extend_errors(rejected_errors);
+                Err(SubmitError::Rejected(state_mut.errors.clone()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SubmitError;
+    use crate::{
+        AsyncValidatable, FormState, Severity, Validatable, ValidationError, ValidationErrors,
+    };
+    use futures::{
+        channel::oneshot,
+        executor::block_on,
+        task::{noop_waker_ref, Context},
+        Future, FutureExt,
+    };
+    use std::{cell::RefCell, pin::Pin};
+
+    #[derive(Clone)]
+    struct Form;
+
+    impl Validatable<&'static str> for Form {
+        fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+            Ok(())
+        }
+    }
+
+    impl AsyncValidatable<&'static str> for Form {
+        fn validate_future(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    /// A second submission while the first is still in progress is
+    /// rejected, and the guard is released once the first completes.
+    #[test]
+    fn guards_against_double_submission() {
+        let state = RefCell::new(FormState::new(Form, vec!["field1"]));
+        let (sender, receiver) = oneshot::channel::<()>();
+
+        let mut first = Box::pin(FormState::submit_with(&state, |_| async move {
+            receiver.await.unwrap();
+            Ok(())
+        }));
+
+        let mut context = Context::from_waker(noop_waker_ref());
+        assert!(first.poll_unpin(&mut context).is_pending());
+        assert!(state.borrow().is_submitting());
+
+        let second = block_on(FormState::submit_with(&state, |_| async { Ok(()) }));
+        assert_eq!(Err(SubmitError::AlreadySubmitting), second);
+
+        sender.send(()).unwrap();
+        assert_eq!(Ok(()), block_on(first));
+        assert!(!state.borrow().is_submitting());
+    }
+
+    #[derive(Clone)]
+    struct UnusualForm;
+
+    impl Validatable<&'static str> for UnusualForm {
+        fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+            Err(ValidationError::new("username", "UNUSUAL_USERNAME")
+                .with_severity(Severity::Warning)
+                .into())
+        }
+    }
+
+    impl AsyncValidatable<&'static str> for UnusualForm {
+        fn validate_future(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+            let result = self.validate();
+            Box::pin(async move { result })
+        }
+    }
+
+    /// Warnings are kept in the state, but don't prevent the form
+    /// from being submitted.
+    #[test]
+    fn warnings_do_not_block_submission() {
+        let state = RefCell::new(FormState::new(UnusualForm, vec!["username"]));
+        assert_eq!(
+            Ok(()),
+            block_on(FormState::submit_with(&state, |_| async { Ok(()) }))
+        );
+        assert_eq!(1, state.borrow().errors().warnings_only().len());
+    }
+
+    /// The `submit` function can use the state, because it isn't
+    /// borrowed while the function is called.
+    #[test]
+    fn submit_can_use_state() {
+        let state = RefCell::new(FormState::new(Form, vec!["field1"]));
+
+        let result = block_on(FormState::submit_with(&state, |_| {
+            assert!(state.borrow_mut().is_submitting());
+            async { Ok(()) }
+        }));
+
+        assert_eq!(Ok(()), result);
+        assert!(!state.borrow().is_submitting());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{validators, IsEmpty, Validation, ValidationErrors, ValidatorFn};
+use std::{fmt::Debug, rc::Rc};
+
+/// The pseudo-key used by [FormValidator::form_rule()] to report
+/// errors which apply to the form as a whole, rather than to a
+/// single field.
+pub const FORM_KEY: &str = "__form__";
+
+type FormValidationFn<Form, Key> = Rc<dyn Fn(&Form, &mut ValidationErrors<Key>)>;
+
+/// Validates a whole `Form`, using validators for each of its fields,
+/// and rules which are not tied to a single field (for example "at
+/// least one contact method must be provided").
+///
+/// The validations are performed in the order that they were added,
+/// and all of their errors are collected.
+///
+/// ## Example
+/// ```
+/// use form_validation::{FormValidator, ValidationError, Validator, FORM_KEY};
+///
+/// struct Contact {
+///     email: String,
+///     phone: String,
+/// }
+///
+/// let not_too_long: Validator<String, &'static str> = Validator::new()
+///     .validation(|value: &String, key: &&'static str| {
+///         if value.len() > 20 {
+///             Err(ValidationError::new(*key, "TOO_LONG").into())
+///         } else {
+///             Ok(())
+///         }
+///     });
+///
+/// let v: FormValidator<Contact, &'static str> = FormValidator::new()
+///     .field("email", |form: &Contact| &form.email, not_too_long.clone())
+///     .field("phone", |form: &Contact| &form.phone, not_too_long)
+///     .form_rule(|form: &Contact, key: &&'static str| {
+///         if form.email.is_empty() && form.phone.is_empty() {
+///             Err(ValidationError::new(*key, "CONTACT_REQUIRED")
+///                 .message("Provide an email address or a phone number")
+///                 .into())
+///         } else {
+///             Ok(())
+///         }
+///     });
+///
+/// let form = Contact {
+///     email: "".to_string(),
+///     phone: "".to_string(),
+/// };
+///
+/// let errors = v.validate(&form).unwrap_err();
+/// assert_eq!(1, errors.len());
+/// assert_eq!(FORM_KEY, errors.errors[0].key);
+/// assert_eq!("CONTACT_REQUIRED", errors.errors[0].type_id);
+///
+/// let form = Contact {
+///     email: "user@example.com".to_string(),
+///     phone: "".to_string(),
+/// };
+/// assert!(v.validate(&form).is_ok());
+/// ```
+pub struct FormValidator<Form, Key> {
+    validations: Vec<FormValidationFn<Form, Key>>,
+}
+
+impl<Form, Key> Clone for FormValidator<Form, Key> {
+    fn clone(&self) -> Self {
+        Self {
+            validations: self.validations.clone(),
+        }
+    }
+}
+
+impl<Form, Key> Debug for FormValidator<Form, Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FormValidator {{ len: {} }}", self.validations.len())
+    }
+}
+
+impl<Form, Key> Default for FormValidator<Form, Key> {
+    fn default() -> Self {
+        Self {
+            validations: Vec::new(),
+        }
+    }
+}
+
+impl<Form, Key> FormValidator<Form, Key>
+where
+    Form: 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    /// Create a new empty `FormValidator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate the field with the specified `key`, whose value is
+    /// obtained from the form using `get`, using `validation`.
+    pub fn field<Value, G, V>(mut self, key: Key, get: G, validation: V) -> Self
+    where
+        Value: 'static,
+        G: Fn(&Form) -> &Value + 'static,
+        V: Validation<Value, Key> + 'static,
+    {
+        self.validations.push(Rc::new(move |form, errors| {
+            validation.validate_value_into(get(form), &key, errors)
+        }));
+        self
+    }
+
+    /// Validate a nested section of the form, obtained from the form
+    /// using `get`, using a `FormValidator` for that section.
+    pub fn nested<Section, G>(self, get: G, validator: FormValidator<Section, Key>) -> Self
+    where
+        Section: 'static,
+        G: Fn(&Form) -> &Section + 'static,
+    {
+        self.nested_if(|_| true, get, validator)
+    }
+
+    /// Validate a nested section of the form, only when `predicate`
+    /// returns `true` for the form. This allows a section which is
+    /// hidden from the user to be skipped, so that it doesn't produce
+    /// confusing errors.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{FormValidator, ValidationError};
+    ///
+    /// struct Address {
+    ///     street: String,
+    /// }
+    ///
+    /// struct Order {
+    ///     ship_to_different_address: bool,
+    ///     shipping_address: Address,
+    /// }
+    ///
+    /// let address: FormValidator<Address, &'static str> = FormValidator::new()
+    ///     .rule("street", |address: &Address, key: &&'static str| {
+    ///         if address.street.is_empty() {
+    ///             Err(ValidationError::new(*key, "REQUIRED").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let v: FormValidator<Order, &'static str> = FormValidator::new().nested_if(
+    ///     |order: &Order| order.ship_to_different_address,
+    ///     |order: &Order| &order.shipping_address,
+    ///     address,
+    /// );
+    ///
+    /// let mut order = Order {
+    ///     ship_to_different_address: false,
+    ///     shipping_address: Address {
+    ///         street: "".to_string(),
+    ///     },
+    /// };
+    /// assert!(v.validate(&order).is_ok());
+    ///
+    /// order.ship_to_different_address = true;
+    /// assert!(v.validate(&order).is_err());
+    /// ```
+    pub fn nested_if<Section, P, G>(
+        mut self,
+        predicate: P,
+        get: G,
+        validator: FormValidator<Section, Key>,
+    ) -> Self
+    where
+        Section: 'static,
+        P: Fn(&Form) -> bool + 'static,
+        G: Fn(&Form) -> &Section + 'static,
+    {
+        self.validations.push(Rc::new(move |form, errors| {
+            if predicate(form) {
+                validator.validate_into(get(form), errors)
+            }
+        }));
+        self
+    }
+
+    /// Validate that the field with the specified `key`, whose value
+    /// is obtained from the form using `get`, is not empty (see
+    /// [IsEmpty]), only when `predicate` returns `true` for the form.
+    /// Produces the same `REQUIRED` error as
+    /// [validators::required()].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::FormValidator;
+    ///
+    /// struct Contact {
+    ///     contact_by_phone: bool,
+    ///     phone: String,
+    /// }
+    ///
+    /// let v: FormValidator<Contact, &'static str> = FormValidator::new().required_if(
+    ///     "phone",
+    ///     |contact: &Contact| contact.contact_by_phone,
+    ///     |contact: &Contact| &contact.phone,
+    /// );
+    ///
+    /// let mut contact = Contact {
+    ///     contact_by_phone: false,
+    ///     phone: " ".to_string(),
+    /// };
+    /// assert!(v.validate(&contact).is_ok());
+    ///
+    /// contact.contact_by_phone = true;
+    /// let errors = v.validate(&contact).unwrap_err();
+    /// assert_eq!("REQUIRED", errors.errors[0].type_id);
+    /// ```
+    pub fn required_if<Value, P, G>(mut self, key: Key, predicate: P, get: G) -> Self
+    where
+        Value: IsEmpty + 'static,
+        P: Fn(&Form) -> bool + 'static,
+        G: Fn(&Form) -> &Value + 'static,
+    {
+        let required: ValidatorFn<Value, Key> = validators::required();
+        self.validations.push(Rc::new(move |form, errors| {
+            if predicate(form) {
+                required.validate_value_into(get(form), &key, errors)
+            }
+        }));
+        self
+    }
+
+    /// Add a rule over the whole form, reporting its errors using the
+    /// specified `key`. This may be one of the form's fields (for
+    /// example to report a mismatched password confirmation on the
+    /// confirmation field), or a pseudo-key for the form as a whole,
+    /// see [FormValidator::form_rule()].
+    pub fn rule<F>(mut self, key: Key, rule: F) -> Self
+    where
+        F: Into<ValidatorFn<Form, Key>>,
+    {
+        let rule = rule.into();
+        self.validations.push(Rc::new(move |form, errors| {
+            rule.validate_value_into(form, &key, errors)
+        }));
+        self
+    }
+
+    /// Add a rule over the whole form, reporting its errors using the
+    /// [FORM_KEY] (`"__form__"`) pseudo-key.
+    pub fn form_rule<F>(self, rule: F) -> Self
+    where
+        Key: From<&'static str>,
+        F: Into<ValidatorFn<Form, Key>>,
+    {
+        self.rule(Key::from(FORM_KEY), rule)
+    }
+
+    /// Add a comparison between two of the form's fields, usually
+    /// created using the [rule!](crate::rule!) macro, which reports
+    /// its errors on both fields, keyed by their names. See
+    /// [validators::FieldComparison].
+    pub fn compare(mut self, comparison: validators::FieldComparison<Form>) -> Self
+    where
+        Key: From<&'static str>,
+    {
+        self.validations.push(Rc::new(move |form, errors| {
+            comparison.validate_into(form, errors)
+        }));
+        self
+    }
+
+    /// Validate the `form`. Returns `Ok(())` if no errors were
+    /// encountered, and returns `Err(ValidationErrors)` if any errors
+    /// were encountered.
+    pub fn validate(&self, form: &Form) -> Result<(), ValidationErrors<Key>> {
+        let mut errors = ValidationErrors::default();
+        self.validate_into(form, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate the `form`, appending any errors to `errors`.
+    pub fn validate_into(&self, form: &Form, errors: &mut ValidationErrors<Key>) {
+        for validation in &self.validations {
+            validation(form, errors);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Whether a value counts as "empty" (not filled in) for the purposes
+/// of validation. This is decided once per type, and used
+/// consistently by [validators::required()](crate::validators::required()),
+/// [ValidatorFn::skip_empty()](crate::ValidatorFn::skip_empty()) and
+/// [FormValidator::required_if()](crate::FormValidator::required_if()).
+///
+/// The provided implementations consider:
+///
+/// + Strings which are empty or contain only whitespace to be empty.
+/// + Collections which contain no items to be empty.
+/// + `None` to be empty, and `Some(value)` to be empty when `value`
+///   is empty.
+///
+/// Implement this trait for your own types to decide what counts as
+/// empty for them.
+///
+/// ## Example
+/// ```
+/// use form_validation::IsEmpty;
+///
+/// struct PhoneNumber {
+///     country_code: u16,
+///     number: String,
+/// }
+///
+/// impl IsEmpty for PhoneNumber {
+///     fn is_empty_value(&self) -> bool {
+///         // the country code is pre-filled, so only the number matters
+///         self.number.is_empty_value()
+///     }
+/// }
+///
+/// assert!("  ".is_empty_value());
+/// assert!(Some(Vec::<i32>::new()).is_empty_value());
+/// assert!(PhoneNumber { country_code: 61, number: String::new() }.is_empty_value());
+/// ```
+pub trait IsEmpty {
+    /// Whether this value counts as empty.
+    fn is_empty_value(&self) -> bool;
+}
+
+impl IsEmpty for str {
+    fn is_empty_value(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty_value(&self) -> bool {
+        self.as_str().is_empty_value()
+    }
+}
+
+impl IsEmpty for Cow<'_, str> {
+    fn is_empty_value(&self) -> bool {
+        self.as_ref().is_empty_value()
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty_value(&self) -> bool {
+        (**self).is_empty_value()
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for Box<T> {
+    fn is_empty_value(&self) -> bool {
+        (**self).is_empty_value()
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for Rc<T> {
+    fn is_empty_value(&self) -> bool {
+        (**self).is_empty_value()
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for Arc<T> {
+    fn is_empty_value(&self) -> bool {
+        (**self).is_empty_value()
+    }
+}
+
+impl<T: IsEmpty> IsEmpty for Option<T> {
+    fn is_empty_value(&self) -> bool {
+        match self {
+            Some(value) => value.is_empty_value(),
+            None => true,
+        }
+    }
+}
+
+impl<T> IsEmpty for [T] {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmpty for VecDeque<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmpty for BTreeSet<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K, V> IsEmpty for BTreeMap<K, V> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T, S> IsEmpty for HashSet<T, S> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K, V, S> IsEmpty for HashMap<K, V, S> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
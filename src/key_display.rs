@@ -0,0 +1,98 @@
+use crate::{error::Shared, MaybeSendSync};
+use std::fmt::{Debug, Display, Formatter};
+
+#[cfg(not(feature = "send"))]
+type KeyFmtTraitObject<Key> = dyn Fn(&Key, &mut Formatter<'_>) -> std::fmt::Result;
+#[cfg(feature = "send")]
+type KeyFmtTraitObject<Key> = dyn Fn(&Key, &mut Formatter<'_>) -> std::fmt::Result + Send + Sync;
+
+/// An adapter which renders keys into messages using a formatting
+/// function, for key types which don't implement `Display` (tuples,
+/// enums, etc). Create it once, and clone it into the message
+/// functions which need it.
+///
+/// ## Example
+/// ```
+/// use form_validation::{KeyDisplay, ValidationError};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Field {
+///     Sku(usize),
+/// }
+///
+/// let key_display: KeyDisplay<Field> = KeyDisplay::new(|key, f| match key {
+///     Field::Sku(index) => write!(f, "SKU of item {}", index + 1),
+/// });
+///
+/// let error = ValidationError::new(Field::Sku(0), "INVALID_SKU").with_message({
+///     let key_display = key_display.clone();
+///     move |key| format!("The {} is invalid", key_display.display(key))
+/// });
+///
+/// assert_eq!("The SKU of item 1 is invalid", error.to_string());
+/// ```
+pub struct KeyDisplay<Key> {
+    fmt_fn: Shared<KeyFmtTraitObject<Key>>,
+}
+
+impl<Key> KeyDisplay<Key> {
+    /// Create a new `KeyDisplay` from a function which formats a key.
+    pub fn new<F>(fmt_fn: F) -> Self
+    where
+        F: Fn(&Key, &mut Formatter<'_>) -> std::fmt::Result + MaybeSendSync + 'static,
+    {
+        Self {
+            fmt_fn: Shared::new(fmt_fn),
+        }
+    }
+
+    /// Obtain an item which implements `Display` for the given `key`.
+    pub fn display<'a>(&'a self, key: &'a Key) -> DisplayKey<'a, Key> {
+        DisplayKey {
+            key_display: self,
+            key,
+        }
+    }
+
+    /// Render the given `key` to a `String`.
+    pub fn key_string(&self, key: &Key) -> String {
+        self.display(key).to_string()
+    }
+}
+
+impl<Key> Clone for KeyDisplay<Key> {
+    fn clone(&self) -> Self {
+        Self {
+            fmt_fn: Shared::clone(&self.fmt_fn),
+        }
+    }
+}
+
+/// Uses the `Display` implementation of the key.
+impl<Key> Default for KeyDisplay<Key>
+where
+    Key: Display,
+{
+    fn default() -> Self {
+        Self::new(|key, f| write!(f, "{}", key))
+    }
+}
+
+impl<Key> Debug for KeyDisplay<Key> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "KeyDisplay")
+    }
+}
+
+/// A key which can be displayed, obtained using
+/// [KeyDisplay::display()].
+pub struct DisplayKey<'a, Key> {
+    key_display: &'a KeyDisplay<Key>,
+    key: &'a Key,
+}
+
+impl<'a, Key> Display for DisplayKey<'a, Key> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        (self.key_display.fmt_fn)(self.key, f)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::KeyDisplay;
+
+/// Provides the human readable label for the field with a given key
+/// (for example "Email address" for the key `email_addr`), for
+/// messages set using
+/// [ValidationError::with_labelled_message()](crate::ValidationError::with_labelled_message()),
+/// so that validation functions don't need to know the display names
+/// of the fields they are used with. See
+/// [ValidationError::message_with_labels()](crate::ValidationError::message_with_labels()).
+///
+/// This is implemented for closures with the signature
+/// `Fn(&Key) -> Option<String>`, and for [KeyDisplay].
+pub trait LabelProvider<Key> {
+    /// The label for the field with the specified `key`, or `None` if
+    /// the key itself should be used.
+    fn label(&self, key: &Key) -> Option<String>;
+}
+
+impl<Key, F> LabelProvider<Key> for F
+where
+    F: Fn(&Key) -> Option<String>,
+{
+    fn label(&self, key: &Key) -> Option<String> {
+        (self)(key)
+    }
+}
+
+impl<Key> LabelProvider<Key> for KeyDisplay<Key> {
+    fn label(&self, key: &Key) -> Option<String> {
+        Some(self.key_string(key))
+    }
+}
@@ -7,28 +7,183 @@
 //! form, and concatinating the results with
 //! [concat_results()](concat_results()).
 //!
+//! The most commonly used items can be imported all at once from the
+//! [prelude].
+//!
 //! ## Optional Features
 //!
-//! + `"stdweb-support"` - enable support for
-//!   [stdweb](https://crates.io/crates/stdweb) on the
-//!   `wasm32-unknown-unknown` platform.
 //! + `"wasm-bindgen-support"` - enable for
 //!   [wasm-bindgen](https://crates.io/crates/wasm-bindgen) on the
 //!   `wasm32-unknown-unknown` platform.
 //! + `"async"` - enable an asynchronous version of this API, and
-//!   conversion traits from synchronous to asynchronous.
+//!   conversion traits from synchronous to asynchronous. When this
+//!   feature is disabled, none of the asynchronous machinery (or the
+//!   `futures` dependency) is compiled.
+//! + `"address"`, `"email"`, `"finance"`, `"net"`, `"dates"` -
+//!   enable groups of the built-in [validators](validators), see the
+//!   module documentation for details.
+//! + `"full"` - enable all the built-in [validators](validators).
+//! + `"regex"` - enable [validators::pattern()], using
+//!   [regex](https://crates.io/crates/regex). This is not included in
+//!   `"full"`.
+//! + `"url"` - enable [validators::url()], using
+//!   [url](https://crates.io/crates/url). This is not included in
+//!   `"full"`.
+//! + `"phone"` - enable [validators::phone()], using
+//!   [phonenumber](https://crates.io/crates/phonenumber). This is not
+//!   included in `"full"`.
+//! + `"unicode"` - count the length of strings in
+//!   [validators::string_length()] in user-perceived characters
+//!   (grapheme clusters), using
+//!   [unicode-segmentation](https://crates.io/crates/unicode-segmentation).
+//!   This is not included in `"full"`.
+//! + `"zxcvbn"` - enable [validators::password_strength()], using
+//!   [zxcvbn](https://crates.io/crates/zxcvbn). This is not included
+//!   in `"full"`.
+//! + `"serde_json"` - enable [validators::json()], and structured
+//!   [metadata](ValidationError::with_meta()) on errors, using
+//!   [serde_json](https://crates.io/crates/serde_json). This is not
+//!   included in `"full"`.
+//! + `"chrono"`, `"time"` - enable [validators::chrono] and
+//!   [validators::time], for dates and times in a custom format,
+//!   using [chrono](https://crates.io/crates/chrono) or
+//!   [time](https://crates.io/crates/time), and implement
+//!   [validators::Today] for their date types, for use with
+//!   [validators::not_in_future()] and [validators::not_in_past()].
+//!   These are not included in `"full"`.
+//! + `"minimal-messages"` - the built-in validators use short static
+//!   messages, to reduce the size of `wasm32-unknown-unknown`
+//!   binaries.
+//! + `"fixtures"` - enable the [fixtures] module of reference form
+//!   implementations, for testing user interface integrations.
+//! + `"derive"` - enable `#[derive(Validatable)]`, to implement
+//!   [Validatable] for a form using `#[validate(...)]` attributes on
+//!   its fields, and (with the `"async"` feature)
+//!   `#[derive(AsyncValidatable)]`, which also supports
+//!   `#[validate_async(...)]` attributes. `#[derive(FormFields)]`
+//!   generates an enum of a form's fields implementing [FormField],
+//!   for use as a strongly typed key, and the `#[validator]` attribute
+//!   turns a validation function into a function returning a
+//!   [ValidatorFn]. The `rules!` macro builds a [Validator] from a
+//!   terse rule language which is parsed at compile time, for example
+//!   `rules!(String, "len(3..=20) && identifier")`.
+//! + `"send"` - store the message functions of a [ValidationError]
+//!   using `Arc` and require them to be `Send + Sync` (see
+//!   [MaybeSendSync]), so that errors can be sent between threads,
+//!   for example when they are produced by a multithreaded server.
+//!   The messages of the built-in validators are formatted when the
+//!   error is produced rather than when it is displayed.
+//!
+//!   **Warning:** unlike the other features, `"send"` is not
+//!   additive. Cargo enables a feature for every crate in the
+//!   dependency graph when any one of them enables it, and this
+//!   feature then rejects the message functions of every other user
+//!   of this library which aren't `Send + Sync`, such as closures
+//!   capturing an `Rc` (which is common in `wasm32-unknown-unknown`
+//!   user interfaces). Libraries which depend on this crate should
+//!   never enable it, only the final binary should. Errors with a
+//!   fixed [message](ValidationError::message()) work with or without
+//!   this feature.
+//! + `"serde"` - enable serialization of [FormState](FormState) and
+//!   [ValidationErrors] using
+//!   [serde](https://crates.io/crates/serde), and the [schema] module
+//!   of serializable, versioned rule sets.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod aria;
+#[cfg(feature = "async")]
+mod async_collections;
+#[cfg(feature = "async")]
+mod async_validatable;
+#[cfg(feature = "async")]
+mod async_validator;
+#[cfg(feature = "async")]
+mod async_validator_fn;
+pub mod codes;
+mod collections;
 mod concat_results;
 mod error;
+mod error_builder;
+mod error_events;
+mod errors_by_key;
+mod field_binding;
+mod field_path;
+#[cfg(feature = "fixtures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixtures")))]
+#[path = "../examples/fixtures/mod.rs"]
+pub mod fixtures;
+mod form_field;
+mod form_state;
+#[cfg(feature = "async")]
+mod form_submit;
+mod form_validator;
+mod is_empty;
+mod key_display;
+mod label_provider;
+mod message_catalog;
+mod message_context;
+mod message_renderer;
+mod middleware;
+pub mod prelude;
+mod scheduler;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod schema;
+mod telemetry;
+pub mod testing;
+mod type_id;
 mod validatable;
 mod validation;
 mod validator;
 mod validator_fn;
+pub mod validators;
 
+#[cfg(feature = "async")]
+pub use async_collections::*;
+#[cfg(feature = "async")]
+pub use async_validatable::*;
+#[cfg(feature = "async")]
+pub use async_validator::*;
+#[cfg(feature = "async")]
+pub use async_validator_fn::*;
+pub use collections::*;
 pub use concat_results::concat_results;
 pub use error::*;
+pub use error_builder::*;
+pub use error_events::*;
+pub use errors_by_key::*;
+pub use field_binding::*;
+pub use field_path::*;
+pub use form_field::*;
+pub use form_state::*;
+#[cfg(feature = "async")]
+pub use form_submit::*;
+#[cfg(all(feature = "derive", feature = "async"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "derive", feature = "async"))))]
+pub use form_validation_derive::AsyncValidatable;
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use form_validation_derive::{rules, validator, FormFields, Validatable};
+
+/// Items used by the code generated by the derive macros, which are
+/// not part of the public API.
+#[cfg(all(feature = "derive", feature = "async"))]
+#[doc(hidden)]
+pub mod __private {
+    pub use futures::future::join_all;
+}
+pub use form_validator::*;
+pub use is_empty::*;
+pub use key_display::*;
+pub use label_provider::*;
+pub use message_catalog::*;
+pub use message_context::*;
+pub use message_renderer::*;
+pub use middleware::*;
+pub use scheduler::*;
+pub use telemetry::*;
+pub use type_id::*;
 pub use validatable::*;
 pub use validation::*;
 pub use validator::*;
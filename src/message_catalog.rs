@@ -0,0 +1,255 @@
+use crate::{MessageRenderer, ValidationError};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+/// Translations of the messages for errors, keyed by locale (for
+/// example `"en"` or `"de-CH"`) and
+/// [type_id](ValidationError::type_id).
+///
+/// ## Example
+/// ```
+/// use form_validation::{MessageCatalog, ValidationError};
+///
+/// let catalog = MessageCatalog::new()
+///     .message("en", "REQUIRED", "This field is required")
+///     .message("de", "REQUIRED", "Dieses Feld ist erforderlich");
+///
+/// let error = ValidationError::new("username", "REQUIRED");
+/// assert_eq!(Some("Dieses Feld ist erforderlich"), catalog.translate("de", &error));
+/// assert_eq!(None, catalog.translate("fr", &error));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+    locales: BTreeMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    /// Create a new empty `MessageCatalog`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A factory method to declare that `locale` is supported by this
+    /// catalog, even though it may not have any messages yet, so that
+    /// its missing translations are reported by
+    /// [check_coverage()](MessageCatalog::check_coverage()).
+    pub fn locale<L: Into<String>>(mut self, locale: L) -> Self {
+        self.locales.entry(locale.into()).or_default();
+        self
+    }
+
+    /// A factory method to add the `message` for errors with the
+    /// specified `type_id` in the specified `locale`, see
+    /// [insert()](MessageCatalog::insert()).
+    pub fn message<L, T, M>(mut self, locale: L, type_id: T, message: M) -> Self
+    where
+        L: Into<String>,
+        T: Into<String>,
+        M: Into<String>,
+    {
+        self.insert(locale, type_id, message);
+        self
+    }
+
+    /// Add the `message` for errors with the specified `type_id` in
+    /// the specified `locale`, replacing any existing message.
+    pub fn insert<L, T, M>(&mut self, locale: L, type_id: T, message: M)
+    where
+        L: Into<String>,
+        T: Into<String>,
+        M: Into<String>,
+    {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(type_id.into(), message.into());
+    }
+
+    /// The locales supported by this catalog, in alphabetical order.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.locales.keys().map(String::as_str)
+    }
+
+    /// Get the message for errors with the specified `type_id` in the
+    /// specified `locale`.
+    pub fn get(&self, locale: &str, type_id: &str) -> Option<&str> {
+        self.locales
+            .get(locale)
+            .and_then(|messages| messages.get(type_id))
+            .map(String::as_str)
+    }
+
+    /// Get the message for the `error` in the specified `locale`.
+    pub fn translate<Key>(&self, locale: &str, error: &ValidationError<Key>) -> Option<&str> {
+        self.get(locale, &error.type_id)
+    }
+
+    /// A [MessageRenderer] which renders the messages for errors
+    /// using this catalog's messages in the specified `locale`,
+    /// replacing placeholders such as `{min}` in the messages with
+    /// the values of the error's [parameters](ValidationError::params()).
+    /// Placeholders without a corresponding parameter are left as they
+    /// are.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{validators, MessageCatalog, Validation, ValidatorFn};
+    ///
+    /// let catalog = MessageCatalog::new()
+    ///     .message("en", "LENGTH_OUT_OF_RANGE", "Must be {min} to {max} characters")
+    ///     .message("de", "LENGTH_OUT_OF_RANGE", "Muss {min} bis {max} Zeichen lang sein");
+    ///
+    /// let v: ValidatorFn<String, &str> = validators::string_length(3, 16);
+    /// let errors = v.validate_value(&"al".to_string(), &"username").unwrap_err();
+    ///
+    /// // the language can be changed without revalidating
+    /// assert_eq!(
+    ///     vec!["Must be 3 to 16 characters"],
+    ///     errors.render(&catalog.renderer("en"))
+    /// );
+    /// assert_eq!(
+    ///     vec!["Muss 3 bis 16 Zeichen lang sein"],
+    ///     errors.render(&catalog.renderer("de"))
+    /// );
+    /// ```
+    pub fn renderer<'a>(&'a self, locale: &'a str) -> CatalogRenderer<'a> {
+        CatalogRenderer {
+            catalog: self,
+            locale,
+        }
+    }
+
+    /// Check that each of the `type_ids` has a translation in every
+    /// supported locale (see [locales()](MessageCatalog::locales())),
+    /// for example the
+    /// [possible_error_types()](crate::Validator::possible_error_types())
+    /// of each validator in an application. Intended to be used in a
+    /// unit test, so that missing translations are caught during
+    /// development instead of in production.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{validators, MessageCatalog, Validator};
+    ///
+    /// let username: Validator<String, &str> = Validator::new()
+    ///     .validation(validators::required())
+    ///     .validation(validators::string_length(3, 16));
+    ///
+    /// let catalog = MessageCatalog::new()
+    ///     .message("en", "REQUIRED", "This field is required")
+    ///     .message("en", "LENGTH_OUT_OF_RANGE", "Wrong length")
+    ///     .message("de", "REQUIRED", "Dieses Feld ist erforderlich");
+    ///
+    /// let missing = catalog
+    ///     .check_coverage(username.possible_error_types())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(1, missing.missing.len());
+    /// assert_eq!("de", missing.missing[0].locale);
+    /// assert_eq!("LENGTH_OUT_OF_RANGE", missing.missing[0].type_id);
+    /// ```
+    pub fn check_coverage<'a, I>(&self, type_ids: I) -> Result<(), MissingTranslations>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let type_ids: Vec<&str> = type_ids.into_iter().collect();
+        let missing: Vec<MissingTranslation> = self
+            .locales
+            .iter()
+            .flat_map(|(locale, messages)| {
+                type_ids
+                    .iter()
+                    .filter(move |type_id| !messages.contains_key(**type_id))
+                    .map(move |type_id| MissingTranslation {
+                        locale: locale.clone(),
+                        type_id: type_id.to_string(),
+                    })
+            })
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingTranslations { missing })
+        }
+    }
+}
+
+/// A [MessageRenderer] for a locale of a [MessageCatalog], see
+/// [MessageCatalog::renderer()].
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogRenderer<'a> {
+    catalog: &'a MessageCatalog,
+    locale: &'a str,
+}
+
+impl<'a, Key> MessageRenderer<Key> for CatalogRenderer<'a> {
+    fn render(&self, error: &ValidationError<Key>) -> Option<String> {
+        self.catalog
+            .translate(self.locale, error)
+            .map(|message| interpolate(message, error.params()))
+    }
+}
+
+/// Replace the `{name}` placeholders in `message` with the values of
+/// the corresponding `params`.
+fn interpolate(message: &str, params: &[(Cow<'static, str>, String)]) -> String {
+    let mut rendered = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        let value = placeholder.find('}').and_then(|end| {
+            let name = &placeholder[1..end];
+            params
+                .iter()
+                .find(|(param, _)| param == name)
+                .map(|(_, value)| (value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                rendered.push_str(value);
+                rest = &placeholder[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = &placeholder[1..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// A [type_id](ValidationError::type_id) which lacks a translation
+/// in a [MessageCatalog].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTranslation {
+    /// The locale which lacks the translation.
+    pub locale: String,
+    /// The type id which lacks a translation.
+    pub type_id: String,
+}
+
+/// The error returned by [MessageCatalog::check_coverage()], listing
+/// all the missing translations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTranslations {
+    /// The missing translations, grouped by locale.
+    pub missing: Vec<MissingTranslation>,
+}
+
+impl Display for MissingTranslations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing translations:")?;
+        for missing in &self.missing {
+            write!(f, "\n  {}: {}", missing.locale, missing.type_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingTranslations {}
@@ -0,0 +1,98 @@
+use crate::{MessageRenderer, ValidationError};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::Debug,
+    rc::Rc,
+};
+
+/// Context which is available when the message for a
+/// [ValidationError] is produced, such as the locale of the current
+/// request and arbitrary application data, so that messages can be
+/// localized when they are displayed rather than when the error is
+/// produced, see
+/// [ValidationError::with_contextual_message()].
+///
+/// A `MessageContext` is also a [MessageRenderer], which renders the
+/// messages of errors using itself as the context.
+///
+/// ## Example
+/// ```
+/// use form_validation::{MessageContext, ValidationError, ValidationErrors};
+///
+/// struct AppName(&'static str);
+///
+/// let errors: ValidationErrors<&str> = ValidationError::new("email", "REQUIRED")
+///     .with_contextual_message(|key, context| {
+///         let app = context.data::<AppName>().map(|app| app.0).unwrap_or("");
+///         match context.locale() {
+///             Some("fr") => format!("{} : {} est obligatoire", app, key),
+///             _ => format!("{}: {} is required", app, key),
+///         }
+///     })
+///     .into();
+///
+/// let context = MessageContext::new().with_data(AppName("Shop"));
+/// assert_eq!(vec!["Shop: email is required"], errors.render(&context));
+///
+/// let context = context.with_locale("fr");
+/// assert_eq!(vec!["Shop : email est obligatoire"], errors.render(&context));
+/// ```
+#[derive(Clone, Default)]
+pub struct MessageContext {
+    locale: Option<String>,
+    data: HashMap<TypeId, Rc<dyn Any>>,
+}
+
+impl MessageContext {
+    /// Create a new empty `MessageContext`, without a locale or any
+    /// data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Factory method to set the locale for the messages, for
+    /// example `"en-US"`.
+    pub fn with_locale<S: Into<String>>(mut self, locale: S) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// The locale for the messages, if one has been set using
+    /// [MessageContext::with_locale()].
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Factory method to attach a piece of application data to this
+    /// context, which can be retrieved by its type using
+    /// [MessageContext::data()]. Only one value of each type is
+    /// stored, and attaching another replaces it.
+    pub fn with_data<T: Any>(mut self, data: T) -> Self {
+        self.data.insert(TypeId::of::<T>(), Rc::new(data));
+        self
+    }
+
+    /// The application data of type `T` attached to this context
+    /// using [MessageContext::with_data()], if any.
+    pub fn data<T: Any>(&self) -> Option<&T> {
+        self.data
+            .get(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+}
+
+impl Debug for MessageContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageContext")
+            .field("locale", &self.locale)
+            .field("data", &self.data.len())
+            .finish()
+    }
+}
+
+impl<Key> MessageRenderer<Key> for MessageContext {
+    fn render(&self, error: &ValidationError<Key>) -> Option<String> {
+        Some(error.message_in(self))
+    }
+}
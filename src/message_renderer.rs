@@ -0,0 +1,80 @@
+use crate::{ValidationError, ValidationErrors};
+
+/// Renders the messages for errors when they are displayed, rather
+/// than when they are produced by validation, using only the
+/// [type_id](ValidationError::type_id) and
+/// [parameters](ValidationError::params()) of each error. This
+/// separates the error data from its presentation, so that an
+/// application can change the language or formatting of its messages
+/// without revalidating the form.
+///
+/// This is implemented for closures with the signature
+/// `Fn(&ValidationError<Key>) -> Option<String>`, and a renderer for
+/// a locale of a [MessageCatalog](crate::MessageCatalog) can be
+/// obtained using
+/// [MessageCatalog::renderer()](crate::MessageCatalog::renderer()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, MessageRenderer, Validation, ValidationError, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::string_length(3, 16);
+/// let errors = v.validate_value(&"al".to_string(), &"username").unwrap_err();
+///
+/// let renderer = |error: &ValidationError<&str>| match error.type_id.as_ref() {
+///     "LENGTH_OUT_OF_RANGE" => Some(format!(
+///         "Between {} and {} characters please",
+///         error.param("min")?,
+///         error.param("max")?
+///     )),
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     "Between 3 and 16 characters please",
+///     errors.errors[0].render(&renderer)
+/// );
+/// // errors without a rendered message fall back to their own message
+/// let error = ValidationError::new("email", "REQUIRED").message("Email is required");
+/// assert_eq!("Email is required", error.render(&renderer));
+/// ```
+pub trait MessageRenderer<Key> {
+    /// Render the message for the `error`, or return `None` to use
+    /// the message which the error was produced with.
+    fn render(&self, error: &ValidationError<Key>) -> Option<String>;
+}
+
+impl<Key, F> MessageRenderer<Key> for F
+where
+    F: Fn(&ValidationError<Key>) -> Option<String>,
+{
+    fn render(&self, error: &ValidationError<Key>) -> Option<String> {
+        (self)(error)
+    }
+}
+
+impl<Key> ValidationError<Key> {
+    /// Render the message for this error using the `renderer`,
+    /// falling back to the message which this error was produced
+    /// with if the renderer doesn't have one, see [MessageRenderer].
+    pub fn render<R>(&self, renderer: &R) -> String
+    where
+        R: MessageRenderer<Key> + ?Sized,
+    {
+        renderer.render(self).unwrap_or_else(|| self.to_string())
+    }
+}
+
+impl<Key> ValidationErrors<Key> {
+    /// Render the messages for all of these errors using the
+    /// `renderer`, see [ValidationError::render()].
+    pub fn render<R>(&self, renderer: &R) -> Vec<String>
+    where
+        R: MessageRenderer<Key> + ?Sized,
+    {
+        self.errors
+            .iter()
+            .map(|error| error.render(renderer))
+            .collect()
+    }
+}
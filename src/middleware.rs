@@ -0,0 +1,145 @@
+use crate::{ValidationError, ValidationErrors};
+use std::{fmt::Debug, rc::Rc};
+
+/// A cross-cutting policy applied to errors after validation, which
+/// can rewrite or enrich an error (by returning a modified error), or
+/// drop it (by returning `None`). For example masking internal error
+/// codes, or hiding errors for features which are in beta.
+///
+/// Middleware is installed using an [ErrorsPipeline], on a
+/// [Validator](crate::Validator::middleware()), an
+/// [AsyncValidator](crate::AsyncValidator::middleware()) or a
+/// [FormState](crate::FormState::middleware()). This trait is
+/// implemented for functions with the signature `Fn(ValidationError<Key>)
+/// -> Option<ValidationError<Key>>`.
+pub trait ErrorsMiddleware<Key> {
+    /// Process an `error`, returning the error to keep, or `None` to
+    /// drop it.
+    fn process(&self, error: ValidationError<Key>) -> Option<ValidationError<Key>>;
+}
+
+impl<Key, F> ErrorsMiddleware<Key> for F
+where
+    F: Fn(ValidationError<Key>) -> Option<ValidationError<Key>>,
+{
+    fn process(&self, error: ValidationError<Key>) -> Option<ValidationError<Key>> {
+        (self)(error)
+    }
+}
+
+/// A chain of [ErrorsMiddleware], applied to each error in the order
+/// that the middleware was added.
+///
+/// ## Example
+/// ```
+/// use form_validation::{ErrorsPipeline, ValidationError, ValidationErrors};
+///
+/// let pipeline: ErrorsPipeline<&'static str> = ErrorsPipeline::new()
+///     // drop errors for a field which is hidden for beta users
+///     .middleware(|error: ValidationError<&'static str>| {
+///         if error.key == "beta_feature" {
+///             None
+///         } else {
+///             Some(error)
+///         }
+///     })
+///     // mask internal error codes
+///     .middleware(|error: ValidationError<&'static str>| {
+///         if error.type_id.starts_with("internal.") {
+///             Some(ValidationError::new(error.key, "INTERNAL").message("Something went wrong"))
+///         } else {
+///             Some(error)
+///         }
+///     });
+///
+/// let errors = pipeline.apply(ValidationErrors::new(vec![
+///     ValidationError::new("beta_feature", "REQUIRED"),
+///     ValidationError::new("username", "internal.DB_TIMEOUT"),
+/// ]));
+///
+/// assert_eq!(1, errors.len());
+/// assert_eq!("INTERNAL", errors.errors[0].type_id);
+/// ```
+pub struct ErrorsPipeline<Key> {
+    middlewares: Vec<Rc<dyn ErrorsMiddleware<Key>>>,
+}
+
+impl<Key> ErrorsPipeline<Key> {
+    /// Create a new empty `ErrorsPipeline`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A factory method to append a [ErrorsMiddleware] to this
+    /// pipeline.
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: ErrorsMiddleware<Key> + 'static,
+    {
+        self.push(middleware);
+        self
+    }
+
+    /// Append a [ErrorsMiddleware] to this pipeline.
+    pub fn push<M>(&mut self, middleware: M)
+    where
+        M: ErrorsMiddleware<Key> + 'static,
+    {
+        self.middlewares.push(Rc::new(middleware));
+    }
+
+    /// Append all the middleware from `other` to this pipeline.
+    pub fn extend(&mut self, other: ErrorsPipeline<Key>) {
+        self.middlewares.extend(other.middlewares);
+    }
+
+    /// Whether this pipeline contains no middleware.
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    /// Apply the middleware in this pipeline to each of the `errors`.
+    pub fn apply(&self, mut errors: ValidationErrors<Key>) -> ValidationErrors<Key> {
+        self.apply_from(&mut errors, 0);
+        errors
+    }
+
+    /// Apply the middleware in this pipeline to the `errors` starting
+    /// at index `start`, leaving the errors before it untouched.
+    pub(crate) fn apply_from(&self, errors: &mut ValidationErrors<Key>, start: usize) {
+        if self.middlewares.is_empty() {
+            return;
+        }
+
+        let new_errors = errors.errors.split_off(start);
+        errors
+            .errors
+            .extend(new_errors.into_iter().filter_map(|error| {
+                self.middlewares
+                    .iter()
+                    .try_fold(error, |error, middleware| middleware.process(error))
+            }));
+    }
+}
+
+impl<Key> Clone for ErrorsPipeline<Key> {
+    fn clone(&self) -> Self {
+        Self {
+            middlewares: self.middlewares.clone(),
+        }
+    }
+}
+
+impl<Key> Default for ErrorsPipeline<Key> {
+    fn default() -> Self {
+        Self {
+            middlewares: Vec::new(),
+        }
+    }
+}
+
+impl<Key> Debug for ErrorsPipeline<Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ErrorsPipeline {{ len: {} }}", self.middlewares.len())
+    }
+}
@@ -0,0 +1,76 @@
+//! A convenience module which re-exports the traits, the most
+//! commonly used types, the built-in [validators](crate::validators)
+//! and the macros of this library, so that a form module needs only a
+//! single import:
+//!
+//! ```
+//! use form_validation::prelude::*;
+//!
+//! struct Signup {
+//!     username: String,
+//!     password: String,
+//!     password_confirm: String,
+//! }
+//!
+//! let username: Validator<String, &'static str> = Validator::new()
+//!     .validation(required())
+//!     .validation(string_length(3, 16))
+//!     .validation(identifier());
+//!
+//! let v: FormValidator<Signup, &'static str> = FormValidator::new()
+//!     .field("username", |form: &Signup| &form.username, username)
+//!     .compare(rule!(Signup: password == password_confirm));
+//!
+//! let signup = Signup {
+//!     username: "42user".to_string(),
+//!     password: "hunter2".to_string(),
+//!     password_confirm: "hunter2".to_string(),
+//! };
+//!
+//! let errors: ValidationErrors<&'static str> = v.validate(&signup).unwrap_err();
+//! assert_eq!("INVALID_IDENTIFIER", errors.errors[0].type_id);
+//! ```
+//!
+//! The validators which share a name with their dependency
+//! ([validators::url()](crate::validators::url()), and the
+//! [validators::chrono](crate::validators::chrono) and
+//! [validators::time](crate::validators::time) modules) are not
+//! re-exported, to avoid ambiguity with those crates, and can be
+//! used via the re-exported [validators](crate::validators) module.
+
+pub use crate::{
+    concat_results, rule, type_id, validation_error, validators, ErrorCode, FieldPath, FormField,
+    FormState, FormValidator, Help, IsEmpty, LabelProvider, MessageContext, MessageRenderer,
+    NestedKey, RevalidationStrategy, Severity, Validatable, Validation, ValidationError,
+    ValidationErrors, Validator, ValidatorFn, FORM_KEY,
+};
+
+#[cfg(feature = "async")]
+pub use crate::{AsyncValidatable, AsyncValidator, AsyncValidatorFn};
+
+pub use crate::validators::{
+    alphanumeric, ascii_only, base64, contains, coordinates, data_uri, date_after, date_before,
+    does_not_contain, ends_with, equals_field, file, identifier, items_count, known_keys, latitude,
+    longitude, multiple_of, not_in_future, not_in_past, number, one_of, range, required,
+    required_keys, starts_with, strict_fields, string_length, unique_items, unique_items_by, uuid,
+    uuid_version, ComparisonOperator, FieldComparison, FileInfo, Today,
+};
+
+#[cfg(feature = "email")]
+pub use crate::validators::email;
+#[cfg(feature = "finance")]
+pub use crate::validators::iban;
+#[cfg(feature = "net")]
+pub use crate::validators::ip_address;
+#[cfg(feature = "dates")]
+pub use crate::validators::iso_date;
+#[cfg(feature = "serde_json")]
+pub use crate::validators::json;
+#[cfg(feature = "zxcvbn")]
+pub use crate::validators::password_strength;
+#[cfg(feature = "regex")]
+pub use crate::validators::pattern;
+#[cfg(feature = "phone")]
+pub use crate::validators::phone;
+#[cfg(feature = "address")]
+pub use crate::validators::postal_code;
@@ -0,0 +1,127 @@
+use std::{fmt::Debug, rc::Rc, time::Duration};
+
+/// Coalesces bursts of field changes (for example when a value is
+/// pasted, or the browser autofills ten fields at once) into a single
+/// batched validation pass, which is performed once no further
+/// changes have occurred for a quiet period (the `delay`).
+///
+/// This doesn't own a timer, so that it can be used with any user
+/// interface framework (and on `wasm32-unknown-unknown`, where
+/// `std::time::Instant` is unavailable). Instead the current time is
+/// provided by the caller, as the duration since an arbitrary fixed
+/// point (for example the value of `performance.now()`), and the
+/// caller is responsible for setting a timer for the
+/// [deadline()](RevalidationScheduler::deadline()). Usually this is
+/// used via
+/// [FormState::schedule_field_changed()](crate::FormState::schedule_field_changed()).
+///
+/// ## Example
+/// ```
+/// use form_validation::RevalidationScheduler;
+/// use std::time::Duration;
+///
+/// let mut scheduler = RevalidationScheduler::new(Duration::from_millis(100));
+///
+/// scheduler.schedule("first_name", Duration::from_millis(0));
+/// let deadline = scheduler.schedule("last_name", Duration::from_millis(10));
+/// assert_eq!(Duration::from_millis(110), deadline);
+///
+/// assert!(!scheduler.is_due(Duration::from_millis(50)));
+/// assert!(scheduler.is_due(Duration::from_millis(110)));
+/// assert_eq!(vec!["first_name", "last_name"], scheduler.take());
+/// assert_eq!(None, scheduler.deadline());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RevalidationScheduler<Key> {
+    delay: Duration,
+    pending: Vec<Key>,
+    deadline: Option<Duration>,
+}
+
+impl<Key> RevalidationScheduler<Key>
+where
+    Key: PartialEq,
+{
+    /// Create a new `RevalidationScheduler` which waits for a quiet
+    /// period of `delay` after the most recent change before the
+    /// changed fields are due to be validated.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: Vec::new(),
+            deadline: None,
+        }
+    }
+
+    /// The quiet period after the most recent change before the
+    /// changed fields are due to be validated.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Record that the field with the specified `key` changed at the
+    /// time `now`, postponing the validation until the quiet period
+    /// after this change. Returns the new
+    /// [deadline()](RevalidationScheduler::deadline()).
+    pub fn schedule(&mut self, key: Key, now: Duration) -> Duration {
+        if !self.pending.contains(&key) {
+            self.pending.push(key);
+        }
+        let deadline = now + self.delay;
+        self.deadline = Some(deadline);
+        deadline
+    }
+
+    /// The time at which the changed fields are due to be validated,
+    /// or `None` if there are no changed fields.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// The keys of the fields which have changed since they were last
+    /// [taken](RevalidationScheduler::take()), in the order they first
+    /// changed.
+    pub fn pending(&self) -> &[Key] {
+        &self.pending
+    }
+
+    /// Whether the changed fields are due to be validated at the time
+    /// `now`.
+    pub fn is_due(&self, now: Duration) -> bool {
+        self.deadline.is_some_and(|deadline| now >= deadline)
+    }
+
+    /// Take the keys of the changed fields, regardless of whether they
+    /// are due, clearing the deadline.
+    pub fn take(&mut self) -> Vec<Key> {
+        self.deadline = None;
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// A caller-provided source of the current time, as the duration
+/// since an arbitrary fixed point (in the same way as the
+/// [RevalidationScheduler]), used to
+/// [stamp](crate::ValidationErrors::stamp()) errors when they are
+/// produced.
+#[derive(Clone)]
+pub(crate) struct Clock(Rc<dyn Fn() -> Duration>);
+
+impl Clock {
+    pub(crate) fn new<F>(now: F) -> Self
+    where
+        F: Fn() -> Duration + 'static,
+    {
+        Self(Rc::new(now))
+    }
+
+    pub(crate) fn now(&self) -> Duration {
+        (self.0)()
+    }
+}
+
+impl Debug for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Clock")
+    }
+}
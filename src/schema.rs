@@ -0,0 +1,351 @@
+//! Serializable descriptions of the rules for a field, which can be
+//! stored (for example in a database, so that administrators can
+//! configure forms at runtime), and later built into a [Validator].
+//!
+//! Rule sets carry a [version](RuleSet::version), so that rule sets
+//! stored by older versions of an application can be upgraded using
+//! [Migrations] when they are loaded, rather than failing to
+//! deserialize or producing incorrect validators. Each rule is stored
+//! as a name with string parameters, so that any rule set can be
+//! deserialized regardless of which version wrote it.
+//!
+//! ## Example
+//! ```
+//! use form_validation::schema::{Migrations, RuleSet};
+//! use form_validation::{Validation, Validator};
+//!
+//! // stored by version 1 of the application, which called the rule
+//! // "length" rather than "string_length".
+//! let stored = r#"{
+//!     "version": 1,
+//!     "rules": [
+//!         { "rule": "required" },
+//!         { "rule": "length", "params": { "min": "3", "max": "16" } }
+//!     ]
+//! }"#;
+//!
+//! let migrations = Migrations::new(2).migration(1, |rule_set: &mut RuleSet| {
+//!     for rule in &mut rule_set.rules {
+//!         if rule.rule == "length" {
+//!             rule.rule = "string_length".to_string();
+//!         }
+//!     }
+//! });
+//!
+//! let rule_set: RuleSet = serde_json::from_str(stored).unwrap();
+//! let rule_set = migrations.migrate(rule_set).unwrap();
+//! assert_eq!(2, rule_set.version);
+//!
+//! let v: Validator<String, &str> = rule_set.build().unwrap();
+//! assert!(v.validate_value(&"alice".to_string(), &"username").is_ok());
+//! assert!(v.validate_value(&"al".to_string(), &"username").is_err());
+//! ```
+
+use crate::{validators, IsEmpty, Validator, ValidatorFn};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display, rc::Rc};
+
+/// A serializable description of a single built-in validation rule,
+/// made up of the name of the rule and its parameters. See
+/// [RuleDescriptor::build()] for the supported rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleDescriptor {
+    /// The name of the rule, for example `"string_length"`.
+    pub rule: String,
+    /// The parameters for the rule, for example `min` and `max` for
+    /// `"string_length"`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl RuleDescriptor {
+    /// Create a new `RuleDescriptor` for the `rule` without any
+    /// parameters.
+    pub fn new<S: Into<String>>(rule: S) -> Self {
+        Self {
+            rule: rule.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// A factory method to add a parameter to this rule.
+    pub fn param<S: Into<String>, V: ToString>(mut self, name: S, value: V) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Build the validation function which this rule describes. The
+    /// following rules are supported:
+    ///
+    /// + `"required"` - [validators::required()].
+    /// + `"string_length"` with `min` and `max` -
+    ///   [validators::string_length()].
+    /// + `"alphanumeric"` - [validators::alphanumeric()].
+    /// + `"ascii_only"` - [validators::ascii_only()].
+    /// + `"identifier"` - [validators::identifier()].
+    /// + `"starts_with"` with `prefix` - [validators::starts_with()].
+    /// + `"ends_with"` with `suffix` - [validators::ends_with()].
+    /// + `"contains"` with `substring` - [validators::contains()].
+    /// + `"does_not_contain"` with `substring` -
+    ///   [validators::does_not_contain()].
+    /// + `"uuid"` - [validators::uuid()].
+    /// + `"email"` - [validators::email()] (`"email"` feature).
+    /// + `"iban"` - [validators::iban()] (`"finance"` feature).
+    /// + `"postal_code"` with `country` - [validators::postal_code()]
+    ///   (`"address"` feature).
+    /// + `"ip_address"` - [validators::ip_address()] (`"net"`
+    ///   feature).
+    /// + `"iso_date"` - [validators::iso_date()] (`"dates"`
+    ///   feature).
+    /// + `"pattern"` with `pattern` - [validators::pattern()]
+    ///   (`"regex"` feature).
+    pub fn build<Value, Key>(&self) -> Result<ValidatorFn<Value, Key>, SchemaError>
+    where
+        Value: AsRef<str> + IsEmpty + 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        match self.rule.as_str() {
+            "required" => Ok(validators::required()),
+            "string_length" => Ok(validators::string_length(
+                self.parsed_param("min")?,
+                self.parsed_param("max")?,
+            )),
+            "alphanumeric" => Ok(validators::alphanumeric()),
+            "ascii_only" => Ok(validators::ascii_only()),
+            "identifier" => Ok(validators::identifier()),
+            "starts_with" => Ok(validators::starts_with(self.required_param("prefix")?)),
+            "ends_with" => Ok(validators::ends_with(self.required_param("suffix")?)),
+            "contains" => Ok(validators::contains(self.required_param("substring")?)),
+            "does_not_contain" => Ok(validators::does_not_contain(
+                self.required_param("substring")?,
+            )),
+            "uuid" => Ok(validators::uuid()),
+            #[cfg(feature = "email")]
+            "email" => Ok(validators::email()),
+            #[cfg(feature = "finance")]
+            "iban" => Ok(validators::iban()),
+            #[cfg(feature = "address")]
+            "postal_code" => Ok(validators::postal_code(self.required_param("country")?)),
+            #[cfg(feature = "net")]
+            "ip_address" => Ok(validators::ip_address()),
+            #[cfg(feature = "dates")]
+            "iso_date" => Ok(validators::iso_date()),
+            #[cfg(feature = "regex")]
+            "pattern" => validators::pattern(self.required_param("pattern")?).map_err(|error| {
+                SchemaError::InvalidParam {
+                    rule: self.rule.clone(),
+                    param: "pattern".to_string(),
+                    reason: error.to_string(),
+                }
+            }),
+            _ => Err(SchemaError::UnknownRule(self.rule.clone())),
+        }
+    }
+
+    fn required_param(&self, name: &str) -> Result<&str, SchemaError> {
+        self.params
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| SchemaError::MissingParam {
+                rule: self.rule.clone(),
+                param: name.to_string(),
+            })
+    }
+
+    fn parsed_param<T>(&self, name: &str) -> Result<T, SchemaError>
+    where
+        T: std::str::FromStr,
+        T::Err: Display,
+    {
+        self.required_param(name)?
+            .parse()
+            .map_err(|error: T::Err| SchemaError::InvalidParam {
+                rule: self.rule.clone(),
+                param: name.to_string(),
+                reason: error.to_string(),
+            })
+    }
+}
+
+/// A serializable, versioned set of rules for a field, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    /// The version of the application's rule format which this rule
+    /// set was written with. Rule sets written before versioning was
+    /// introduced are loaded as version `0`.
+    #[serde(default)]
+    pub version: u32,
+    /// The rules, in the order they are validated.
+    pub rules: Vec<RuleDescriptor>,
+}
+
+impl RuleSet {
+    /// Create a new empty `RuleSet` with the specified `version`.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            rules: Vec::new(),
+        }
+    }
+
+    /// A factory method to add a rule to this rule set.
+    pub fn rule(mut self, rule: RuleDescriptor) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Build a [Validator] containing the validation functions for
+    /// all the rules in this set, see [RuleDescriptor::build()].
+    pub fn build<Value, Key>(&self) -> Result<Validator<Value, Key>, SchemaError>
+    where
+        Value: AsRef<str> + IsEmpty + 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        self.rules
+            .iter()
+            .try_fold(Validator::new(), |validator, rule| {
+                Ok(validator.validation(rule.build()?))
+            })
+    }
+}
+
+/// An error produced while building or migrating a [RuleSet].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    /// The rule is not known (or the feature it requires is not
+    /// enabled).
+    UnknownRule(String),
+    /// A parameter required by the rule is missing.
+    MissingParam {
+        /// The name of the rule.
+        rule: String,
+        /// The name of the missing parameter.
+        param: String,
+    },
+    /// A parameter for the rule has an invalid value.
+    InvalidParam {
+        /// The name of the rule.
+        rule: String,
+        /// The name of the invalid parameter.
+        param: String,
+        /// Why the value is invalid.
+        reason: String,
+    },
+    /// The rule set was written by a newer version of the
+    /// application than this one supports.
+    UnsupportedVersion {
+        /// The version of the rule set.
+        version: u32,
+        /// The current version supported by the [Migrations].
+        current: u32,
+    },
+    /// There is no migration from `version` to the next version.
+    MissingMigration {
+        /// The version which could not be migrated from.
+        version: u32,
+    },
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaError::UnknownRule(rule) => write!(f, "unknown rule {:?}", rule),
+            SchemaError::MissingParam { rule, param } => {
+                write!(f, "rule {:?} is missing the parameter {:?}", rule, param)
+            }
+            SchemaError::InvalidParam {
+                rule,
+                param,
+                reason,
+            } => write!(
+                f,
+                "rule {:?} has an invalid value for the parameter {:?}: {}",
+                rule, param, reason
+            ),
+            SchemaError::UnsupportedVersion { version, current } => write!(
+                f,
+                "rule set version {} is newer than the current version {}",
+                version, current
+            ),
+            SchemaError::MissingMigration { version } => {
+                write!(f, "no migration from rule set version {}", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+type Migration = Rc<dyn Fn(&mut RuleSet)>;
+
+/// A chain of migrations to upgrade a [RuleSet] written by an older
+/// version of an application to the `current` version, one version
+/// at a time. See the [module documentation](self) for an example.
+#[derive(Clone)]
+pub struct Migrations {
+    current: u32,
+    migrations: BTreeMap<u32, Migration>,
+}
+
+impl Migrations {
+    /// Create a new `Migrations` for rule sets with the `current`
+    /// version.
+    pub fn new(current: u32) -> Self {
+        Self {
+            current,
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// The current version of rule sets.
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// A factory method to add the migration which upgrades a rule
+    /// set from version `from` to version `from + 1`. The version of
+    /// the rule set is updated after the `migration` is applied.
+    pub fn migration<M>(mut self, from: u32, migration: M) -> Self
+    where
+        M: Fn(&mut RuleSet) + 'static,
+    {
+        self.migrations.insert(from, Rc::new(migration));
+        self
+    }
+
+    /// Upgrade the `rule_set` to the current version, by applying
+    /// each of the migrations from its version in turn. Returns an
+    /// error if the rule set is newer than the current version, or a
+    /// required migration is missing.
+    pub fn migrate(&self, mut rule_set: RuleSet) -> Result<RuleSet, SchemaError> {
+        if rule_set.version > self.current {
+            return Err(SchemaError::UnsupportedVersion {
+                version: rule_set.version,
+                current: self.current,
+            });
+        }
+
+        while rule_set.version < self.current {
+            let migration =
+                self.migrations
+                    .get(&rule_set.version)
+                    .ok_or(SchemaError::MissingMigration {
+                        version: rule_set.version,
+                    })?;
+            migration(&mut rule_set);
+            rule_set.version += 1;
+        }
+
+        Ok(rule_set)
+    }
+}
+
+impl std::fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrations")
+            .field("current", &self.current)
+            .field("migrations", &self.migrations.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Severity;
+use std::{borrow::Cow, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+/// An anonymized summary of a single validation run, provided to a
+/// [ValidationSink]. It contains no values or messages, only the key
+/// of the field that was validated and the identifiers of the types
+/// of errors which were produced, so it is suitable for product
+/// analytics (for example measuring which fields cause the most
+/// friction for users).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationOutcome<'a, Key> {
+    /// The key for the field which was validated.
+    pub key: &'a Key,
+    /// The [type_id](crate::ValidationError::type_id) of each error
+    /// that was produced. Empty if the value was valid.
+    pub type_ids: Vec<Cow<'static, str>>,
+    /// The [severity](crate::ValidationError::severity()) of each
+    /// error that was produced, in the same order as the `type_ids`.
+    pub severities: Vec<Severity>,
+    /// How long the validation took. This is `None` on the
+    /// `wasm32-unknown-unknown` platform where it cannot be measured
+    /// using the standard library.
+    pub duration: Option<Duration>,
+    /// Whether the validation was performed asynchronously.
+    pub is_async: bool,
+}
+
+impl<'a, Key> ValidationOutcome<'a, Key> {
+    /// Whether the validated value was valid.
+    pub fn is_valid(&self) -> bool {
+        self.type_ids.is_empty()
+    }
+
+    /// Whether any of the errors that were produced are blocking
+    /// (with the [Severity::Error] severity), rather than only
+    /// warnings or informational notices.
+    pub fn has_errors(&self) -> bool {
+        self.severities.contains(&Severity::Error)
+    }
+}
+
+/// Receives a [ValidationOutcome] after each validation run
+/// performed by a [Validator](crate::Validator) that the sink has
+/// been installed on using
+/// [Validator::sink()](crate::Validator::sink()).
+///
+/// This is implemented for closures with the signature
+/// `Fn(&ValidationOutcome<Key>)`.
+pub trait ValidationSink<Key> {
+    /// Record the outcome of a validation run.
+    fn record(&self, outcome: &ValidationOutcome<'_, Key>);
+}
+
+impl<Key, F> ValidationSink<Key> for F
+where
+    F: Fn(&ValidationOutcome<'_, Key>),
+{
+    fn record(&self, outcome: &ValidationOutcome<'_, Key>) {
+        (self)(outcome)
+    }
+}
+
+/// Measures the duration of a validation run, where supported.
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Some(self.start.elapsed())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            None
+        }
+    }
+}
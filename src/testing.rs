@@ -0,0 +1,162 @@
+//! Utilities for testing validators.
+
+use crate::{Validation, ValidationErrors};
+use std::{borrow::Cow, fmt::Debug};
+
+/// The number of times each sample is validated by [check_pure()].
+const REPETITIONS: usize = 3;
+
+/// A summary of the errors produced by a validation run, which can be
+/// compared with the summary of another run.
+type Summary<Key> = Vec<(
+    Key,
+    Cow<'static, str>,
+    String,
+    Vec<(Cow<'static, str>, String)>,
+)>;
+
+fn summarize<Key: Clone>(result: Result<(), ValidationErrors<Key>>) -> Summary<Key> {
+    result
+        .err()
+        .unwrap_or_default()
+        .errors
+        .iter()
+        .map(|error| {
+            (
+                error.key.clone(),
+                error.type_id.clone(),
+                error.to_string(),
+                error.params().to_vec(),
+            )
+        })
+        .collect()
+}
+
+/// Check that `validation` is pure: validating each of the `samples`
+/// (values with the key to validate them with) repeatedly, and in a
+/// different order, always produces the same errors (with the same
+/// keys, type ids, messages and parameters). This catches
+/// accidentally stateful validation functions (for example ones which
+/// count how many times they have been called), which break the
+/// assumptions made when caching or memoizing validation results.
+///
+/// See [check_pure_with_probe()] to also check for side effects.
+///
+/// # Panics
+///
+/// Panics with a description of the first sample which produced
+/// different results.
+///
+/// ## Example
+/// ```
+/// use form_validation::{testing::check_pure, validators, ValidationError, ValidatorFn};
+/// use std::cell::Cell;
+///
+/// let v: ValidatorFn<String, &str> = validators::string_length(2, 5);
+/// check_pure(&v, &[("ab".to_string(), "name"), ("a".to_string(), "name")]);
+///
+/// // a validator which only fails the first time it is called
+/// let called = Cell::new(false);
+/// let stateful: ValidatorFn<String, &str> = ValidatorFn::new(move |_: &String, key: &&str| {
+///     if called.replace(true) {
+///         Ok(())
+///     } else {
+///         Err(ValidationError::new(*key, "FIRST_CALL").into())
+///     }
+/// });
+///
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     check_pure(&stateful, &[("ab".to_string(), "name")]);
+/// }));
+/// assert!(result.is_err());
+/// ```
+pub fn check_pure<Value, Key, V>(validation: &V, samples: &[(Value, Key)])
+where
+    Value: Debug,
+    Key: Clone + PartialEq + Debug,
+    V: Validation<Value, Key> + ?Sized,
+{
+    check_pure_with_probe(validation, samples, || ());
+}
+
+/// The same as [check_pure()], but also checks that validation has no
+/// observable side effects, using a `probe` which captures the
+/// external state that the validation function might modify (for
+/// example the contents of a shared cache, or the number of requests
+/// recorded by a mock service). The `probe` is called before and
+/// after all the samples have been validated, and the captured states
+/// must be equal.
+///
+/// # Panics
+///
+/// Panics if validation is not deterministic (see [check_pure()]), or
+/// if the state captured by the `probe` changed.
+///
+/// ## Example
+/// ```
+/// use form_validation::{testing::check_pure_with_probe, ValidatorFn};
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+/// let validator_log = log.clone();
+/// let logging: ValidatorFn<String, &str> = ValidatorFn::new(move |value: &String, _: &&str| {
+///     validator_log.borrow_mut().push(value.clone());
+///     Ok(())
+/// });
+///
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     check_pure_with_probe(&logging, &[("ab".to_string(), "name")], || log.borrow().len());
+/// }));
+/// assert!(result.is_err());
+/// ```
+pub fn check_pure_with_probe<Value, Key, V, P, S>(
+    validation: &V,
+    samples: &[(Value, Key)],
+    mut probe: P,
+) where
+    Value: Debug,
+    Key: Clone + PartialEq + Debug,
+    V: Validation<Value, Key> + ?Sized,
+    P: FnMut() -> S,
+    S: PartialEq + Debug,
+{
+    let before = probe();
+
+    let expected: Vec<Summary<Key>> = samples
+        .iter()
+        .map(|(value, key)| summarize(validation.validate_value(value, key)))
+        .collect();
+
+    for repetition in 1..REPETITIONS {
+        // alternate the order, to catch results which depend on the
+        // previously validated value.
+        let order: Vec<usize> = if repetition % 2 == 1 {
+            (0..samples.len()).rev().collect()
+        } else {
+            (0..samples.len()).collect()
+        };
+
+        for index in order {
+            let (value, key) = &samples[index];
+            let actual = summarize(validation.validate_value(value, key));
+            assert!(
+                actual == expected[index],
+                "validation is not deterministic for the value {:?} with the key {:?}: \
+                 first produced {:?}, then produced {:?}",
+                value,
+                key,
+                expected[index],
+                actual
+            );
+        }
+    }
+
+    let after = probe();
+    assert!(
+        before == after,
+        "validation has side effects: the probe captured {:?} before validation, \
+         and {:?} after",
+        before,
+        after
+    );
+}
@@ -0,0 +1,292 @@
+use crate::codes;
+use std::{collections::HashMap, fmt::Display};
+
+/// The [type_id](crate::ValidationError::type_id)s of the errors
+/// produced by this library (including the built-in
+/// [validators](crate::validators) which are disabled by cargo
+/// features), so they can be reserved in a [TypeIdRegistry]. Each of
+/// these has a constant in the [codes] module.
+pub const BUILTIN_TYPE_IDS: &[&str] = &[
+    codes::VALIDATOR_PANICKED,
+    codes::MISSING_KEY,
+    codes::UNKNOWN_KEY,
+    codes::UNKNOWN_FIELD,
+    codes::REQUIRED,
+    codes::FIELDS_DO_NOT_MATCH,
+    codes::FIELDS_MATCH,
+    codes::FIELD_COMPARISON_FAILED,
+    codes::LENGTH_OUT_OF_RANGE,
+    codes::ITEMS_COUNT_OUT_OF_RANGE,
+    codes::DUPLICATE_ITEM,
+    codes::NOT_ALPHANUMERIC,
+    codes::NOT_ASCII,
+    codes::INVALID_IDENTIFIER,
+    codes::MISSING_PREFIX,
+    codes::MISSING_SUFFIX,
+    codes::MISSING_SUBSTRING,
+    codes::FORBIDDEN_SUBSTRING,
+    codes::OUT_OF_RANGE,
+    codes::INVALID_NUMBER,
+    codes::NOT_MULTIPLE_OF,
+    codes::INVALID_COORDINATE,
+    codes::COORDINATE_OUT_OF_RANGE,
+    codes::TOO_MANY_DECIMAL_PLACES,
+    codes::NOT_ONE_OF,
+    codes::DATE_NOT_BEFORE,
+    codes::DATE_NOT_AFTER,
+    codes::DATE_IN_FUTURE,
+    codes::DATE_IN_PAST,
+    codes::INVALID_UUID,
+    codes::UUID_VERSION_MISMATCH,
+    codes::INVALID_EMAIL,
+    codes::INVALID_IBAN,
+    codes::POSTAL_CODE_INVALID,
+    codes::INVALID_IP_ADDRESS,
+    codes::INVALID_DATE,
+    codes::INVALID_DATETIME,
+    codes::PATTERN_MISMATCH,
+    codes::URL_INVALID,
+    codes::URL_SCHEME_NOT_ALLOWED,
+    codes::URL_HOST_REQUIRED,
+    codes::PHONE_INVALID,
+    codes::PHONE_INVALID_FOR_REGION,
+    codes::WEAK_PASSWORD,
+    codes::FILE_TOO_LARGE,
+    codes::FILE_EXTENSION_NOT_ALLOWED,
+    codes::FILE_TYPE_NOT_ALLOWED,
+    codes::INVALID_BASE64,
+    codes::DECODED_SIZE_TOO_LARGE,
+    codes::INVALID_DATA_URI,
+    codes::DATA_URI_TYPE_NOT_ALLOWED,
+    codes::INVALID_JSON,
+    codes::INVALID_BOOLEAN,
+    codes::INVALID_CHARACTER,
+];
+
+/// The owner of the [BUILTIN_TYPE_IDS] in a [TypeIdRegistry].
+pub const BUILTIN_OWNER: &str = "form-validation";
+
+/// Whether `type_id` follows the naming convention for a
+/// [type_id](crate::ValidationError::type_id): one or more non-empty
+/// segments of ASCII letters, digits and `_`, separated by `.`, for
+/// example `"INVALID_EMAIL"` or `"myapp.billing.INVALID_VAT"`.
+///
+/// This is a `const fn`, so that it can be checked at compile time by
+/// the [type_id!](crate::type_id!) macro.
+pub const fn is_valid_type_id(type_id: &str) -> bool {
+    let bytes = type_id.as_bytes();
+    let mut segment_len = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte == b'.' {
+            if segment_len == 0 {
+                return false;
+            }
+            segment_len = 0;
+        } else if byte.is_ascii_alphanumeric() || byte == b'_' {
+            segment_len += 1;
+        } else {
+            return false;
+        }
+        i += 1;
+    }
+
+    segment_len > 0
+}
+
+/// Build a namespaced [type_id](crate::ValidationError::type_id) from
+/// string literals, joined with `.`. The result is a `&'static str`,
+/// which is checked at compile time using [is_valid_type_id()].
+///
+/// ## Example
+/// ```
+/// use form_validation::type_id;
+///
+/// const INVALID_VAT: &str = type_id!("myapp", "billing", "INVALID_VAT");
+/// assert_eq!("myapp.billing.INVALID_VAT", INVALID_VAT);
+/// ```
+///
+/// Invalid type ids fail to compile:
+///
+/// ```compile_fail
+/// use form_validation::type_id;
+///
+/// const INVALID: &str = type_id!("myapp", "INVALID VAT");
+/// ```
+#[macro_export]
+macro_rules! type_id {
+    ($first:literal $(, $rest:literal)* $(,)?) => {{
+        const TYPE_ID: &str = concat!($first $(, ".", $rest)*);
+        const _: () = assert!(
+            $crate::is_valid_type_id(TYPE_ID),
+            "invalid type_id, expected segments of ASCII letters, digits and _ separated by ."
+        );
+        TYPE_ID
+    }};
+}
+
+/// An application's own set of error codes, usually an enum, which
+/// are converted to and from the
+/// [type_id](crate::ValidationError::type_id) of errors. Creating
+/// errors using [ValidationError::from_code()](crate::ValidationError::from_code())
+/// and checking them using
+/// [ValidationError::code()](crate::ValidationError::code()) prevents
+/// typos in the codes, and allows matching on them exhaustively.
+///
+/// ## Example
+/// ```
+/// use form_validation::{ErrorCode, ValidationError};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum AppError {
+///     UsernameTaken,
+///     InvalidVat,
+/// }
+///
+/// impl ErrorCode for AppError {
+///     const ALL: &'static [Self] = &[AppError::UsernameTaken, AppError::InvalidVat];
+///
+///     fn type_id(&self) -> &'static str {
+///         match self {
+///             AppError::UsernameTaken => "USERNAME_TAKEN",
+///             AppError::InvalidVat => "billing.INVALID_VAT",
+///         }
+///     }
+/// }
+///
+/// let error = ValidationError::from_code("username", AppError::UsernameTaken);
+/// assert_eq!("USERNAME_TAKEN", error.type_id);
+///
+/// let message = match error.code::<AppError>() {
+///     Some(AppError::UsernameTaken) => "That username is taken",
+///     Some(AppError::InvalidVat) => "Invalid VAT number",
+///     None => "Invalid value",
+/// };
+/// assert_eq!("That username is taken", message);
+///
+/// // errors from the built-in validators aren't application codes
+/// assert_eq!(None, ValidationError::new("email", "INVALID_EMAIL").code::<AppError>());
+/// ```
+pub trait ErrorCode: Copy + 'static {
+    /// All the error codes.
+    const ALL: &'static [Self];
+
+    /// The [type_id](crate::ValidationError::type_id) for this error
+    /// code.
+    fn type_id(&self) -> &'static str;
+
+    /// The error code with the specified `type_id`, if there is one.
+    fn from_type_id(type_id: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|code| code.type_id() == type_id)
+    }
+}
+
+/// An error returned by [TypeIdRegistry::register()] when a
+/// [type_id](crate::ValidationError::type_id) has already been
+/// registered by a different owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeIdCollision {
+    /// The type id which was registered more than once.
+    pub type_id: &'static str,
+    /// The owner which originally registered the type id.
+    pub existing_owner: &'static str,
+    /// The owner which attempted to register the type id again.
+    pub owner: &'static str,
+}
+
+impl Display for TypeIdCollision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "type_id {:?} registered by {:?} is already registered by {:?}",
+            self.type_id, self.owner, self.existing_owner
+        )
+    }
+}
+
+impl std::error::Error for TypeIdCollision {}
+
+/// A registry of the [type_id](crate::ValidationError::type_id)s in
+/// use by an application, and the module or library which owns each
+/// of them, to detect accidental clashes between codes in large
+/// applications which mix built-in and custom codes (for example in a
+/// unit test which registers all the application's codes).
+///
+/// ## Example
+/// ```
+/// use form_validation::{type_id, TypeIdRegistry};
+///
+/// let mut registry = TypeIdRegistry::with_builtins();
+///
+/// assert!(registry.register(type_id!("billing", "INVALID_VAT"), "billing").is_ok());
+/// // registering the same code again with the same owner is allowed
+/// assert!(registry.register(type_id!("billing", "INVALID_VAT"), "billing").is_ok());
+///
+/// let collision = registry.register("INVALID_EMAIL", "accounts").unwrap_err();
+/// assert_eq!("form-validation", collision.existing_owner);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypeIdRegistry {
+    owners: HashMap<&'static str, &'static str>,
+}
+
+impl TypeIdRegistry {
+    /// Create a new empty `TypeIdRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `TypeIdRegistry` with the [BUILTIN_TYPE_IDS]
+    /// already registered to [BUILTIN_OWNER].
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for type_id in BUILTIN_TYPE_IDS {
+            registry.owners.insert(type_id, BUILTIN_OWNER);
+        }
+        registry
+    }
+
+    /// Register a `type_id` as being owned by `owner`. Returns an
+    /// error if it has already been registered by a different owner.
+    pub fn register(
+        &mut self,
+        type_id: &'static str,
+        owner: &'static str,
+    ) -> Result<(), TypeIdCollision> {
+        match self.owners.get(type_id) {
+            Some(existing_owner) if *existing_owner != owner => Err(TypeIdCollision {
+                type_id,
+                existing_owner,
+                owner,
+            }),
+            _ => {
+                self.owners.insert(type_id, owner);
+                Ok(())
+            }
+        }
+    }
+
+    /// Register the type ids of all the [ErrorCode]s `C` as being
+    /// owned by `owner`. Returns the first collision with a type id
+    /// which has already been registered by a different owner.
+    pub fn register_codes<C: ErrorCode>(
+        &mut self,
+        owner: &'static str,
+    ) -> Result<(), TypeIdCollision> {
+        for code in C::ALL {
+            self.register(code.type_id(), owner)?;
+        }
+        Ok(())
+    }
+
+    /// Get the owner of `type_id`, or `None` if it hasn't been
+    /// registered.
+    pub fn owner(&self, type_id: &str) -> Option<&'static str> {
+        self.owners.get(type_id).copied()
+    }
+}
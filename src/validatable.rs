@@ -1,11 +1,5 @@
 use crate::ValidationErrors;
 
-#[cfg(feature = "async")]
-use futures::Future;
-
-#[cfg(feature = "async")]
-use std::pin::Pin;
-
 /// An item that can be validated.
 pub trait Validatable<Key> {
     /// Validate this item. Returns `Ok(())` if no errors were
@@ -21,32 +15,13 @@ pub trait Validatable<Key> {
             Err(errors) => errors,
         }
     }
-}
-
-/// An item that can be validated asynchronously.
-///
-/// See [Validatable] for the synchronous version.
-#[cfg(feature = "async")]
-#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-pub trait AsyncValidatable<Key>
-where
-    Key: 'static,
-{
-    /// Creates a future that will validate this item. The future
-    /// returns `Ok(())` if no errors were encountered, and returns
-    /// `Err(ValidationErrors)` if any errors were encountered.
-    fn validate_future(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>;
-    /// Creates a future that will validate this item. The future
-    /// returns an empty [ValidationErrors](ValidationErrors) if no
-    /// errors were encountered during validation.
-    fn validate_future_or_empty(&self) -> Pin<Box<dyn Future<Output = ValidationErrors<Key>>>> {
-        let future = self.validate_future();
-        Box::pin(async move {
-            let result: Result<(), ValidationErrors<Key>> = future.await;
-            match result {
-                Ok(()) => ValidationErrors::default(),
-                Err(errors) => errors,
-            }
-        })
+    /// Validate this item, only failing if there are blocking errors
+    /// (with the [Severity::Error](crate::Severity::Error) severity),
+    /// so that soft rules (for example "weak password, but allowed")
+    /// don't prevent the form from being submitted. Returns `Ok` with
+    /// any non-blocking warnings and notices, see
+    /// [ValidationErrors::partition_result()].
+    fn validate_with_warnings(&self) -> Result<ValidationErrors<Key>, ValidationErrors<Key>> {
+        ValidationErrors::partition_result(self.validate())
     }
 }
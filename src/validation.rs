@@ -7,4 +7,81 @@ pub trait Validation<Value, Key> {
     /// contains a given `Value`, returns
     /// [ValidationErrors](ValidationErrors) if there are any.
     fn validate_value(&self, value: &Value, key: &Key) -> Result<(), ValidationErrors<Key>>;
+
+    /// Validate a given form field referenced by a given `Key`, that
+    /// contains a given `Value`, appending any errors to `errors`.
+    ///
+    /// This allows a caller validating many values (for example a
+    /// server validating thousands of requests per second) to reuse
+    /// the same buffer by calling
+    /// [ValidationErrors::clear()](ValidationErrors::clear()) between
+    /// validations, rather than allocating a new collection each
+    /// time. Validation functions created with
+    /// [ValidatorFn::new_into()](crate::ValidatorFn::new_into()) (as
+    /// the most commonly used [built-in validators](crate::validators)
+    /// are) push their errors directly into the buffer, so that they
+    /// don't allocate a collection for each failure either.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, ValidationErrors, Validator};
+    ///
+    /// let v: Validator<i32, String> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let key = "field1".to_string();
+    /// let mut errors = ValidationErrors::with_capacity(16);
+    ///
+    /// for value in &[-1, 2, -3] {
+    ///     errors.clear();
+    ///     v.validate_value_into(value, &key, &mut errors);
+    ///     assert_eq!(*value < 0, !errors.is_empty());
+    /// }
+    /// ```
+    fn validate_value_into(&self, value: &Value, key: &Key, errors: &mut ValidationErrors<Key>) {
+        if let Err(new_errors) = self.validate_value(value, key) {
+            errors.extend(new_errors)
+        }
+    }
+
+    /// Validate a given form field referenced by a given `Key`, that
+    /// contains a given `Value`, only failing if there are blocking
+    /// errors (with the [Severity::Error](crate::Severity::Error)
+    /// severity). Returns `Ok` with any non-blocking warnings and
+    /// notices, see [ValidationErrors::partition_result()].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, Validation, ValidationError, Validator};
+    ///
+    /// let v: Validator<String, &'static str> = Validator::new()
+    ///     .validation(|value: &String, key: &&'static str| {
+    ///         if value.is_empty() {
+    ///             Err(ValidationError::new(*key, "REQUIRED").into())
+    ///         } else if value.len() < 8 {
+    ///             Err(ValidationError::new(*key, "WEAK_PASSWORD")
+    ///                 .with_severity(Severity::Warning)
+    ///                 .into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let warnings = v.validate_value_with_warnings(&"hunter2".to_string(), &"password").unwrap();
+    /// assert_eq!("WEAK_PASSWORD", warnings.errors[0].type_id);
+    /// assert!(v.validate_value_with_warnings(&String::new(), &"password").is_err());
+    /// ```
+    fn validate_value_with_warnings(
+        &self,
+        value: &Value,
+        key: &Key,
+    ) -> Result<ValidationErrors<Key>, ValidationErrors<Key>> {
+        ValidationErrors::partition_result(self.validate_value(value, key))
+    }
 }
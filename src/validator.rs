@@ -1,11 +1,11 @@
-use crate::{Validation, ValidationErrors, ValidatorFn};
-use std::fmt::Debug;
-
-#[cfg(feature = "async")]
-use crate::AsyncValidatorFn;
-
-#[cfg(feature = "async")]
-use futures::future::join_all;
+use crate::{
+    telemetry::Stopwatch, ErrorsMiddleware, ErrorsPipeline, IsEmpty, MaybeSendSync, Validation,
+    ValidationErrors, ValidationOutcome, ValidationSink, ValidatorFn, ValidatorId,
+};
+use std::{
+    fmt::{Debug, Display},
+    rc::Rc,
+};
 
 /// Validates a particular type of value, can contain many validation
 /// functions. Generally used with a single key for all contained
@@ -50,22 +50,25 @@ use futures::future::join_all;
 /// {
 ///     let errors = v.validate_value(&11, &key).unwrap_err();
 ///     assert_eq!(1, errors.len());
-///     let error = errors.errors.get(0).unwrap();
+///     let error = errors.errors.first().unwrap();
 ///     assert_eq!("NOT_GREATER_THAN_10", error.type_id);
 /// }
-
+///
 /// assert!(v.validate_value(&5, &key).is_ok());
 ///
 /// {
 ///     let errors = v.validate_value(&-1, &key).unwrap_err();
 ///     assert_eq!(1, errors.len());
-///     let error = errors.errors.get(0).unwrap();
+///     let error = errors.errors.first().unwrap();
 ///     assert_eq!("NOT_LESS_THAN_0", error.type_id);
 /// }
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Validator<Value, Key> {
     pub validations: Vec<ValidatorFn<Value, Key>>,
+    pub(crate) sinks: Vec<Rc<dyn ValidationSink<Key>>>,
+    pub(crate) sanitizers: Vec<Rc<dyn Fn(Value) -> Value>>,
+    pub(crate) middleware: ErrorsPipeline<Key>,
 }
 
 impl<Value, Key> PartialEq for Validator<Value, Key> {
@@ -86,11 +89,41 @@ impl<Value, Key> PartialEq for Validator<Value, Key> {
     }
 }
 
+/// Prints the number of validation functions and their identifiers,
+/// which is stable between runs for validation functions created with
+/// [ValidatorFn::with_id()].
+///
+/// ## Example
+/// ```
+/// use form_validation::{Validator, ValidatorFn};
+///
+/// let v: Validator<i32, String> = Validator::new()
+///     .validation(ValidatorFn::with_id("not_negative", |_, _| Ok(())))
+///     .validation(|_: &i32, _: &String| Ok(()));
+///
+/// assert_eq!(
+///     "Validator { len: 2, validations: [ValidatorFn(\"not_negative\"), ValidatorFn(<anonymous>)], sinks: 0 }",
+///     format!("{:?}", v)
+/// );
+/// ```
+impl<Value, Key> Debug for Validator<Value, Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Validator")
+            .field("len", &self.validations.len())
+            .field("validations", &self.validations)
+            .field("sinks", &self.sinks.len())
+            .finish()
+    }
+}
+
 impl<Value, Key> Validator<Value, Key> {
     /// Create a new `Validator`.
     pub fn new() -> Self {
         Self {
             validations: Vec::new(),
+            sinks: Vec::new(),
+            sanitizers: Vec::new(),
+            middleware: ErrorsPipeline::new(),
         }
     }
 
@@ -102,241 +135,572 @@ impl<Value, Key> Validator<Value, Key> {
         self.validations.push(validator_fn.into());
         self
     }
-}
 
-impl<Value, Key> Validation<Value, Key> for Validator<Value, Key>
-where
-    Key: PartialEq + Clone,
-{
-    fn validate_value(&self, value: &Value, key: &Key) -> Result<(), ValidationErrors<Key>> {
-        let mut errors = ValidationErrors::default();
+    /// A factory method to install a [ValidationSink] on this
+    /// validator, which will receive an anonymized
+    /// [ValidationOutcome] after each validation run.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Severity, Validation, ValidationError, ValidationOutcome, Validator};
+    /// use std::{borrow::Cow, cell::RefCell, rc::Rc};
+    ///
+    /// let failures: Rc<RefCell<Vec<(Cow<'static, str>, Severity)>>> =
+    ///     Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let v: Validator<i32, String> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .sink({
+    ///         let failures = failures.clone();
+    ///         move |outcome: &ValidationOutcome<String>| {
+    ///             let errors = outcome.type_ids.iter().cloned();
+    ///             failures
+    ///                 .borrow_mut()
+    ///                 .extend(errors.zip(outcome.severities.iter().copied()));
+    ///         }
+    ///     });
+    ///
+    /// let key = "field1".to_string();
+    /// let _ = v.validate_value(&-1, &key);
+    /// let _ = v.validate_value(&1, &key);
+    ///
+    /// assert_eq!(
+    ///     vec![(Cow::Borrowed("NOT_LESS_THAN_0"), Severity::Error)],
+    ///     *failures.borrow()
+    /// );
+    ///
+    /// // sinks are retained when the key is mapped
+    /// let v = v.map_key(|key: &(usize, String)| key.1.clone());
+    /// let _ = v.validate_value(&-1, &(0, key));
+    /// assert_eq!(2, failures.borrow().len());
+    /// ```
+    pub fn sink<S>(mut self, sink: S) -> Self
+    where
+        S: ValidationSink<Key> + 'static,
+    {
+        self.sinks.push(Rc::new(sink));
+        self
+    }
 
-        for validation in &self.validations {
-            if let Err(new_errors) = validation.validate_value(value, key) {
-                errors.extend(new_errors)
-            }
+    /// A factory method to install an [ErrorsMiddleware] on this
+    /// validator, which is applied to the errors produced by the
+    /// validation functions (before they are recorded by any
+    /// [ValidationSink]s). See [ErrorsPipeline].
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, Validator};
+    ///
+    /// let v: Validator<i32, String> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "internal.NEGATIVE").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .middleware(|error: ValidationError<String>| {
+    ///         Some(ValidationError::new(error.key, "INVALID"))
+    ///     });
+    ///
+    /// let errors = v.validate_value(&-1, &"field1".to_string()).unwrap_err();
+    /// assert_eq!("INVALID", errors.errors[0].type_id);
+    /// ```
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: ErrorsMiddleware<Key> + 'static,
+    {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// A factory method to add a sanitizer to this validator, which
+    /// normalizes a value (for example trimming whitespace) before it
+    /// is validated by [Validator::validate_and_normalize()].
+    /// Sanitizers are applied in the order that they were added.
+    pub fn sanitizer<S>(mut self, sanitizer: S) -> Self
+    where
+        S: Fn(Value) -> Value + 'static,
+    {
+        self.sanitizers.push(Rc::new(sanitizer));
+        self
+    }
+
+    /// Normalize the `value` using this validator's sanitizers, and
+    /// then validate the normalized value. Returns the normalized
+    /// value to store, along with the result of validating it.
+    ///
+    /// [Validation::validate_value()] validates the value as it is,
+    /// without applying the sanitizers.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{ValidationError, Validator};
+    ///
+    /// let v: Validator<String, String> = Validator::new()
+    ///     .sanitizer(|value: String| value.trim().to_string())
+    ///     .validation(|value: &String, key: &String| {
+    ///         if value.is_empty() {
+    ///             Err(ValidationError::new(key.clone(), "REQUIRED").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let key = "name".to_string();
+    ///
+    /// let (value, result) = v.validate_and_normalize("  Alice ".to_string(), &key);
+    /// assert_eq!("Alice", value);
+    /// assert!(result.is_ok());
+    ///
+    /// let (value, result) = v.validate_and_normalize("   ".to_string(), &key);
+    /// assert_eq!("", value);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn validate_and_normalize(
+        &self,
+        value: Value,
+        key: &Key,
+    ) -> (Value, Result<(), ValidationErrors<Key>>)
+    where
+        Key: PartialEq + Clone,
+    {
+        let value = self
+            .sanitizers
+            .iter()
+            .fold(value, |value, sanitizer| sanitizer(value));
+        let result = self.validate_value(&value, key);
+        (value, result)
+    }
+
+    /// A factory method to append all the validation functions from
+    /// `other` to this validator. The validation functions are
+    /// flattened into a single list, so combining validators adds no
+    /// overhead when validating.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, Validator};
+    ///
+    /// let not_negative: Validator<i32, String> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// let v = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value > 10 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_GREATER_THAN_10").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .and(not_negative);
+    ///
+    /// assert_eq!(2, v.validations.len());
+    /// assert!(v.validate_value(&-1, &"field1".to_string()).is_err());
+    /// ```
+    pub fn and(mut self, other: Validator<Value, Key>) -> Self {
+        self.validations.extend(other.validations);
+        self.sinks.extend(other.sinks);
+        self.sanitizers.extend(other.sanitizers);
+        self.middleware.extend(other.middleware);
+        self
+    }
+
+    /// Convert this into a validator for an optional value, which
+    /// only performs validation when the value is `Some`. Each
+    /// validation function is converted individually using
+    /// [ValidatorFn::optional()], rather than nesting this validator
+    /// inside another.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, Validator};
+    ///
+    /// let v: Validator<Option<i32>, String> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .optional();
+    ///
+    /// let key = "field1".to_string();
+    /// assert!(v.validate_value(&None, &key).is_ok());
+    /// assert!(v.validate_value(&Some(-1), &key).is_err());
+    /// ```
+    pub fn optional(self) -> Validator<Option<Value>, Key>
+    where
+        Value: 'static,
+        Key: 'static,
+    {
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(ValidatorFn::optional)
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self
+                .sanitizers
+                .into_iter()
+                .map(|sanitizer| {
+                    Rc::new(move |value: Option<Value>| value.map(|value| sanitizer(value)))
+                        as Rc<dyn Fn(Option<Value>) -> Option<Value>>
+                })
+                .collect(),
+            middleware: self.middleware,
         }
+    }
 
-        if !errors.is_empty() {
-            Err(errors)
-        } else {
-            Ok(())
+    /// Convert this into a validator which only performs validation
+    /// when the value is not empty (see [IsEmpty]). Each validation
+    /// function is converted individually using
+    /// [ValidatorFn::skip_empty()].
+    pub fn skip_empty(self) -> Self
+    where
+        Value: IsEmpty + 'static,
+        Key: 'static,
+    {
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(ValidatorFn::skip_empty)
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self.sanitizers,
+            middleware: self.middleware,
         }
     }
-}
 
-impl<Value, Key> Default for Validator<Value, Key> {
-    fn default() -> Self {
-        Validator::new()
+    /// Convert this into a validator for a different key type, using
+    /// [ValidatorFn::map_key()] on each validation function. The
+    /// [ValidationSink]s are retained, and receive outcomes keyed by
+    /// the key produced by `key_fn`. The
+    /// [middleware](Validator::middleware()) is retained, and is
+    /// applied to the errors of each validation function before their
+    /// keys are mapped, because it was written for errors with the
+    /// original key type.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, Validator};
+    ///
+    /// let v: Validator<i32, (usize, String)> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
+    ///                 .with_message(|key| format!("{} cannot be negative", key))
+    ///                 .into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .map_key(|key: &(usize, String)| key.1.clone());
+    ///
+    /// let errors = v.validate_value(&-1, &(0, "quantity".to_string())).unwrap_err();
+    /// assert_eq!((0, "quantity".to_string()), errors.errors[0].key);
+    /// assert_eq!("quantity cannot be negative", errors.to_string());
+    ///
+    /// // the middleware is applied with the original key type
+    /// let v: Validator<i32, (usize, String)> = Validator::new()
+    ///     .validation(|value: &i32, key: &String| {
+    ///         if *value < 0 {
+    ///             Err(ValidationError::new(key.clone(), "internal.NEGATIVE").into())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .middleware(|error: ValidationError<String>| {
+    ///         Some(ValidationError::new(error.key, "INVALID"))
+    ///     })
+    ///     .map_key(|key: &(usize, String)| key.1.clone());
+    ///
+    /// let errors = v.validate_value(&-1, &(0, "quantity".to_string())).unwrap_err();
+    /// assert_eq!("INVALID", errors.errors[0].type_id);
+    /// ```
+    pub fn map_key<OuterKey, F>(self, key_fn: F) -> Validator<Value, OuterKey>
+    where
+        Value: 'static,
+        Key: MaybeSendSync + 'static,
+        OuterKey: Clone + PartialEq + 'static,
+        F: Fn(&OuterKey) -> Key + 'static,
+    {
+        let key_fn = Rc::new(key_fn);
+        let middleware = self.middleware;
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(|validation| {
+                    let key_fn = Rc::clone(&key_fn);
+                    validation.map_key_with_middleware(
+                        move |key: &OuterKey| key_fn(key),
+                        middleware.clone(),
+                    )
+                })
+                .collect(),
+            sinks: self
+                .sinks
+                .into_iter()
+                .map(|sink| {
+                    let key_fn = Rc::clone(&key_fn);
+                    Rc::new(move |outcome: &ValidationOutcome<'_, OuterKey>| {
+                        let key = key_fn(outcome.key);
+                        sink.record(&ValidationOutcome {
+                            key: &key,
+                            type_ids: outcome.type_ids.clone(),
+                            severities: outcome.severities.clone(),
+                            duration: outcome.duration,
+                            is_async: outcome.is_async,
+                        })
+                    }) as Rc<dyn ValidationSink<OuterKey>>
+                })
+                .collect(),
+            sanitizers: self.sanitizers,
+            middleware: ErrorsPipeline::new(),
+        }
     }
-}
 
-/// Validates a particular type of value asynchronously, can contain
-/// many validation functions. Generally used with a single key for
-/// all contained validation functions.
-///
-/// See [Validator] for the synchronous version.
-///
-/// ```
-/// use form_validation::{AsyncValidator, ValidationError, AsyncValidatorFn, ValidatorFn};
-/// use futures::executor::block_on;
-///
-/// let v: AsyncValidator<i32, String> = AsyncValidator::new()
-///     .validation(AsyncValidatorFn::new(|value: &i32, key: &String| {
-///         let value = *value;
-///         let key = key.clone();
-///         Box::pin(async move {
-///             if value < 0 {
-///                 Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
-///                     .with_message(move |key| {
-///                         format!("The value of {} ({}) cannot be less than 0", key, value)
-///                     })
-///                     .into()) // convert into ValidationErrors
-///             } else {
-///                 Ok(())
-///             }
-///         })
-///     }))
-///     // also supports compatibility with the synchronous ValidatorFn
-///     .validation(ValidatorFn::new(|value: &i32, key: &String| {
-///         if value > &10 {
-///             let value_clone = *value;
-///             Err(ValidationError::new(key.clone(), "NOT_GREATER_THAN_10")
-///                 .with_message(move |key| {
-///                     format!(
-///                         "The value of {} ({}) cannot be greater than 10",
-///                         key, value_clone
-///                     )
-///                 })
-///                 .into()) // convert into ValidationErrors
-///         } else {
-///             Ok(())
-///         }
-///     }));
-/// let key = "field1".to_string();
-/// {
-///     let errors = block_on(v.validate_value(&11, &key)).unwrap_err();
-///     assert_eq!(1, errors.len());
-///     let error = errors.errors.get(0).unwrap();
-///     assert_eq!("NOT_GREATER_THAN_10", error.type_id);
-/// }
-///
-/// assert!(block_on(v.validate_value(&5, &key)).is_ok());
-///
-/// {
-///     let errors = block_on(v.validate_value(&-1, &key)).unwrap_err();
-///     assert_eq!(1, errors.len());
-///     let error = errors.errors.get(0).unwrap();
-///     assert_eq!("NOT_LESS_THAN_0", error.type_id);
-/// }
-/// ```
-#[cfg(feature = "async")]
-#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-#[derive(Clone, PartialEq, Debug)]
-pub struct AsyncValidator<Value, Key> {
-    pub validations: Vec<AsyncValidatorFn<Value, Key>>,
-}
+    /// Convert this into a validator where each validation function
+    /// catches panics, using [ValidatorFn::catch_panics()].
+    pub fn catch_panics(self) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(ValidatorFn::catch_panics)
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self.sanitizers,
+            middleware: self.middleware,
+        }
+    }
 
-#[cfg(feature = "async")]
-impl<Value, Key> AsyncValidator<Value, Key>
-where
-    Key: Clone + PartialEq,
-    Value: Clone + PartialEq,
-{
-    /// Create a new `Validator`.
-    pub fn new() -> Self {
-        Self {
-            validations: Vec::new(),
+    /// Convert this into a validator where each validation function
+    /// catches panics, and reports the panic message to `on_panic`,
+    /// see [ValidatorFn::catch_panics_with()].
+    pub fn catch_panics_with<F>(self, on_panic: F) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+        F: Fn(&Key, &str) + 'static,
+    {
+        let on_panic = Rc::new(on_panic);
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(|validation| {
+                    let on_panic = Rc::clone(&on_panic);
+                    validation
+                        .catch_panics_with(move |key: &Key, reason: &str| on_panic(key, reason))
+                })
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self.sanitizers,
+            middleware: self.middleware,
         }
     }
 
-    /// A factory method to add a validation function to this validator.
-    pub fn validation<F: Into<AsyncValidatorFn<Value, Key>> + 'static>(
-        mut self,
-        async_validator_fn: F,
-    ) -> Self {
-        self.validations.push(async_validator_fn.into());
-        self
+    /// Convert this into a validator which masks occurrences of the
+    /// validated value in errors, using
+    /// [ValidatorFn::redact_values()] on each validation function,
+    /// without needing to change the validation functions
+    /// themselves.
+    pub fn redact_values(self) -> Self
+    where
+        Value: Display + 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(ValidatorFn::redact_values)
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self.sanitizers,
+            middleware: self.middleware,
+        }
     }
 
-    pub async fn validate_value(
-        &self,
-        value: &Value,
-        key: &Key,
-    ) -> Result<(), ValidationErrors<Key>> {
-        let mut errors = ValidationErrors::default();
+    /// Convert this into a validator which redacts errors using the
+    /// `redact` function, see
+    /// [ValidatorFn::redact_values_with()].
+    pub fn redact_values_with<R>(self, redact: R) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+        R: Fn(&Value, &str) -> String + 'static,
+    {
+        let redact = Rc::new(redact);
+        Validator {
+            validations: self
+                .validations
+                .into_iter()
+                .map(|validation| {
+                    let redact = Rc::clone(&redact);
+                    validation
+                        .redact_values_with(move |value: &Value, text: &str| redact(value, text))
+                })
+                .collect(),
+            sinks: self.sinks,
+            sanitizers: self.sanitizers,
+            middleware: self.middleware,
+        }
+    }
 
-        let futures = self
-            .validations
+    /// Human-readable descriptions of the rules enforced by this
+    /// validator, from the validation functions which have a
+    /// [description](ValidatorFn::description()). These can be shown
+    /// as hint text for a field, or exported by documentation
+    /// generators, so that the rules and their explanations have a
+    /// single source of truth.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validator, ValidatorFn};
+    ///
+    /// let v: Validator<String, String> = Validator::new()
+    ///     .validation(ValidatorFn::new(|_: &String, _: &String| Ok(())).described("must be unique"))
+    ///     .validation(ValidatorFn::new(|_: &String, _: &String| Ok(())));
+    ///
+    /// assert_eq!(vec!["must be unique"], v.describe());
+    /// ```
+    pub fn describe(&self) -> Vec<&str> {
+        self.validations
             .iter()
-            .map(|async_validator_fn| async_validator_fn.validate_value(value, key))
-            .collect::<Vec<_>>();
-
-        // Execute all the futures concurrently
-        let results: Vec<Result<(), ValidationErrors<Key>>> = join_all(futures).await;
+            .filter_map(|validation| validation.description())
+            .collect()
+    }
 
-        for result in results {
-            if let Err(new_errors) = result {
-                errors.extend(new_errors)
+    /// The [type_id](crate::ValidationError::type_id)s of all the
+    /// errors that the validation functions in this validator have
+    /// declared they can produce (see [ValidatorFn::produces()]),
+    /// without duplicates. For example to check that each of them has
+    /// a translation in a [MessageCatalog](crate::MessageCatalog).
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{validators, Validator};
+    ///
+    /// let v: Validator<String, &str> = Validator::new()
+    ///     .validation(validators::required())
+    ///     .validation(validators::string_length(3, 16))
+    ///     .validation(validators::uuid());
+    ///
+    /// assert_eq!(
+    ///     vec!["REQUIRED", "LENGTH_OUT_OF_RANGE", "INVALID_UUID"],
+    ///     v.possible_error_types()
+    /// );
+    /// ```
+    pub fn possible_error_types(&self) -> Vec<&'static str> {
+        let mut error_types = Vec::new();
+        for validation in &self.validations {
+            for error_type in validation.error_types() {
+                if !error_types.contains(error_type) {
+                    error_types.push(*error_type);
+                }
             }
         }
+        error_types
+    }
 
-        if !errors.is_empty() {
-            Err(errors)
-        } else {
-            Ok(())
-        }
+    /// Remove the validation function with the specified `id` from
+    /// this validator. Returns `true` if a validation function was
+    /// removed.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validator, ValidatorFn};
+    ///
+    /// let mut v: Validator<i32, String> = Validator::new()
+    ///     .validation(ValidatorFn::with_id("not_negative", |_, _| Ok(())));
+    ///
+    /// assert!(v.remove_validation("not_negative"));
+    /// assert!(v.validations.is_empty());
+    /// ```
+    pub fn remove_validation<I: Into<ValidatorId>>(&mut self, id: I) -> bool {
+        let id = id.into();
+        let len_before = self.validations.len();
+        self.validations.retain(|validation| validation.id() != &id);
+        self.validations.len() != len_before
     }
 }
 
-#[cfg(feature = "async")]
-impl<Value, Key> Default for AsyncValidator<Value, Key>
+impl<Value, Key> Validation<Value, Key> for Validator<Value, Key>
 where
-    Key: Clone + PartialEq,
-    Value: Clone + PartialEq,
+    Key: PartialEq + Clone,
 {
-    fn default() -> Self {
-        Self::new()
+    fn validate_value(&self, value: &Value, key: &Key) -> Result<(), ValidationErrors<Key>> {
+        let mut errors = ValidationErrors::default();
+
+        self.validate_value_into(value, key, &mut errors);
+
+        if !errors.is_empty() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
     }
-}
 
-#[cfg(feature = "async")]
-impl<Value, Key> From<Validator<Value, Key>> for AsyncValidator<Value, Key>
-where
-    Value: Clone + PartialEq + 'static,
-    Key: Clone + PartialEq + 'static,
-{
-    fn from(validator: Validator<Value, Key>) -> Self {
-        let mut async_validator: AsyncValidator<Value, Key> = AsyncValidator::new();
+    fn validate_value_into(&self, value: &Value, key: &Key, errors: &mut ValidationErrors<Key>) {
+        let stopwatch = if self.sinks.is_empty() {
+            None
+        } else {
+            Some(Stopwatch::start())
+        };
+        let len_before = errors.len();
 
-        for validator_fn in validator.validations {
-            async_validator = async_validator.validation(validator_fn);
+        for validation in &self.validations {
+            validation.validate_value_into(value, key, errors);
         }
 
-        async_validator
-    }
-}
+        self.middleware.apply_from(errors, len_before);
 
-#[cfg(test)]
-mod test {
-    #[cfg(feature = "async")]
-    mod async_tests {
-        use super::super::{AsyncValidator, Validator};
-        use crate::ValidationError;
-        use futures::executor::block_on;
-
-        /// Unit test for the `From<Validator> for AsyncValidator` implmentation
-        #[test]
-        fn async_validator_from_validator() {
-            let v: Validator<i32, String> = Validator::new()
-                .validation(|value: &i32, key: &String| {
-                    if value < &0 {
-                        let value_clone = *value;
-                        Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
-                            .with_message(move |key| {
-                                format!(
-                                    "The value of {} ({}) cannot be less than 0",
-                                    key, value_clone
-                                )
-                            })
-                            .into())
-                    } else {
-                        Ok(())
-                    }
-                })
-                .validation(|value: &i32, key: &String| {
-                    if value > &10 {
-                        let value_clone = *value;
-                        Err(ValidationError::new(key.clone(), "NOT_GREATER_THAN_10")
-                            .with_message(move |key| {
-                                format!(
-                                    "The value of {} ({}) cannot be greater than 10",
-                                    key, value_clone
-                                )
-                            })
-                            .into())
-                    } else {
-                        Ok(())
-                    }
-                });
-
-            // perform the conversion
-            let av: AsyncValidator<i32, String> = v.into();
-
-            let key = "field1".to_string();
-            {
-                let errors = block_on(av.validate_value(&11, &key)).unwrap_err();
-                assert_eq!(1, errors.len());
-                let error = errors.errors.get(0).unwrap();
-                assert_eq!("NOT_GREATER_THAN_10", error.type_id);
-            }
-            assert!(block_on(av.validate_value(&5, &key)).is_ok());
-            {
-                let errors = block_on(av.validate_value(&-1, &key)).unwrap_err();
-                assert_eq!(1, errors.len());
-                let error = errors.errors.get(0).unwrap();
-                assert_eq!("NOT_LESS_THAN_0", error.type_id);
+        if let Some(stopwatch) = stopwatch {
+            let outcome = ValidationOutcome {
+                key,
+                type_ids: errors.errors[len_before..]
+                    .iter()
+                    .map(|error| error.type_id.clone())
+                    .collect(),
+                severities: errors.errors[len_before..]
+                    .iter()
+                    .map(|error| error.severity())
+                    .collect(),
+                duration: stopwatch.elapsed(),
+                is_async: false,
+            };
+
+            for sink in &self.sinks {
+                sink.record(&outcome);
             }
         }
     }
 }
+
+impl<Value, Key> Default for Validator<Value, Key> {
+    fn default() -> Self {
+        Validator::new()
+    }
+}
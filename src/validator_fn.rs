@@ -1,11 +1,97 @@
-use crate::{Validation, ValidationErrors};
-use std::{fmt::Debug, rc::Rc};
+use crate::{
+    codes, ErrorsPipeline, IsEmpty, MaybeSendSync, Validation, ValidationError, ValidationErrors,
+};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fmt::{Debug, Display},
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
 use uuid::Uuid;
 
-#[cfg(feature = "async")]
-use std::{future::Future, marker::PhantomData, pin::Pin};
+type ValidatorFnTraitObject<Value, Key> = dyn Fn(&Value, &Key, &mut ValidationErrors<Key>);
 
-type ValidatorFnTraitObject<Value, Key> = dyn Fn(&Value, &Key) -> Result<(), ValidationErrors<Key>>;
+/// An identifier for a [ValidatorFn] (or
+/// [AsyncValidatorFn](crate::AsyncValidatorFn)), used
+/// to compare validation functions, and to remove them from a
+/// [Validator](crate::Validator).
+///
+/// ## Example
+/// ```
+/// use form_validation::{ValidatorFn, ValidatorId};
+///
+/// let v1: ValidatorFn<i32, String> = ValidatorFn::with_id("not_negative", |_, _| Ok(()));
+/// let v2: ValidatorFn<i32, String> = ValidatorFn::with_id("not_negative", |_, _| Ok(()));
+///
+/// assert_eq!(&ValidatorId::from("not_negative"), v1.id());
+/// assert_eq!(v1, v2);
+/// assert_eq!("ValidatorFn(\"not_negative\")", format!("{:?}", v1));
+/// ```
+///
+/// The [built-in validators](crate::validators) which are configured
+/// with parameters include them in their id, so that validators with
+/// different parameters are not equal:
+///
+/// ```
+/// use form_validation::{validators, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::string_length(2, 5);
+///
+/// assert_eq!("string_length(2, 5)", v.id().to_string());
+/// assert_eq!(v, validators::string_length(2, 5));
+/// assert_ne!(v, validators::string_length(10, 20));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ValidatorId {
+    /// A stable identifier supplied by the caller, which remains the
+    /// same across process restarts.
+    Named(Cow<'static, str>),
+    /// A random identifier generated when the validation function
+    /// was constructed.
+    Random(Uuid),
+}
+
+impl ValidatorId {
+    /// Generate a new random identifier.
+    pub fn random() -> Self {
+        Self::Random(Uuid::new_v4())
+    }
+}
+
+impl Display for ValidatorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidatorId::Named(name) => write!(f, "{}", name),
+            ValidatorId::Random(uuid) => write!(f, "{}", uuid),
+        }
+    }
+}
+
+/// Formats a [ValidatorId] for `Debug` output of the validators,
+/// omitting random identifiers so that the output is reproducible.
+pub(crate) struct DebugValidatorId<'a>(pub(crate) &'a ValidatorId);
+
+impl<'a> Debug for DebugValidatorId<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            ValidatorId::Named(name) => write!(f, "{:?}", name),
+            ValidatorId::Random(_) => write!(f, "<anonymous>"),
+        }
+    }
+}
+
+impl From<&'static str> for ValidatorId {
+    fn from(name: &'static str) -> Self {
+        Self::Named(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for ValidatorId {
+    fn from(name: String) -> Self {
+        Self::Named(Cow::Owned(name))
+    }
+}
 
 /// Function to perform validation on a form field.
 ///
@@ -42,27 +128,501 @@ type ValidatorFnTraitObject<Value, Key> = dyn Fn(&Value, &Key) -> Result<(), Val
 /// ```
 pub struct ValidatorFn<Value, Key> {
     closure: Rc<ValidatorFnTraitObject<Value, Key>>,
-    id: Uuid,
+    id: ValidatorId,
+    description: Option<Rc<str>>,
+    error_types: Vec<&'static str>,
+    /// Checked before calling the closure, to skip validating empty
+    /// values without adding another layer around the closure, see
+    /// [ValidatorFn::skip_empty()].
+    skip: Option<fn(&Value) -> bool>,
 }
 
 impl<Value, Key> ValidatorFn<Value, Key> {
-    /// Create a new `ValidatorFn`.
+    /// Create a new `ValidatorFn`, with a randomly generated
+    /// [ValidatorId].
     pub fn new<C>(closure: C) -> Self
     where
         C: Fn(&Value, &Key) -> Result<(), ValidationErrors<Key>> + 'static,
+    {
+        Self::new_into(
+            move |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+                if let Err(new_errors) = closure(value, key) {
+                    errors.extend(new_errors)
+                }
+            },
+        )
+    }
+
+    /// Create a new `ValidatorFn` with a stable caller-supplied `id`.
+    /// Two `ValidatorFn`s with the same `id` are considered equal.
+    pub fn with_id<I, C>(id: I, closure: C) -> Self
+    where
+        I: Into<ValidatorId>,
+        C: Fn(&Value, &Key) -> Result<(), ValidationErrors<Key>> + 'static,
+    {
+        Self {
+            id: id.into(),
+            ..Self::new(closure)
+        }
+    }
+
+    /// Create a new `ValidatorFn`, with a randomly generated
+    /// [ValidatorId], from a closure which pushes any errors it finds
+    /// directly into the collection that it is given, rather than
+    /// returning a new [ValidationErrors] for each failure. Together
+    /// with [Validation::validate_value_into()], this allows errors
+    /// to be collected into a buffer which is reused between
+    /// validations, without allocating a collection per validation
+    /// function.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, ValidationErrors, ValidatorFn};
+    ///
+    /// let v: ValidatorFn<i32, &str> =
+    ///     ValidatorFn::new_into(|value: &i32, key: &&str, errors: &mut ValidationErrors<&str>| {
+    ///         if *value < 0 {
+    ///             errors.push(ValidationError::new(*key, "NOT_LESS_THAN_0"));
+    ///         }
+    ///     });
+    ///
+    /// let mut errors = ValidationErrors::with_capacity(16);
+    /// v.validate_value_into(&-1, &"field1", &mut errors);
+    /// assert_eq!("NOT_LESS_THAN_0", errors.errors[0].type_id);
+    /// assert!(v.validate_value(&1, &"field1").is_ok());
+    /// ```
+    pub fn new_into<C>(closure: C) -> Self
+    where
+        C: Fn(&Value, &Key, &mut ValidationErrors<Key>) + 'static,
     {
         Self {
             closure: Rc::new(closure),
-            id: Uuid::new_v4(),
+            id: ValidatorId::random(),
+            description: None,
+            error_types: Vec::new(),
+            skip: None,
+        }
+    }
+
+    /// Create a new `ValidatorFn` with a stable caller-supplied `id`,
+    /// from a closure which pushes any errors it finds directly into
+    /// the collection that it is given, see
+    /// [ValidatorFn::new_into()].
+    pub fn with_id_into<I, C>(id: I, closure: C) -> Self
+    where
+        I: Into<ValidatorId>,
+        C: Fn(&Value, &Key, &mut ValidationErrors<Key>) + 'static,
+    {
+        Self {
+            id: id.into(),
+            ..Self::new_into(closure)
+        }
+    }
+
+    /// The identifier for this validation function.
+    pub fn id(&self) -> &ValidatorId {
+        &self.id
+    }
+
+    /// A factory method to set a human-readable description of the
+    /// rule that this validation function enforces (for example
+    /// "between 8 and 64 characters"), which can be displayed as hint
+    /// text for a field, see [Validator::describe()](crate::Validator::describe()).
+    pub fn described<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into().into());
+        self
+    }
+
+    /// A human-readable description of the rule that this
+    /// validation function enforces, if it has one.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// A factory method to declare the
+    /// [type_id](ValidationError::type_id)s of the errors that this
+    /// validation function can produce, so that tooling can
+    /// enumerate them, see [Validator::possible_error_types()](crate::Validator::possible_error_types()).
+    /// The built-in [validators](crate::validators) declare their
+    /// error types.
+    pub fn produces(mut self, type_ids: &[&'static str]) -> Self {
+        for type_id in type_ids {
+            if !self.error_types.contains(type_id) {
+                self.error_types.push(type_id);
+            }
+        }
+        self
+    }
+
+    /// The [type_id](ValidationError::type_id)s of the errors that
+    /// this validation function has declared it can produce, using
+    /// [produces()](ValidatorFn::produces()).
+    pub fn error_types(&self) -> &[&'static str] {
+        &self.error_types
+    }
+
+    /// Convert this into a validation function for an optional
+    /// value, which only performs validation when the value is
+    /// `Some`. The [ValidatorId] is preserved.
+    ///
+    /// Because the type of the value changes, this wraps the
+    /// validation function in a single adapter, which also performs
+    /// the check added by [ValidatorFn::skip_empty()] (if any).
+    pub fn optional(self) -> ValidatorFn<Option<Value>, Key>
+    where
+        Value: 'static,
+        Key: 'static,
+    {
+        let closure = self.closure;
+        let skip = self.skip;
+        ValidatorFn {
+            closure: Rc::new(
+                move |value: &Option<Value>, key: &Key, errors: &mut ValidationErrors<Key>| {
+                    if let Some(value) = value {
+                        if !skip.is_some_and(|skip| skip(value)) {
+                            closure(value, key, errors)
+                        }
+                    }
+                },
+            ),
+            id: self.id,
+            description: self.description,
+            error_types: self.error_types,
+            skip: None,
+        }
+    }
+
+    /// Convert this into a validation function which only performs
+    /// validation when the value is not empty (see [IsEmpty]), for
+    /// fields which are optional, but must be valid when they are
+    /// filled in. Unlike [ValidatorFn::optional()], this doesn't
+    /// change the type of the value, and treats `Some` empty values
+    /// (such as `Some("")`) as empty. The [ValidatorId] is preserved.
+    ///
+    /// The check is performed before the validation function is
+    /// called, rather than by wrapping it in another closure, so this
+    /// adds no overhead when the value isn't empty, and calling it
+    /// again has no effect.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{validators, Validation, ValidatorFn};
+    ///
+    /// let v: ValidatorFn<String, &str> = validators::string_length(3, 16).skip_empty();
+    ///
+    /// assert!(v.validate_value(&"".to_string(), &"nickname").is_ok());
+    /// assert!(v.validate_value(&"ab".to_string(), &"nickname").is_err());
+    ///
+    /// // the check is kept when converting to an optional value
+    /// let v: ValidatorFn<Option<String>, &str> = v.skip_empty().optional();
+    /// assert!(v.validate_value(&None, &"nickname").is_ok());
+    /// assert!(v.validate_value(&Some("".to_string()), &"nickname").is_ok());
+    /// assert!(v.validate_value(&Some("ab".to_string()), &"nickname").is_err());
+    /// ```
+    pub fn skip_empty(mut self) -> Self
+    where
+        Value: IsEmpty,
+    {
+        self.skip = Some(Value::is_empty_value);
+        self
+    }
+
+    /// Convert this into a validation function which catches any
+    /// panics that occur during validation, and converts them into a
+    /// `VALIDATOR_PANICKED` error. This prevents a buggy (perhaps
+    /// third-party) validation function from taking down the whole
+    /// form or server request. The [ValidatorId] is preserved.
+    ///
+    /// The error has a fixed message and no parameters, so that
+    /// internal details aren't shown to the user, or returned in the
+    /// response of an API. To log the panic message use
+    /// [ValidatorFn::catch_panics_with()].
+    ///
+    /// **Note:** panics can only be caught when the panic strategy is
+    /// `unwind`, when compiling with `panic = "abort"` (the default
+    /// for `wasm32-unknown-unknown`) the panic will still abort.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidatorFn};
+    ///
+    /// let v: ValidatorFn<Vec<i32>, String> = ValidatorFn::new(|value: &Vec<i32>, _key: &String| {
+    ///     if value[0] > 10 {
+    ///         unreachable!()
+    ///     }
+    ///     Ok(())
+    /// })
+    /// .catch_panics();
+    ///
+    /// let errors = v.validate_value(&Vec::new(), &"field1".to_string()).unwrap_err();
+    /// assert_eq!("VALIDATOR_PANICKED", errors.errors[0].type_id);
+    /// assert_eq!("Validation failed unexpectedly", errors.errors[0].to_string());
+    /// assert!(errors.errors[0].params().is_empty());
+    /// ```
+    pub fn catch_panics(self) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        self.catch_panics_with(|_: &Key, _: &str| {})
+    }
+
+    /// The same as [ValidatorFn::catch_panics()], but `on_panic` is
+    /// called with the key and the panic message (when the panic
+    /// payload is a string), for example to log it on the server.
+    /// The panic message is not included in the error.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidatorFn};
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let v: ValidatorFn<Vec<i32>, String> = ValidatorFn::new(|value: &Vec<i32>, _key: &String| {
+    ///     if value[0] > 10 {
+    ///         unreachable!()
+    ///     }
+    ///     Ok(())
+    /// })
+    /// .catch_panics_with({
+    ///     let log = log.clone();
+    ///     move |key: &String, reason: &str| {
+    ///         log.borrow_mut().push(format!("{} panicked: {}", key, reason))
+    ///     }
+    /// });
+    ///
+    /// let errors = v.validate_value(&Vec::new(), &"field1".to_string()).unwrap_err();
+    /// assert_eq!("VALIDATOR_PANICKED", errors.errors[0].type_id);
+    /// assert!(log.borrow()[0].contains("index out of bounds"));
+    /// ```
+    pub fn catch_panics_with<F>(self, on_panic: F) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+        F: Fn(&Key, &str) + 'static,
+    {
+        let closure = self.closure;
+        ValidatorFn {
+            closure: Rc::new(
+                move |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+                    let len_before = errors.len();
+                    let overflow_before = errors.overflow();
+                    if let Err(payload) =
+                        catch_unwind(AssertUnwindSafe(|| closure(value, key, errors)))
+                    {
+                        // discard any errors pushed (or counted as
+                        // overflow) before the panic
+                        errors.truncate(len_before, overflow_before);
+
+                        let reason = payload
+                            .downcast_ref::<&str>()
+                            .copied()
+                            .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+
+                        if let Some(reason) = reason {
+                            on_panic(key, reason);
+                        }
+
+                        errors.push(
+                            ValidationError::new(key.clone(), codes::VALIDATOR_PANICKED)
+                                .message("Validation failed unexpectedly"),
+                        );
+                    }
+                },
+            ),
+            id: self.id,
+            description: self.description,
+            error_types: self.error_types,
+            skip: self.skip,
+        }
+        .produces(&[codes::VALIDATOR_PANICKED])
+    }
+
+    /// Convert this into a validation function which masks any
+    /// occurrences of the validated value in the errors it produces
+    /// with `***`, for fields which contain sensitive information such
+    /// as passwords or tokens. The value is masked in the
+    /// [parameters](ValidationError::params()) (so messages rendered
+    /// from them, for example by a
+    /// [MessageCatalog](crate::MessageCatalog), are redacted too), the
+    /// [suggestion](ValidationError::suggestion()), and the message.
+    /// Only whole occurrences of the value are masked, so that a short
+    /// value such as `a` doesn't mask parts of other words. See
+    /// [ValidatorFn::redact_values_with()] to customize the
+    /// redaction. The [ValidatorId] is preserved.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{Validation, ValidationError, ValidatorFn};
+    ///
+    /// let v: ValidatorFn<String, String> = ValidatorFn::new(|value: &String, key: &String| {
+    ///     let value = value.clone();
+    ///     Err(ValidationError::new(key.clone(), "TOO_SHORT")
+    ///         .with_param("value", &value)
+    ///         .with_message(move |key| format!("{} ({}) is too short", key, value))
+    ///         .into())
+    /// })
+    /// .redact_values();
+    ///
+    /// let errors = v.validate_value(&"hunter2".to_string(), &"password".to_string()).unwrap_err();
+    /// assert_eq!("password (***) is too short", errors.to_string());
+    /// assert_eq!(Some("***"), errors.errors[0].param("value"));
+    ///
+    /// let errors = v.validate_value(&"a".to_string(), &"password".to_string()).unwrap_err();
+    /// assert_eq!("password (***) is too short", errors.to_string());
+    ///
+    /// let errors = v.validate_value(&String::new(), &"password".to_string()).unwrap_err();
+    /// assert_eq!("password () is too short", errors.to_string());
+    /// ```
+    pub fn redact_values(self) -> Self
+    where
+        Value: Display + 'static,
+        Key: Clone + PartialEq + 'static,
+    {
+        self.redact_values_with(|value: &Value, text: &str| {
+            replace_whole(text, &value.to_string(), "***")
+        })
+    }
+
+    /// Convert this into a validation function which redacts the
+    /// errors it produces using the `redact` function, which is
+    /// provided with the validated value and a piece of text from an
+    /// error (the value of a [parameter](ValidationError::params()),
+    /// the [suggestion](ValidationError::suggestion()) or the
+    /// message), and returns the redacted text. The messages are
+    /// rendered at the time of validation. The [ValidatorId] is
+    /// preserved.
+    pub fn redact_values_with<R>(self, redact: R) -> Self
+    where
+        Value: 'static,
+        Key: Clone + PartialEq + 'static,
+        R: Fn(&Value, &str) -> String + 'static,
+    {
+        let closure = self.closure;
+        ValidatorFn {
+            closure: Rc::new(
+                move |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+                    let len_before = errors.len();
+                    closure(value, key, errors);
+                    for error in &mut errors.errors[len_before..] {
+                        error.redact(|text| redact(value, text));
+                    }
+                },
+            ),
+            id: self.id,
+            description: self.description,
+            error_types: self.error_types,
+            skip: self.skip,
+        }
+    }
+
+    /// Convert this into a validation function for a different key
+    /// type. `key_fn` produces the key that this validation function
+    /// is invoked with, and any errors it produces are reported
+    /// using the outer key. The [ValidatorId] is preserved.
+    ///
+    /// Because the type of the key changes, this wraps the validation
+    /// function in a single adapter, which collects its errors in a
+    /// buffer that is reused between validations, and maps their keys
+    /// in one step. The check added by [ValidatorFn::skip_empty()] (if
+    /// any) is kept, rather than being wrapped.
+    pub fn map_key<OuterKey, F>(self, key_fn: F) -> ValidatorFn<Value, OuterKey>
+    where
+        Value: 'static,
+        Key: MaybeSendSync + 'static,
+        OuterKey: Clone + PartialEq + 'static,
+        F: Fn(&OuterKey) -> Key + 'static,
+    {
+        self.map_key_with_middleware(key_fn, ErrorsPipeline::new())
+    }
+
+    /// The same as [ValidatorFn::map_key()], but the `middleware`
+    /// (written for the original key type) is applied to the errors
+    /// before their keys are mapped. Used by
+    /// [Validator::map_key()](crate::Validator::map_key()) to keep its
+    /// middleware.
+    pub(crate) fn map_key_with_middleware<OuterKey, F>(
+        self,
+        key_fn: F,
+        middleware: ErrorsPipeline<Key>,
+    ) -> ValidatorFn<Value, OuterKey>
+    where
+        Value: 'static,
+        Key: MaybeSendSync + 'static,
+        OuterKey: Clone + PartialEq + 'static,
+        F: Fn(&OuterKey) -> Key + 'static,
+    {
+        let closure = self.closure;
+        let buffer: RefCell<ValidationErrors<Key>> = RefCell::new(ValidationErrors::default());
+        ValidatorFn {
+            closure: Rc::new(
+                move |value: &Value,
+                      outer_key: &OuterKey,
+                      errors: &mut ValidationErrors<OuterKey>| {
+                    let validate =
+                        |inner_errors: &mut ValidationErrors<Key>,
+                         errors: &mut ValidationErrors<OuterKey>| {
+                            inner_errors.clear();
+                            closure(value, &key_fn(outer_key), inner_errors);
+                            middleware.apply_from(inner_errors, 0);
+                            for error in inner_errors.drain() {
+                                errors.push(error.map_key(|_| outer_key.clone()));
+                            }
+                        };
+
+                    match buffer.try_borrow_mut() {
+                        Ok(mut inner_errors) => validate(&mut inner_errors, errors),
+                        // the buffer is already in use if this validation
+                        // function is (indirectly) validating itself
+                        Err(_) => validate(&mut ValidationErrors::default(), errors),
+                    }
+                },
+            ),
+            id: self.id,
+            description: self.description,
+            error_types: self.error_types,
+            skip: self.skip,
         }
     }
 }
 
+/// Replace the occurrences of `value` in `text` with `replacement`,
+/// skipping those which are part of a longer word.
+fn replace_whole(text: &str, value: &str, replacement: &str) -> String {
+    if value.is_empty() {
+        return text.to_string();
+    }
+
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let value_starts_word = value.starts_with(is_word);
+    let value_ends_word = value.ends_with(is_word);
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut last_end = 0;
+    for (start, _) in text.match_indices(value) {
+        let end = start + value.len();
+        let joined_before = value_starts_word && text[..start].ends_with(is_word);
+        let joined_after = value_ends_word && text[end..].starts_with(is_word);
+        if joined_before || joined_after {
+            continue;
+        }
+        redacted.push_str(&text[last_end..start]);
+        redacted.push_str(replacement);
+        last_end = end;
+    }
+    redacted.push_str(&text[last_end..]);
+    redacted
+}
+
 impl<Value, Key> Clone for ValidatorFn<Value, Key> {
     fn clone(&self) -> Self {
         Self {
             closure: Rc::clone(&self.closure),
-            id: self.id,
+            id: self.id.clone(),
+            description: self.description.clone(),
+            error_types: self.error_types.clone(),
+            skip: self.skip,
         }
     }
 }
@@ -84,11 +644,9 @@ where
 
 impl<Value, Key> Debug for ValidatorFn<Value, Key> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "ValidatorFn(closure: {:p}, id: {})",
-            self.closure, self.id
-        )
+        f.debug_tuple("ValidatorFn")
+            .field(&DebugValidatorId(&self.id))
+            .finish()
     }
 }
 
@@ -97,139 +655,109 @@ where
     Key: Clone + PartialEq,
 {
     fn validate_value(&self, value: &Value, key: &Key) -> Result<(), ValidationErrors<Key>> {
-        (self.closure)(value, key)
-    }
-}
+        let mut errors = ValidationErrors::default();
+        self.validate_value_into(value, key, &mut errors);
 
-/// An function to perform validation on a field asynchonously.
-///
-/// For the synchronous version, see [ValidatorFn].
-///
-/// ## Example
-///
-/// ```
-/// use form_validation::{AsyncValidatorFn, ValidationError};
-/// use futures::executor::block_on;
-///
-/// let v: AsyncValidatorFn<i32, String> =
-///     AsyncValidatorFn::new(|value: &i32, key: &String| {
-///         let key = key.clone();
-///         let value = *value;
-///         Box::pin(async move {
-///             // perform actions here that require async
-///             if value < 0 {
-///                 Err(ValidationError::new(key.clone(), "NOT_LESS_THAN_0")
-///                     .with_message(move |key| {
-///                         format!(
-///                             "The value of {} ({}) cannot be less than 0",
-///                             key, value
-///                         )
-///                     })
-///                     .into()) // convert into ValidationErrors
-///             } else {
-///                 Ok(())
-///             }
-///         })
-///     });
-///
-/// let key = "field1".to_string();
-/// assert!(block_on(v.validate_value(&20, &key)).is_ok());
-///
-/// let errors = block_on(v.validate_value(&-1, &key)).unwrap_err();
-/// assert_eq!(1, errors.len());
-/// let error = errors.errors.get(0).unwrap();
-/// assert_eq!(
-///     "The value of field1 (-1) cannot be less than 0",
-///     error.to_string()
-/// );
-/// assert_eq!("NOT_LESS_THAN_0", error.type_id);
-/// ```
-#[cfg(feature = "async")]
-#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-pub struct AsyncValidatorFn<Value, Key> {
-    future_producer: Rc<
-        dyn Fn(&Value, &Key) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>,
-    >,
-    id: Uuid,
-    key_type: PhantomData<Key>,
-    value_type: PhantomData<Value>,
-}
-
-#[cfg(feature = "async")]
-impl<Value, Key> AsyncValidatorFn<Value, Key>
-where
-    Key: Clone + PartialEq,
-    Value: Clone + PartialEq,
-{
-    /// Takes a closure that produces a `Future` that produces a
-    /// [ValidatorFn] closure.
-    pub fn new<C>(closure: C) -> Self
-    where
-        C: Fn(&Value, &Key) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>
-            + 'static,
-    {
-        Self {
-            future_producer: Rc::new(closure),
-            id: Uuid::new_v4(),
-            key_type: PhantomData,
-            value_type: PhantomData,
+        if !errors.is_empty() {
+            Err(errors)
+        } else {
+            Ok(())
         }
     }
 
-    /// Runs the future to produce the [ValidatorFn] closure, and then
-    /// performs the validation with that.
-    pub async fn validate_value(
-        &self,
-        value: &Value,
-        key: &Key,
-    ) -> Result<(), ValidationErrors<Key>> {
-        let future = (self.future_producer)(value, key);
-        future.await
+    fn validate_value_into(&self, value: &Value, key: &Key, errors: &mut ValidationErrors<Key>) {
+        if !self.skip.is_some_and(|skip| skip(value)) {
+            (self.closure)(value, key, errors)
+        }
     }
 }
 
-#[cfg(feature = "async")]
-impl<Value, Key> From<ValidatorFn<Value, Key>> for AsyncValidatorFn<Value, Key>
-where
-    Key: Clone + PartialEq + 'static,
-    Value: Clone + PartialEq + 'static,
-{
-    fn from(validator_fn: ValidatorFn<Value, Key>) -> Self {
-        Self::new(move |value, key| {
-            let value_clone = value.clone();
-            let key_clone = key.clone();
-            let new_fn = validator_fn.clone();
-            Box::pin(async move { new_fn.validate_value(&value_clone, &key_clone) })
-        })
-    }
-}
+#[cfg(test)]
+mod test {
+    use super::ValidatorFn;
+    use crate::{Validation, ValidationError, ValidationErrors};
 
-#[cfg(feature = "async")]
-impl<Value, Key> PartialEq for AsyncValidatorFn<Value, Key> {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+    /// The panic message must not be returned to the user in the
+    /// serialized errors.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn catch_panics_serialized_without_panic_message() {
+        let v: ValidatorFn<Vec<i32>, String> =
+            ValidatorFn::new(|value: &Vec<i32>, _key: &String| {
+                if value[0] > 10 {
+                    panic!("secret internal state")
+                }
+                Ok(())
+            })
+            .catch_panics_with(|_: &String, _: &str| {});
+
+        let errors = v
+            .validate_value(&vec![11], &"field1".to_string())
+            .unwrap_err();
+        let json = serde_json::to_string(&errors).unwrap();
+
+        assert!(json.contains("VALIDATOR_PANICKED"));
+        assert!(!json.contains("secret internal state"));
+
+        let errors = v
+            .validate_value(&Vec::new(), &"field1".to_string())
+            .unwrap_err();
+        let json = serde_json::to_string(&errors).unwrap();
+
+        assert!(!json.contains("index out of bounds"));
     }
-}
 
-#[cfg(feature = "async")]
-impl<Value, Key> Clone for AsyncValidatorFn<Value, Key> {
-    fn clone(&self) -> Self {
-        Self {
-            future_producer: Rc::clone(&self.future_producer),
-            id: self.id,
-            key_type: PhantomData,
-            value_type: PhantomData,
-        }
+    /// The errors which are discarded because of a panic are not
+    /// counted as overflow.
+    #[test]
+    fn catch_panics_restores_overflow() {
+        let v: ValidatorFn<i32, &'static str> =
+            ValidatorFn::new_into(|value: &i32, key: &&'static str, errors| {
+                errors.push(ValidationError::new(*key, "FIRST"));
+                errors.push(ValidationError::new(*key, "SECOND"));
+                if *value < 0 {
+                    panic!("negative value");
+                }
+            })
+            .catch_panics();
+
+        let mut errors: ValidationErrors<&'static str> = ValidationErrors::with_limit(2);
+        v.validate_value_into(&1, &"field1", &mut errors);
+        assert_eq!(0, errors.overflow());
+
+        errors.drain();
+        errors.push(ValidationError::new("field0", "REQUIRED"));
+        v.validate_value_into(&-1, &"field1", &mut errors);
+        assert_eq!(
+            vec!["REQUIRED", "VALIDATOR_PANICKED"],
+            errors
+                .errors
+                .iter()
+                .map(|error| error.type_id.as_ref())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, errors.overflow());
     }
-}
 
-#[cfg(feature = "async")]
-impl<Value, Key> Debug for AsyncValidatorFn<Value, Key> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "AsyncValidatorFn(future_producer: {:p}, id: {})",
-            self.future_producer, self.id
-        )
+    /// The buffer reused by a key-mapped validation function doesn't
+    /// carry errors over between validations.
+    #[test]
+    fn map_key_reuses_buffer_without_leaking_errors() {
+        let v: ValidatorFn<i32, (usize, &'static str)> =
+            ValidatorFn::new(|value: &i32, key: &&'static str| {
+                if *value < 0 {
+                    Err(ValidationError::new(*key, "NEGATIVE").into())
+                } else {
+                    Ok(())
+                }
+            })
+            .map_key(|key: &(usize, &'static str)| key.1);
+
+        assert_eq!(1, v.validate_value(&-1, &(0, "field1")).unwrap_err().len());
+        assert!(v.validate_value(&1, &(1, "field1")).is_ok());
+        assert_eq!(
+            (2, "field1"),
+            v.validate_value(&-1, &(2, "field1")).unwrap_err().errors[0].key
+        );
     }
 }
@@ -0,0 +1,175 @@
+use crate::{codes, ValidationError, ValidatorFn};
+
+/// The formats of the postal codes of each supported country, keyed
+/// by ISO 3166-1 alpha-2 country code. In a format `#` matches a
+/// digit, `@` matches a letter, `*` matches a letter or a digit, a
+/// space matches an optional space, and any other character matches
+/// itself.
+const POSTAL_CODE_FORMATS: &[(&str, &[&str])] = &[
+    ("AT", &["####"]),
+    ("AU", &["####"]),
+    ("BE", &["####"]),
+    ("BR", &["#####-###", "########"]),
+    ("CA", &["@#@ #@#"]),
+    ("CH", &["####"]),
+    ("CN", &["######"]),
+    ("CZ", &["### ##"]),
+    ("DE", &["#####"]),
+    ("DK", &["####"]),
+    ("ES", &["#####"]),
+    ("FI", &["#####"]),
+    ("FR", &["#####"]),
+    (
+        "GB",
+        &[
+            "@# #@@", "@## #@@", "@#@ #@@", "@@# #@@", "@@## #@@", "@@#@ #@@",
+        ],
+    ),
+    ("IE", &["@** ****"]),
+    ("IN", &["######", "### ###"]),
+    ("IT", &["#####"]),
+    ("JP", &["###-####", "#######"]),
+    ("MX", &["#####"]),
+    ("NL", &["#### @@"]),
+    ("NO", &["####"]),
+    ("NZ", &["####"]),
+    ("PL", &["##-###"]),
+    ("PT", &["####-###"]),
+    ("RU", &["######"]),
+    ("SE", &["### ##"]),
+    ("US", &["#####", "#####-####"]),
+];
+
+/// Whether `code` is a valid postal code for the `country` (an ISO
+/// 3166-1 alpha-2 country code such as `"US"` or `"gb"`, ignoring
+/// case). For countries without a built-in format, a generic format
+/// is used: between 2 and 10 letters, digits, spaces or hyphens,
+/// including at least one letter or digit. Letters are matched
+/// ignoring case, and leading or trailing whitespace is ignored.
+///
+/// This can be used to validate a postal code against a country
+/// selected in another field of a form.
+///
+/// ## Example
+/// ```
+/// use form_validation::validators::is_postal_code;
+///
+/// assert!(is_postal_code("US", "90210-1234"));
+/// assert!(is_postal_code("gb", "sw1a 1aa"));
+/// assert!(is_postal_code("GB", "SW1A1AA"));
+/// assert!(!is_postal_code("DE", "1234"));
+///
+/// // a country without a built-in format
+/// assert!(is_postal_code("KE", "00100"));
+/// assert!(!is_postal_code("KE", "--"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "address")))]
+pub fn is_postal_code(country: &str, code: &str) -> bool {
+    let code = code.trim();
+    match postal_code_formats(country) {
+        Some(formats) => formats.iter().any(|format| matches_format(format, code)),
+        None => {
+            (2..=10).contains(&code.chars().count())
+                && code.chars().any(|c| c.is_ascii_alphanumeric())
+                && code
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
+        }
+    }
+}
+
+/// The built-in postal code formats for the `country` (an ISO 3166-1
+/// alpha-2 country code, ignoring case), for example to display a
+/// placeholder. See [is_postal_code()] for how to read a format.
+/// Returns `None` if the country doesn't have a built-in format.
+///
+/// ## Example
+/// ```
+/// use form_validation::validators::postal_code_formats;
+///
+/// assert_eq!(Some(&["@#@ #@#"][..]), postal_code_formats("ca"));
+/// assert_eq!(None, postal_code_formats("KE"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "address")))]
+pub fn postal_code_formats(country: &str) -> Option<&'static [&'static str]> {
+    POSTAL_CODE_FORMATS
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .map(|(_, formats)| *formats)
+}
+
+/// Validates that a string is a postal code (for example a ZIP code
+/// or a postcode) for the specified `country`, see
+/// [is_postal_code()]. Produces a `POSTAL_CODE_INVALID` error if it
+/// isn't, with the country available (in upper case) as the
+/// `"country"` [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::postal_code("CA");
+///
+/// assert!(v.validate_value(&"K1A 0B1".to_string(), &"postal_code").is_ok());
+///
+/// let errors = v.validate_value(&"90210".to_string(), &"postal_code").unwrap_err();
+/// assert_eq!("POSTAL_CODE_INVALID", errors.errors[0].type_id);
+/// assert_eq!(Some("CA"), errors.errors[0].param("country"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "address")))]
+pub fn postal_code<Value, Key, C>(country: C) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    C: Into<String>,
+{
+    let country = country.into().to_ascii_uppercase();
+    let description = format!("must be a valid postal code for {}", country);
+
+    ValidatorFn::with_id(
+        format!("postal_code({:?})", country),
+        move |value: &Value, key: &Key| {
+            if is_postal_code(&country, value.as_ref()) {
+                Ok(())
+            } else {
+                let country = country.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::POSTAL_CODE_INVALID)
+                        .with_param("country", &country),
+                    "Invalid postal code",
+                    "Must be a valid postal code for {}",
+                    country
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::POSTAL_CODE_INVALID])
+}
+
+fn matches_format(format: &str, code: &str) -> bool {
+    let mut code = code.chars().peekable();
+    for expected in format.chars() {
+        if expected == ' ' {
+            if code.peek() == Some(&' ') {
+                code.next();
+            }
+            continue;
+        }
+
+        let matches = match code.next() {
+            Some(c) => match expected {
+                '#' => c.is_ascii_digit(),
+                '@' => c.is_ascii_alphabetic(),
+                '*' => c.is_ascii_alphanumeric(),
+                _ => c.eq_ignore_ascii_case(&expected),
+            },
+            None => false,
+        };
+        if !matches {
+            return false;
+        }
+    }
+    code.next().is_none()
+}
@@ -0,0 +1,56 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use std::fmt::Display;
+
+/// Validates that a value is one of the `allowed` values, for example
+/// for select or enum fields. Produces a `NOT_ONE_OF` error if it
+/// isn't, with each of the allowed values available as an
+/// `"allowed"` parameter (see
+/// [ValidationError::param_values()]), so that messages can list
+/// them.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::one_of(vec![
+///     "small".to_string(),
+///     "medium".to_string(),
+///     "large".to_string(),
+/// ]);
+///
+/// assert!(v.validate_value(&"medium".to_string(), &"size").is_ok());
+///
+/// let errors = v.validate_value(&"huge".to_string(), &"size").unwrap_err();
+/// assert_eq!("NOT_ONE_OF", errors.errors[0].type_id);
+/// assert_eq!(
+///     vec!["small", "medium", "large"],
+///     errors.errors[0].param_values("allowed").collect::<Vec<_>>()
+/// );
+/// ```
+pub fn one_of<Value, Key>(allowed: Vec<Value>) -> ValidatorFn<Value, Key>
+where
+    Value: PartialEq + Display + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    let allowed_strings: Vec<String> = allowed.iter().map(ToString::to_string).collect();
+    let description = format!("must be one of: {}", allowed_strings.join(", "));
+
+    ValidatorFn::with_id(
+        format!("one_of({})", allowed_strings.join(", ")),
+        move |value: &Value, key: &Key| {
+            if allowed.contains(value) {
+                Ok(())
+            } else {
+                let error = allowed_strings.iter().fold(
+                    ValidationError::new(key.clone(), codes::NOT_ONE_OF),
+                    |error, allowed| error.with_param("allowed", allowed),
+                );
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let allowed = allowed_strings.join(", ");
+                Err(builtin_message!(error, "Invalid choice", "Must be one of: {}", allowed).into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::NOT_ONE_OF])
+}
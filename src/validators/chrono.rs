@@ -0,0 +1,126 @@
+//! Validators for dates and times written in a custom format, using
+//! the [chrono](https://crates.io/crates/chrono) crate.
+
+use super::invalid_date_format;
+use crate::{codes, ValidatorFn};
+use ::chrono::format::{parse_and_remainder, Item, ParseError, Parsed, StrftimeItems};
+
+/// Validates that a string is a date written in the specified
+/// `format` (using chrono's `strftime`-like
+/// [syntax](https://docs.rs/chrono/latest/chrono/format/strftime/index.html),
+/// for example `"%d/%m/%Y"`). Produces an `INVALID_DATE` error if it
+/// isn't, with the format available as the `"format"`
+/// [parameter](crate::ValidationError::param()), and the character
+/// offset where parsing failed (when known) as the `"position"`
+/// parameter. Returns an error if the `format` is invalid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::chrono::date("%d/%m/%Y").unwrap();
+///
+/// assert!(v.validate_value(&"29/02/2020".to_string(), &"date").is_ok());
+///
+/// let errors = v.validate_value(&"29-02-2020".to_string(), &"date").unwrap_err();
+/// assert_eq!("INVALID_DATE", errors.errors[0].type_id);
+/// assert_eq!(Some("2"), errors.errors[0].param("position"));
+///
+/// // not a leap year
+/// let errors = v.validate_value(&"29/02/2021".to_string(), &"date").unwrap_err();
+/// assert_eq!(None, errors.errors[0].param("position"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub fn date<Value, Key>(format: &str) -> Result<ValidatorFn<Value, Key>, ParseError>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let items = StrftimeItems::new(format).parse_to_owned()?;
+    let format = format.to_string();
+    let description = format!("must be a date in the format {}", format);
+
+    Ok(ValidatorFn::with_id(
+        format!("chrono_date({:?})", format),
+        move |value: &Value, key: &Key| {
+            parse(value.as_ref(), &items, |parsed| {
+                parsed.to_naive_date().is_ok()
+            })
+            .map_err(|position| invalid_date_format(key, codes::INVALID_DATE, &format, position))
+        },
+    )
+    .described(description)
+    .produces(&[codes::INVALID_DATE]))
+}
+
+/// Validates that a string is a date and time written in the
+/// specified `format` (using chrono's `strftime`-like
+/// [syntax](https://docs.rs/chrono/latest/chrono/format/strftime/index.html),
+/// for example `"%Y-%m-%d %H:%M"`). Produces an `INVALID_DATETIME`
+/// error if it isn't, with the same parameters as [date()]. Returns
+/// an error if the `format` is invalid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::chrono::datetime("%Y-%m-%d %H:%M").unwrap();
+///
+/// assert!(v.validate_value(&"2020-02-29 13:30".to_string(), &"start").is_ok());
+///
+/// let errors = v.validate_value(&"2020-02-29 25:30".to_string(), &"start").unwrap_err();
+/// assert_eq!("INVALID_DATETIME", errors.errors[0].type_id);
+/// assert_eq!(Some("11"), errors.errors[0].param("position"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub fn datetime<Value, Key>(format: &str) -> Result<ValidatorFn<Value, Key>, ParseError>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let items = StrftimeItems::new(format).parse_to_owned()?;
+    let format = format.to_string();
+    let description = format!("must be a date and time in the format {}", format);
+
+    Ok(ValidatorFn::with_id(
+        format!("chrono_datetime({:?})", format),
+        move |value: &Value, key: &Key| {
+            parse(value.as_ref(), &items, |parsed| {
+                parsed.to_naive_datetime_with_offset(0).is_ok()
+            })
+            .map_err(|position| {
+                invalid_date_format(key, codes::INVALID_DATETIME, &format, position)
+            })
+        },
+    )
+    .described(description)
+    .produces(&[codes::INVALID_DATETIME]))
+}
+
+/// Parse `value` one format item at a time, so that the position of
+/// the first item which fails to parse is known. Returns `Err` with
+/// the character offset where parsing failed, or `None` if the parsed
+/// fields don't `resolve` to a valid value.
+fn parse<R>(value: &str, items: &[Item<'static>], resolve: R) -> Result<(), Option<usize>>
+where
+    R: FnOnce(&Parsed) -> bool,
+{
+    let mut parsed = Parsed::new();
+    let mut remainder = value;
+    let position = |remainder: &str| value[..value.len() - remainder.len()].chars().count();
+
+    for item in items {
+        remainder = parse_and_remainder(&mut parsed, remainder, std::iter::once(item))
+            .map_err(|_| Some(position(remainder)))?;
+    }
+
+    if !remainder.is_empty() {
+        return Err(Some(position(remainder)));
+    }
+
+    if resolve(&parsed) {
+        Ok(())
+    } else {
+        Err(None)
+    }
+}
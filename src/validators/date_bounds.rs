@@ -0,0 +1,221 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use std::fmt::Display;
+
+/// A date type which can provide the current date, for use with the
+/// [not_in_future()] and [not_in_past()] validators.
+///
+/// This is implemented for `chrono::NaiveDate` when the `"chrono"`
+/// feature is enabled, and for `time::Date` when the `"time"` feature
+/// is enabled, in both cases using the current date in UTC.
+pub trait Today {
+    /// The current date.
+    fn today() -> Self;
+}
+
+/// Validates that a date (or any other ordered value, such as a
+/// `chrono::NaiveDate`, a `time::Date` or an ISO 8601 date string) is
+/// strictly before the `limit`, for example the date of birth for a
+/// minimum age requirement. Produces a `DATE_NOT_BEFORE` error if it
+/// isn't, with the limit available as the `"before"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::date_before("2003-01-01".to_string());
+///
+/// assert!(v.validate_value(&"2002-12-31".to_string(), &"date_of_birth").is_ok());
+///
+/// let errors = v.validate_value(&"2003-01-01".to_string(), &"date_of_birth").unwrap_err();
+/// assert_eq!("DATE_NOT_BEFORE", errors.errors[0].type_id);
+/// assert_eq!(Some("2003-01-01"), errors.errors[0].param("before"));
+/// ```
+pub fn date_before<Value, Key>(limit: Value) -> ValidatorFn<Value, Key>
+where
+    Value: PartialOrd + Display + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    let limit_display = limit.to_string();
+    let description = format!("must be before {}", limit_display);
+
+    ValidatorFn::with_id(
+        format!("date_before({})", limit_display),
+        move |value: &Value, key: &Key| {
+            if *value < limit {
+                Ok(())
+            } else {
+                let limit = limit_display.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::DATE_NOT_BEFORE)
+                        .with_param("before", &limit),
+                    "Date too late",
+                    "Must be before {}",
+                    limit
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::DATE_NOT_BEFORE])
+}
+
+/// Validates that a date (or any other ordered value, such as a
+/// `chrono::NaiveDate`, a `time::Date` or an ISO 8601 date string) is
+/// strictly after the `limit`, for example the check-out date for a
+/// booking. Produces a `DATE_NOT_AFTER` error if it isn't, with the
+/// limit available as the `"after"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::date_after("2021-03-14".to_string());
+///
+/// assert!(v.validate_value(&"2021-03-15".to_string(), &"check_out").is_ok());
+///
+/// let errors = v.validate_value(&"2021-03-14".to_string(), &"check_out").unwrap_err();
+/// assert_eq!("DATE_NOT_AFTER", errors.errors[0].type_id);
+/// assert_eq!(Some("2021-03-14"), errors.errors[0].param("after"));
+/// ```
+pub fn date_after<Value, Key>(limit: Value) -> ValidatorFn<Value, Key>
+where
+    Value: PartialOrd + Display + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    let limit_display = limit.to_string();
+    let description = format!("must be after {}", limit_display);
+
+    ValidatorFn::with_id(
+        format!("date_after({})", limit_display),
+        move |value: &Value, key: &Key| {
+            if *value > limit {
+                Ok(())
+            } else {
+                let limit = limit_display.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::DATE_NOT_AFTER)
+                        .with_param("after", &limit),
+                    "Date too early",
+                    "Must be after {}",
+                    limit
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::DATE_NOT_AFTER])
+}
+
+/// Validates that a date is not after the current date (see
+/// [Today]), for example a date of birth. Produces a
+/// `DATE_IN_FUTURE` error if it is, with the current date available
+/// as the `"today"` [parameter](ValidationError::param()).
+///
+/// The current date is obtained each time the value is validated, so
+/// the same validator remains correct in a long running application.
+///
+/// ## Example
+/// ```
+/// # #[cfg(feature = "chrono")]
+/// # {
+/// use chrono::NaiveDate;
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<NaiveDate, &str> = validators::not_in_future();
+///
+/// let date_of_birth = NaiveDate::from_ymd_opt(1990, 5, 17).unwrap();
+/// assert!(v.validate_value(&date_of_birth, &"date_of_birth").is_ok());
+///
+/// let errors = v.validate_value(&NaiveDate::MAX, &"date_of_birth").unwrap_err();
+/// assert_eq!("DATE_IN_FUTURE", errors.errors[0].type_id);
+/// # }
+/// ```
+pub fn not_in_future<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: Today + PartialOrd + Display + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("not_in_future", |value: &Value, key: &Key| {
+        let today = Value::today();
+        if *value <= today {
+            Ok(())
+        } else {
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::DATE_IN_FUTURE)
+                    .with_param("today", &today),
+                "Date in the future",
+                "Must not be after {}",
+                today
+            )
+            .into())
+        }
+    })
+    .described("must not be in the future")
+    .produces(&[codes::DATE_IN_FUTURE])
+}
+
+/// Validates that a date is not before the current date (see
+/// [Today]), for example the expiry date of a card, or the date of a
+/// booking. Produces a `DATE_IN_PAST` error if it is, with the
+/// current date available as the `"today"`
+/// [parameter](ValidationError::param()).
+///
+/// The current date is obtained each time the value is validated, so
+/// the same validator remains correct in a long running application.
+///
+/// ## Example
+/// ```
+/// # #[cfg(feature = "time")]
+/// # {
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use time::{Date, Month};
+///
+/// let v: ValidatorFn<Date, &str> = validators::not_in_past();
+///
+/// assert!(v.validate_value(&Date::MAX, &"booking_date").is_ok());
+///
+/// let booking_date = Date::from_calendar_date(2000, Month::January, 1).unwrap();
+/// let errors = v.validate_value(&booking_date, &"booking_date").unwrap_err();
+/// assert_eq!("DATE_IN_PAST", errors.errors[0].type_id);
+/// # }
+/// ```
+pub fn not_in_past<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: Today + PartialOrd + Display + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("not_in_past", |value: &Value, key: &Key| {
+        let today = Value::today();
+        if *value >= today {
+            Ok(())
+        } else {
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::DATE_IN_PAST).with_param("today", &today),
+                "Date in the past",
+                "Must not be before {}",
+                today
+            )
+            .into())
+        }
+    })
+    .described("must not be in the past")
+    .produces(&[codes::DATE_IN_PAST])
+}
+
+#[cfg(feature = "chrono")]
+impl Today for ::chrono::NaiveDate {
+    fn today() -> Self {
+        ::chrono::Utc::now().date_naive()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Today for ::time::Date {
+    fn today() -> Self {
+        ::time::OffsetDateTime::now_utc().date()
+    }
+}
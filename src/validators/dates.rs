@@ -0,0 +1,73 @@
+use crate::{codes, ValidationError, ValidatorFn};
+
+/// Validates that a string is a calendar date in the ISO 8601
+/// `YYYY-MM-DD` format, which is what browsers submit for `<input
+/// type="date">`. Produces an `INVALID_DATE` error if it isn't.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::iso_date();
+///
+/// assert!(v.validate_value(&"2020-02-29".to_string(), &"date").is_ok());
+/// assert!(v.validate_value(&"2021-02-29".to_string(), &"date").is_err());
+///
+/// let errors = v.validate_value(&"29/02/2020".to_string(), &"date").unwrap_err();
+/// assert_eq!("INVALID_DATE", errors.errors[0].type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "dates")))]
+pub fn iso_date<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("iso_date", |value: &Value, key: &Key| {
+        if is_iso_date(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::INVALID_DATE),
+                "Invalid date",
+                "Must be a valid date (YYYY-MM-DD)"
+            )
+            .into())
+        }
+    })
+    .described("must be a valid date (YYYY-MM-DD)")
+    .produces(&[codes::INVALID_DATE])
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+
+    let number = |range: std::ops::Range<usize>| -> Option<u32> {
+        let part = &value[range];
+        if part.bytes().all(|b| b.is_ascii_digit()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+
+    match (number(0..4), number(5..7), number(8..10)) {
+        (Some(year), Some(month), Some(day)) => {
+            (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+        }
+        _ => false,
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
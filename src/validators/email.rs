@@ -0,0 +1,75 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+
+/// Validates that a string is a plausible email address, of the form
+/// `local@domain.tld`. Produces an `INVALID_EMAIL` error if it isn't.
+///
+/// This is deliberately not a full RFC 5322 parser; it rejects the
+/// mistakes users commonly make (missing `@`, missing domain,
+/// whitespace), the only reliable way to fully validate an email
+/// address is to send an email to it.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::email();
+///
+/// assert!(v.validate_value(&"user@example.com".to_string(), &"email").is_ok());
+/// assert!(v.validate_value(&"user@example".to_string(), &"email").is_err());
+/// assert!(v.validate_value(&"user example.com".to_string(), &"email").is_err());
+///
+/// let errors = v.validate_value(&"@example.com".to_string(), &"email").unwrap_err();
+/// assert_eq!("INVALID_EMAIL", errors.errors[0].type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "email")))]
+pub fn email<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id_into(
+        "email",
+        |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+            if !is_email(value.as_ref()) {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::INVALID_EMAIL),
+                    "Invalid email",
+                    "Must be a valid email address"
+                ))
+            }
+        },
+    )
+    .described("must be a valid email address")
+    .produces(&[codes::INVALID_EMAIL])
+}
+
+fn is_email(value: &str) -> bool {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    if local.is_empty()
+        || local.len() > 64
+        || local.starts_with('.')
+        || local.ends_with('.')
+        || local.contains("..")
+        || local
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '@')
+    {
+        return false;
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+
+    labels.len() >= 2
+        && domain.len() <= 253
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
@@ -0,0 +1,269 @@
+use super::{files::mime_matches, format_file_size};
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+
+/// Validates that a string is encoded using
+/// [base64](https://datatracker.ietf.org/doc/html/rfc4648#section-4)
+/// (with the standard alphabet and padding), and optionally that the
+/// decoded data is at most `max_decoded_size` bytes. Produces one of
+/// the following errors if it isn't valid:
+///
+/// + `INVALID_BASE64` - the value is not valid base64.
+/// + `DECODED_SIZE_TOO_LARGE` - the decoded data is larger than
+///   `max_decoded_size`. The decoded size and the maximum size are
+///   available as the `"size"` and `"max_size"`
+///   [parameters](ValidationError::param()), and the message contains
+///   the maximum size in a human-readable form (see
+///   [format_file_size()]).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::base64(Some(8));
+///
+/// assert!(v.validate_value(&"aGVsbG8=".to_string(), &"payload").is_ok());
+///
+/// let errors = v.validate_value(&"aGVsbG8".to_string(), &"payload").unwrap_err();
+/// assert_eq!("INVALID_BASE64", errors.errors[0].type_id);
+///
+/// let errors = v
+///     .validate_value(&"aGVsbG8sIHdvcmxkIQ==".to_string(), &"payload")
+///     .unwrap_err();
+/// assert_eq!("DECODED_SIZE_TOO_LARGE", errors.errors[0].type_id);
+/// assert_eq!(Some("13"), errors.errors[0].param("size"));
+/// ```
+pub fn base64<Value, Key>(max_decoded_size: Option<usize>) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("base64({:?})", max_decoded_size),
+        move |value: &Value, key: &Key| match base64_decoded_size(value.as_ref()) {
+            Some(size) => check_size(size, max_decoded_size, key),
+            None => Err(invalid_base64(key)),
+        },
+    )
+    .described("must be valid base64")
+    .produces(&[codes::INVALID_BASE64, codes::DECODED_SIZE_TOO_LARGE])
+}
+
+/// Validates that a string is a
+/// [data URI](https://datatracker.ietf.org/doc/html/rfc2397), for
+/// example `data:image/png;base64,iVBORw0KGgo=`, containing data with
+/// one of the `allowed_mime` types (which may use a wildcard subtype
+/// such as `"image/*"`, and allow any type if empty), and optionally
+/// that the decoded data is at most `max_decoded_size` bytes. Both
+/// base64 and percent-encoded data are supported, and a data URI
+/// without a type has the type `text/plain`. Produces one of the
+/// following errors if it isn't valid:
+///
+/// + `INVALID_DATA_URI` - the value is not a valid data URI.
+/// + `DATA_URI_TYPE_NOT_ALLOWED` - the type of the data is not one of
+///   the `allowed_mime` types, and is available as the `"mime"`
+///   [parameter](ValidationError::param()).
+/// + `INVALID_BASE64` - the data is not valid base64.
+/// + `DECODED_SIZE_TOO_LARGE` - the decoded data is too large, see
+///   [base64()].
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::data_uri(vec!["image/*"], Some(1024));
+///
+/// assert!(v
+///     .validate_value(&"data:image/png;base64,iVBORw0KGgo=".to_string(), &"avatar")
+///     .is_ok());
+///
+/// let errors = v
+///     .validate_value(&"data:text/plain,hello%20world".to_string(), &"avatar")
+///     .unwrap_err();
+/// assert_eq!("DATA_URI_TYPE_NOT_ALLOWED", errors.errors[0].type_id);
+/// assert_eq!(Some("text/plain"), errors.errors[0].param("mime"));
+///
+/// let errors = v.validate_value(&"image.png".to_string(), &"avatar").unwrap_err();
+/// assert_eq!("INVALID_DATA_URI", errors.errors[0].type_id);
+///
+/// let v: ValidatorFn<String, &str> = validators::data_uri(Vec::<String>::new(), Some(5));
+/// assert!(v.validate_value(&"data:,hello%21".to_string(), &"note").is_err());
+/// assert!(v.validate_value(&"data:,hell%21".to_string(), &"note").is_ok());
+/// ```
+pub fn data_uri<Value, Key, M>(
+    allowed_mime: Vec<M>,
+    max_decoded_size: Option<usize>,
+) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    M: Into<String>,
+{
+    let allowed_mime: Vec<String> = allowed_mime
+        .into_iter()
+        .map(|mime| mime.into().to_lowercase())
+        .collect();
+
+    ValidatorFn::with_id(
+        format!("data_uri({:?}, {:?})", allowed_mime, max_decoded_size),
+        move |value: &Value, key: &Key| {
+            let uri = match DataUri::parse(value.as_ref()) {
+                Some(uri) => uri,
+                None => {
+                    return Err(builtin_message!(
+                        ValidationError::new(key.clone(), codes::INVALID_DATA_URI),
+                        "Invalid data URI",
+                        "Must be a valid data URI"
+                    )
+                    .into())
+                }
+            };
+
+            let mime = uri.mime.to_lowercase();
+            if !allowed_mime.is_empty()
+                && !allowed_mime
+                    .iter()
+                    .any(|allowed| mime_matches(allowed, &mime))
+            {
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let allowed = allowed_mime.join(", ");
+                return Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::DATA_URI_TYPE_NOT_ALLOWED)
+                        .with_param("mime", &mime),
+                    "Type not allowed",
+                    "Must contain one of the following types of data: {}",
+                    allowed
+                )
+                .into());
+            }
+
+            let size = if uri.base64 {
+                match base64_decoded_size(uri.data) {
+                    Some(size) => size,
+                    None => return Err(invalid_base64(key)),
+                }
+            } else {
+                percent_decoded_size(uri.data)
+            };
+            check_size(size, max_decoded_size, key)
+        },
+    )
+    .described("must be a valid data URI")
+    .produces(&[
+        codes::INVALID_DATA_URI,
+        codes::DATA_URI_TYPE_NOT_ALLOWED,
+        codes::INVALID_BASE64,
+        codes::DECODED_SIZE_TOO_LARGE,
+    ])
+}
+
+/// The parts of a data URI which are validated.
+struct DataUri<'a> {
+    mime: &'a str,
+    base64: bool,
+    data: &'a str,
+}
+
+impl<'a> DataUri<'a> {
+    fn parse(value: &'a str) -> Option<Self> {
+        let scheme = value.get(..5)?;
+        if !scheme.eq_ignore_ascii_case("data:") {
+            return None;
+        }
+        let (header, data) = value[5..].split_once(',')?;
+
+        let mut parameters = header.split(';');
+        let mime = parameters.next().unwrap_or_default();
+        let mime = if mime.is_empty() {
+            "text/plain"
+        } else {
+            let (type_, subtype) = mime.split_once('/')?;
+            if type_.is_empty() || subtype.is_empty() {
+                return None;
+            }
+            mime
+        };
+
+        let mut base64 = false;
+        for parameter in parameters {
+            if parameter.eq_ignore_ascii_case("base64") {
+                base64 = true;
+            } else if !parameter.contains('=') {
+                return None;
+            }
+        }
+
+        Some(Self { mime, base64, data })
+    }
+}
+
+/// The size of the data encoded as base64 in `value`, or `None` if
+/// it isn't valid base64.
+fn base64_decoded_size(value: &str) -> Option<usize> {
+    let bytes = value.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let padding = bytes.iter().rev().take_while(|b| **b == b'=').count();
+    if padding > 2 {
+        return None;
+    }
+
+    let valid = bytes[..bytes.len() - padding]
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || *b == b'+' || *b == b'/');
+    if !valid {
+        return None;
+    }
+
+    Some(bytes.len() / 4 * 3 - padding)
+}
+
+/// The size of the percent-encoded data in `value`, where each `%XX`
+/// escape decodes to a single byte.
+fn percent_decoded_size(value: &str) -> usize {
+    let mut bytes = value.bytes();
+    let mut size = 0;
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            bytes.nth(1);
+        }
+        size += 1;
+    }
+    size
+}
+
+fn check_size<Key>(
+    size: usize,
+    max_decoded_size: Option<usize>,
+    key: &Key,
+) -> Result<(), ValidationErrors<Key>>
+where
+    Key: Clone + PartialEq,
+{
+    match max_decoded_size {
+        Some(max_size) if size > max_size => {
+            #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+            let max = format_file_size(max_size as u64);
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::DECODED_SIZE_TOO_LARGE)
+                    .with_param("size", size)
+                    .with_param("max_size", max_size),
+                "Too large",
+                "Must be at most {}",
+                max
+            )
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn invalid_base64<Key: Clone + PartialEq>(key: &Key) -> ValidationErrors<Key> {
+    builtin_message!(
+        ValidationError::new(key.clone(), codes::INVALID_BASE64),
+        "Invalid base64",
+        "Must be valid base64"
+    )
+    .into()
+}
@@ -0,0 +1,325 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+use std::{fmt::Debug, rc::Rc};
+
+/// Validates that two fields of a form have the same value, for
+/// example a password and its confirmation. The `field` and
+/// `confirmation` getters obtain the current values of the fields
+/// from the form. Produces a `FIELDS_DO_NOT_MATCH` error if they
+/// differ, which is keyed on the key that this rule is added with
+/// using [FormValidator::rule()](crate::FormValidator::rule()),
+/// typically the confirmation field. Closures can't be compared, so
+/// each instance of this validator has a random
+/// [ValidatorId](crate::ValidatorId).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, FormValidator};
+///
+/// struct Signup {
+///     password: String,
+///     confirm_password: String,
+/// }
+///
+/// let v: FormValidator<Signup, &'static str> = FormValidator::new().rule(
+///     "confirm_password",
+///     validators::equals_field(
+///         |form: &Signup| &form.password,
+///         |form: &Signup| &form.confirm_password,
+///     ),
+/// );
+///
+/// let mut signup = Signup {
+///     password: "hunter2".to_string(),
+///     confirm_password: "hunter3".to_string(),
+/// };
+///
+/// let errors = v.validate(&signup).unwrap_err();
+/// assert_eq!("confirm_password", errors.errors[0].key);
+/// assert_eq!("FIELDS_DO_NOT_MATCH", errors.errors[0].type_id);
+///
+/// signup.confirm_password = "hunter2".to_string();
+/// assert!(v.validate(&signup).is_ok());
+/// ```
+pub fn equals_field<Form, Value, Key, F, C>(field: F, confirmation: C) -> ValidatorFn<Form, Key>
+where
+    Value: PartialEq + ?Sized,
+    Key: Clone + PartialEq + 'static,
+    F: Fn(&Form) -> &Value + 'static,
+    C: Fn(&Form) -> &Value + 'static,
+{
+    ValidatorFn::new(move |form: &Form, key: &Key| {
+        if field(form) == confirmation(form) {
+            Ok(())
+        } else {
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::FIELDS_DO_NOT_MATCH),
+                "Does not match",
+                "The values do not match"
+            )
+            .into())
+        }
+    })
+    .described("must match")
+    .produces(&[codes::FIELDS_DO_NOT_MATCH])
+}
+
+/// The operator of a [FieldComparison].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl ComparisonOperator {
+    /// The symbol for this operator, for example `">="`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Eq => "==",
+            ComparisonOperator::Ne => "!=",
+            ComparisonOperator::Lt => "<",
+            ComparisonOperator::Le => "<=",
+            ComparisonOperator::Gt => ">",
+            ComparisonOperator::Ge => ">=",
+        }
+    }
+
+    /// The operator which holds when the operands are swapped, for
+    /// example `a > b` is equivalent to `b < a`.
+    pub fn flipped(&self) -> Self {
+        match self {
+            ComparisonOperator::Eq => ComparisonOperator::Eq,
+            ComparisonOperator::Ne => ComparisonOperator::Ne,
+            ComparisonOperator::Lt => ComparisonOperator::Gt,
+            ComparisonOperator::Le => ComparisonOperator::Ge,
+            ComparisonOperator::Gt => ComparisonOperator::Lt,
+            ComparisonOperator::Ge => ComparisonOperator::Le,
+        }
+    }
+
+    /// The [type_id](ValidationError::type_id) of the errors produced
+    /// when a [FieldComparison] using this operator doesn't hold.
+    pub fn type_id(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Eq => codes::FIELDS_DO_NOT_MATCH,
+            ComparisonOperator::Ne => codes::FIELDS_MATCH,
+            _ => codes::FIELD_COMPARISON_FAILED,
+        }
+    }
+
+    #[cfg(not(feature = "minimal-messages"))]
+    fn describe(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Eq => "the same as",
+            ComparisonOperator::Ne => "different from",
+            ComparisonOperator::Lt => "less than",
+            ComparisonOperator::Le => "at most",
+            ComparisonOperator::Gt => "greater than",
+            ComparisonOperator::Ge => "at least",
+        }
+    }
+}
+
+/// A comparison between two fields of a form, for example
+/// `end_date > start_date`, usually created using the [rule!](crate::rule!)
+/// macro, and added to a [FormValidator](crate::FormValidator) using
+/// [FormValidator::compare()](crate::FormValidator::compare()).
+///
+/// When the comparison doesn't hold, an error is produced for *both*
+/// fields, keyed by their names, so that each field can display the
+/// problem. The [type_id](ValidationError::type_id) depends on the
+/// [operator](ComparisonOperator::type_id()), and the errors have the
+/// following [parameters](ValidationError::param()):
+///
+/// + `"other"` - the name of the other field.
+/// + `"operator"` - the [symbol](ComparisonOperator::symbol()) of the
+///   operator, as seen from this field (for example the error for
+///   `start_date` in `end_date > start_date` has the operator `"<"`).
+pub struct FieldComparison<Form> {
+    left: &'static str,
+    operator: ComparisonOperator,
+    right: &'static str,
+    holds: Rc<dyn Fn(&Form) -> bool>,
+}
+
+impl<Form> Clone for FieldComparison<Form> {
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left,
+            operator: self.operator,
+            right: self.right,
+            holds: self.holds.clone(),
+        }
+    }
+}
+
+impl<Form> Debug for FieldComparison<Form> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FieldComparison({} {} {})",
+            self.left,
+            self.operator.symbol(),
+            self.right
+        )
+    }
+}
+
+impl<Form> FieldComparison<Form> {
+    /// Create a new `FieldComparison` between the fields named `left`
+    /// and `right`, where `holds` evaluates the comparison for a
+    /// form.
+    pub fn new<H>(
+        left: &'static str,
+        operator: ComparisonOperator,
+        right: &'static str,
+        holds: H,
+    ) -> Self
+    where
+        H: Fn(&Form) -> bool + 'static,
+    {
+        Self {
+            left,
+            operator,
+            right,
+            holds: Rc::new(holds),
+        }
+    }
+
+    /// The name of the field on the left of the operator.
+    pub fn left(&self) -> &'static str {
+        self.left
+    }
+
+    /// The operator of this comparison.
+    pub fn operator(&self) -> ComparisonOperator {
+        self.operator
+    }
+
+    /// The name of the field on the right of the operator.
+    pub fn right(&self) -> &'static str {
+        self.right
+    }
+
+    /// Whether this comparison holds for the `form`.
+    pub fn holds(&self, form: &Form) -> bool {
+        (self.holds)(form)
+    }
+
+    /// Validate the `form`, appending an error for each of the fields
+    /// to `errors` if this comparison doesn't hold.
+    pub fn validate_into<Key>(&self, form: &Form, errors: &mut ValidationErrors<Key>)
+    where
+        Key: From<&'static str> + Clone + PartialEq,
+    {
+        if !self.holds(form) {
+            errors.push(comparison_error(self.left, self.operator, self.right));
+            errors.push(comparison_error(
+                self.right,
+                self.operator.flipped(),
+                self.left,
+            ));
+        }
+    }
+}
+
+#[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+fn comparison_error<Key>(
+    field: &'static str,
+    operator: ComparisonOperator,
+    other: &'static str,
+) -> ValidationError<Key>
+where
+    Key: From<&'static str> + Clone + PartialEq,
+{
+    builtin_message!(
+        ValidationError::new(Key::from(field), operator.type_id())
+            .with_param("other", other)
+            .with_param("operator", operator.symbol()),
+        "Invalid value",
+        "Must be {} {}",
+        operator.describe(),
+        other
+    )
+}
+
+/// Create a [FieldComparison] between two fields of a form, using
+/// their names and a comparison operator (one of `==`, `!=`, `<`,
+/// `<=`, `>` or `>=`), for the most common constraints between
+/// fields. The type of the form needs to be specified before the
+/// comparison. Add the comparison to a
+/// [FormValidator](crate::FormValidator) using
+/// [FormValidator::compare()](crate::FormValidator::compare()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{rule, FormValidator};
+///
+/// struct Booking {
+///     password: String,
+///     password_confirm: String,
+///     start_date: u32,
+///     end_date: u32,
+/// }
+///
+/// let v: FormValidator<Booking, &'static str> = FormValidator::new()
+///     .compare(rule!(Booking: password == password_confirm))
+///     .compare(rule!(Booking: end_date > start_date));
+///
+/// let mut booking = Booking {
+///     password: "hunter2".to_string(),
+///     password_confirm: "hunter2".to_string(),
+///     start_date: 5,
+///     end_date: 3,
+/// };
+///
+/// let errors = v.validate(&booking).unwrap_err();
+/// assert_eq!(2, errors.len());
+/// assert_eq!("end_date", errors.errors[0].key);
+/// assert_eq!("FIELD_COMPARISON_FAILED", errors.errors[0].type_id);
+/// assert_eq!(Some(">"), errors.errors[0].param("operator"));
+/// assert_eq!("start_date", errors.errors[1].key);
+/// assert_eq!(Some("<"), errors.errors[1].param("operator"));
+/// assert_eq!(Some("end_date"), errors.errors[1].param("other"));
+///
+/// booking.end_date = 7;
+/// assert!(v.validate(&booking).is_ok());
+/// ```
+#[macro_export]
+macro_rules! rule {
+    ($form:ty: $left:ident == $right:ident) => {
+        $crate::rule!(@compare $form, $left, ==, Eq, $right)
+    };
+    ($form:ty: $left:ident != $right:ident) => {
+        $crate::rule!(@compare $form, $left, !=, Ne, $right)
+    };
+    ($form:ty: $left:ident <= $right:ident) => {
+        $crate::rule!(@compare $form, $left, <=, Le, $right)
+    };
+    ($form:ty: $left:ident >= $right:ident) => {
+        $crate::rule!(@compare $form, $left, >=, Ge, $right)
+    };
+    ($form:ty: $left:ident < $right:ident) => {
+        $crate::rule!(@compare $form, $left, <, Lt, $right)
+    };
+    ($form:ty: $left:ident > $right:ident) => {
+        $crate::rule!(@compare $form, $left, >, Gt, $right)
+    };
+    (@compare $form:ty, $left:ident, $op:tt, $operator:ident, $right:ident) => {
+        $crate::validators::FieldComparison::<$form>::new(
+            stringify!($left),
+            $crate::validators::ComparisonOperator::$operator,
+            stringify!($right),
+            |form: &$form| form.$left $op form.$right,
+        )
+    };
+}
@@ -0,0 +1,227 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+
+/// The metadata of a file selected for upload, for example obtained
+/// from a `File` in the browser, for use with the [file()] validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileInfo {
+    /// The name of the file, including its extension.
+    pub name: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The MIME type of the file, for example `"image/png"`.
+    pub mime: String,
+}
+
+impl FileInfo {
+    /// Create a new `FileInfo`.
+    pub fn new<N: Into<String>, M: Into<String>>(name: N, size: u64, mime: M) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            mime: mime.into(),
+        }
+    }
+
+    /// The extension of the file's name (without the `.`), if it has
+    /// one.
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::validators::FileInfo;
+    ///
+    /// assert_eq!(Some("gz"), FileInfo::new("backup.tar.gz", 0, "").extension());
+    /// assert_eq!(None, FileInfo::new("README", 0, "").extension());
+    /// assert_eq!(None, FileInfo::new(".profile", 0, "").extension());
+    /// ```
+    pub fn extension(&self) -> Option<&str> {
+        match self.name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() && !extension.is_empty() => Some(extension),
+            _ => None,
+        }
+    }
+}
+
+/// Format a size in bytes to be read by a person, using binary
+/// multiples (`1 KB` is `1024` bytes), with at most one decimal
+/// place.
+///
+/// ## Example
+/// ```
+/// use form_validation::validators::format_file_size;
+///
+/// assert_eq!("512 bytes", format_file_size(512));
+/// assert_eq!("1.5 KB", format_file_size(1536));
+/// assert_eq!("5 MB", format_file_size(5 * 1024 * 1024));
+/// ```
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{} bytes", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+
+    let rounded = (size * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{} {}", rounded as u64, unit)
+    } else {
+        format!("{:.1} {}", rounded, unit)
+    }
+}
+
+/// Validates the metadata of a file selected for upload. Produces one
+/// of the following errors if it isn't valid:
+///
+/// + `FILE_TOO_LARGE` - the file is larger than `max_size` bytes. The
+///   size of the file and the `max_size` are available as the `"size"`
+///   and `"max_size"` [parameters](ValidationError::param()), and the
+///   message contains the maximum size in a human-readable form (see
+///   [format_file_size()]).
+/// + `FILE_EXTENSION_NOT_ALLOWED` - the file's extension is not one of
+///   the `allowed_extensions`. The extension is available as the
+///   `"extension"` parameter (which is empty if the file doesn't have
+///   one).
+/// + `FILE_TYPE_NOT_ALLOWED` - the file's MIME type is not one of the
+///   `allowed_mime_types`, which is available as the `"mime"`
+///   parameter.
+///
+/// Extensions may be specified with or without the leading `.`, and
+/// both extensions and MIME types are compared ignoring case. A MIME
+/// type may use a wildcard subtype, for example `"image/*"`. If
+/// `allowed_extensions` or `allowed_mime_types` are empty, then any
+/// extension or MIME type is allowed.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use form_validation::validators::FileInfo;
+///
+/// let v: ValidatorFn<FileInfo, &str> = validators::file(
+///     5 * 1024 * 1024,
+///     vec!["png", ".jpg", "jpeg"],
+///     vec!["image/*"],
+/// );
+///
+/// assert!(v.validate_value(&FileInfo::new("avatar.PNG", 2048, "image/png"), &"avatar").is_ok());
+///
+/// let errors = v
+///     .validate_value(&FileInfo::new("avatar.png", 8 * 1024 * 1024, "image/png"), &"avatar")
+///     .unwrap_err();
+/// assert_eq!("FILE_TOO_LARGE", errors.errors[0].type_id);
+/// assert_eq!(Some("5242880"), errors.errors[0].param("max_size"));
+/// # #[cfg(not(feature = "minimal-messages"))]
+/// assert_eq!("Must be at most 5 MB", errors.errors[0].to_string());
+///
+/// let errors = v
+///     .validate_value(&FileInfo::new("cv.pdf", 2048, "application/pdf"), &"avatar")
+///     .unwrap_err();
+/// assert_eq!(2, errors.len());
+/// assert_eq!("FILE_EXTENSION_NOT_ALLOWED", errors.errors[0].type_id);
+/// assert_eq!(Some("pdf"), errors.errors[0].param("extension"));
+/// assert_eq!("FILE_TYPE_NOT_ALLOWED", errors.errors[1].type_id);
+/// ```
+pub fn file<Key, E, M>(
+    max_size: u64,
+    allowed_extensions: Vec<E>,
+    allowed_mime_types: Vec<M>,
+) -> ValidatorFn<FileInfo, Key>
+where
+    Key: Clone + PartialEq + 'static,
+    E: Into<String>,
+    M: Into<String>,
+{
+    let allowed_extensions: Vec<String> = allowed_extensions
+        .into_iter()
+        .map(|extension| extension.into().trim_start_matches('.').to_lowercase())
+        .collect();
+    let allowed_mime_types: Vec<String> = allowed_mime_types
+        .into_iter()
+        .map(|mime| mime.into().to_lowercase())
+        .collect();
+    let description = format!("must be a file of at most {}", format_file_size(max_size));
+
+    ValidatorFn::with_id(
+        format!(
+            "file({}, {:?}, {:?})",
+            max_size, allowed_extensions, allowed_mime_types
+        ),
+        move |file: &FileInfo, key: &Key| {
+            let mut errors = Vec::new();
+
+            if file.size > max_size {
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let max = format_file_size(max_size);
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::FILE_TOO_LARGE)
+                        .with_param("size", file.size)
+                        .with_param("max_size", max_size),
+                    "File too large",
+                    "Must be at most {}",
+                    max
+                ));
+            }
+
+            let extension = file.extension().unwrap_or_default().to_lowercase();
+            if !allowed_extensions.is_empty() && !allowed_extensions.contains(&extension) {
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let allowed = allowed_extensions.join(", ");
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::FILE_EXTENSION_NOT_ALLOWED)
+                        .with_param("extension", &extension),
+                    "File type not allowed",
+                    "Must be one of the following types of file: {}",
+                    allowed
+                ));
+            }
+
+            let mime = file.mime.to_lowercase();
+            if !allowed_mime_types.is_empty()
+                && !allowed_mime_types
+                    .iter()
+                    .any(|allowed| mime_matches(allowed, &mime))
+            {
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let allowed = allowed_mime_types.join(", ");
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::FILE_TYPE_NOT_ALLOWED)
+                        .with_param("mime", &file.mime),
+                    "File type not allowed",
+                    "Must be one of the following types of file: {}",
+                    allowed
+                ));
+            }
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationErrors::new(errors))
+            }
+        },
+    )
+    .described(description)
+    .produces(&[
+        codes::FILE_TOO_LARGE,
+        codes::FILE_EXTENSION_NOT_ALLOWED,
+        codes::FILE_TYPE_NOT_ALLOWED,
+    ])
+}
+
+/// Whether the `mime` type matches the `allowed` MIME type, which may
+/// have a wildcard subtype (for example `"image/*"`).
+pub(super) fn mime_matches(allowed: &str, mime: &str) -> bool {
+    match allowed.strip_suffix("/*") {
+        Some(allowed_type) => mime
+            .split_once('/')
+            .is_some_and(|(mime_type, _)| mime_type == allowed_type),
+        None => allowed == mime,
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{codes, ValidationError, ValidatorFn};
+
+/// Validates that a string is an International Bank Account Number
+/// (IBAN), checking its structure and its mod-97 check digits.
+/// Spaces used to group the characters are permitted. Produces an
+/// `INVALID_IBAN` error if it isn't valid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::iban();
+///
+/// assert!(v.validate_value(&"GB82 WEST 1234 5698 7654 32".to_string(), &"iban").is_ok());
+/// assert!(v.validate_value(&"GB82 WEST 1234 5698 7654 33".to_string(), &"iban").is_err());
+///
+/// let errors = v.validate_value(&"not an iban".to_string(), &"iban").unwrap_err();
+/// assert_eq!("INVALID_IBAN", errors.errors[0].type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "finance")))]
+pub fn iban<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("iban", |value: &Value, key: &Key| {
+        if is_iban(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::INVALID_IBAN),
+                "Invalid IBAN",
+                "Must be a valid IBAN"
+            )
+            .into())
+        }
+    })
+    .described("must be a valid IBAN")
+    .produces(&[codes::INVALID_IBAN])
+}
+
+fn is_iban(value: &str) -> bool {
+    let compact: Vec<char> = value.chars().filter(|c| *c != ' ').collect();
+
+    if compact.len() < 15 || compact.len() > 34 {
+        return false;
+    }
+
+    if !compact[0..2].iter().all(|c| c.is_ascii_uppercase())
+        || !compact[2..4].iter().all(|c| c.is_ascii_digit())
+        || !compact[4..]
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return false;
+    }
+
+    // Move the country code and check digits to the end, convert
+    // letters to numbers (A = 10, B = 11, ...), and calculate the
+    // remainder piecewise to avoid overflow.
+    let remainder = compact[4..]
+        .iter()
+        .chain(compact[0..4].iter())
+        .fold(0u32, |remainder, c| {
+            let digit = c.to_digit(36).expect("checked to be alphanumeric");
+            if digit >= 10 {
+                (remainder * 100 + digit) % 97
+            } else {
+                (remainder * 10 + digit) % 97
+            }
+        });
+
+    remainder == 1
+}
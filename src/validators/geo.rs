@@ -0,0 +1,268 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+
+/// Validates that a string is a latitude in decimal degrees, between
+/// `-90` and `90` inclusive, for example `-33.8688`. Optionally the
+/// number of digits after the decimal point can be limited to
+/// `max_decimal_places` (`5` decimal places is about one metre).
+/// Produces one of the following errors if it isn't valid:
+///
+/// + `INVALID_COORDINATE` - the value is not a plain decimal number
+///   (exponents, `NaN` and infinity are rejected).
+/// + `COORDINATE_OUT_OF_RANGE` - the value is outside the valid range,
+///   which is available as the `"min"` and `"max"`
+///   [parameters](ValidationError::param()).
+/// + `TOO_MANY_DECIMAL_PLACES` - the value has more than
+///   `max_decimal_places`, which is available as the
+///   `"max_decimal_places"` parameter.
+///
+/// All of these errors have the `"component"` parameter set to
+/// `"latitude"`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::latitude(Some(5));
+///
+/// assert!(v.validate_value(&"-33.8688".to_string(), &"latitude").is_ok());
+/// assert!(v.validate_value(&"90".to_string(), &"latitude").is_ok());
+///
+/// let errors = v.validate_value(&"91.5".to_string(), &"latitude").unwrap_err();
+/// assert_eq!("COORDINATE_OUT_OF_RANGE", errors.errors[0].type_id);
+/// assert_eq!(Some("90"), errors.errors[0].param("max"));
+///
+/// let errors = v.validate_value(&"-33.868812".to_string(), &"latitude").unwrap_err();
+/// assert_eq!("TOO_MANY_DECIMAL_PLACES", errors.errors[0].type_id);
+///
+/// let errors = v.validate_value(&"NaN".to_string(), &"latitude").unwrap_err();
+/// assert_eq!("INVALID_COORDINATE", errors.errors[0].type_id);
+/// ```
+pub fn latitude<Value, Key>(max_decimal_places: Option<usize>) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("latitude({:?})", max_decimal_places),
+        move |value: &Value, key: &Key| {
+            let mut errors = Vec::new();
+            validate_component(
+                value.as_ref(),
+                LATITUDE,
+                max_decimal_places,
+                key,
+                &mut errors,
+            );
+            into_result(errors)
+        },
+    )
+    .described("must be a latitude between -90 and 90")
+    .produces(COORDINATE_TYPE_IDS)
+}
+
+/// Validates that a string is a longitude in decimal degrees, between
+/// `-180` and `180` inclusive, for example `151.2093`. This produces
+/// the same errors as [latitude()], with the `"component"`
+/// [parameter](ValidationError::param()) set to `"longitude"`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::longitude(None);
+///
+/// assert!(v.validate_value(&"151.2093".to_string(), &"longitude").is_ok());
+/// assert!(v.validate_value(&"-180".to_string(), &"longitude").is_ok());
+///
+/// let errors = v.validate_value(&"180.1".to_string(), &"longitude").unwrap_err();
+/// assert_eq!("COORDINATE_OUT_OF_RANGE", errors.errors[0].type_id);
+/// assert_eq!(Some("longitude"), errors.errors[0].param("component"));
+/// ```
+pub fn longitude<Value, Key>(max_decimal_places: Option<usize>) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("longitude({:?})", max_decimal_places),
+        move |value: &Value, key: &Key| {
+            let mut errors = Vec::new();
+            validate_component(
+                value.as_ref(),
+                LONGITUDE,
+                max_decimal_places,
+                key,
+                &mut errors,
+            );
+            into_result(errors)
+        },
+    )
+    .described("must be a longitude between -180 and 180")
+    .produces(COORDINATE_TYPE_IDS)
+}
+
+/// Validates that a string is a pair of coordinates, written as a
+/// latitude and a longitude separated by a comma (for example
+/// `-33.8688, 151.2093`, the format used when copying a location from
+/// most maps). Produces an `INVALID_COORDINATE` error without a
+/// `"component"` [parameter](ValidationError::param()) if the value
+/// isn't a pair, otherwise each of the components is validated as
+/// described for [latitude()] and [longitude()], and the errors for
+/// both are reported.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::coordinates(Some(6));
+///
+/// assert!(v.validate_value(&"-33.8688, 151.2093".to_string(), &"location").is_ok());
+///
+/// let errors = v.validate_value(&"-33.8688".to_string(), &"location").unwrap_err();
+/// assert_eq!("INVALID_COORDINATE", errors.errors[0].type_id);
+/// assert_eq!(None, errors.errors[0].param("component"));
+///
+/// let errors = v.validate_value(&"95,200".to_string(), &"location").unwrap_err();
+/// assert_eq!(2, errors.len());
+/// assert_eq!(Some("latitude"), errors.errors[0].param("component"));
+/// assert_eq!(Some("longitude"), errors.errors[1].param("component"));
+/// ```
+pub fn coordinates<Value, Key>(max_decimal_places: Option<usize>) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("coordinates({:?})", max_decimal_places),
+        move |value: &Value, key: &Key| {
+            let mut components = value.as_ref().split(',');
+            match (components.next(), components.next(), components.next()) {
+                (Some(latitude), Some(longitude), None) => {
+                    let mut errors = Vec::new();
+                    validate_component(latitude, LATITUDE, max_decimal_places, key, &mut errors);
+                    validate_component(longitude, LONGITUDE, max_decimal_places, key, &mut errors);
+                    into_result(errors)
+                }
+                _ => Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::INVALID_COORDINATE),
+                    "Invalid coordinates",
+                    "Must be a latitude and a longitude separated by a comma"
+                )
+                .into()),
+            }
+        },
+    )
+    .described("must be a latitude and a longitude separated by a comma")
+    .produces(COORDINATE_TYPE_IDS)
+}
+
+const COORDINATE_TYPE_IDS: &[&str] = &[
+    codes::INVALID_COORDINATE,
+    codes::COORDINATE_OUT_OF_RANGE,
+    codes::TOO_MANY_DECIMAL_PLACES,
+];
+
+/// A component of a coordinate, and its valid range in degrees.
+struct Component {
+    name: &'static str,
+    limit: u8,
+}
+
+const LATITUDE: Component = Component {
+    name: "latitude",
+    limit: 90,
+};
+
+const LONGITUDE: Component = Component {
+    name: "longitude",
+    limit: 180,
+};
+
+fn validate_component<Key>(
+    text: &str,
+    component: Component,
+    max_decimal_places: Option<usize>,
+    key: &Key,
+    errors: &mut Vec<ValidationError<Key>>,
+) where
+    Key: Clone + PartialEq + 'static,
+{
+    let text = text.trim();
+    let name = component.name;
+    let limit = component.limit;
+
+    let decimal_places = match decimal_places(text) {
+        Some(decimal_places) => decimal_places,
+        None => {
+            errors.push(builtin_message!(
+                ValidationError::new(key.clone(), codes::INVALID_COORDINATE)
+                    .with_param("component", name),
+                "Invalid coordinate",
+                "Must be a {} in decimal degrees",
+                name
+            ));
+            return;
+        }
+    };
+
+    // plain decimal numbers always parse, overflowing to infinity.
+    let degrees: f64 = text.parse().unwrap_or(f64::INFINITY);
+    if degrees.abs() > f64::from(limit) {
+        errors.push(builtin_message!(
+            ValidationError::new(key.clone(), codes::COORDINATE_OUT_OF_RANGE)
+                .with_param("component", name)
+                .with_param("min", -i16::from(limit))
+                .with_param("max", limit),
+            "Out of range",
+            "Must be a {} between -{} and {}",
+            name,
+            limit,
+            limit
+        ));
+    }
+
+    if let Some(max) = max_decimal_places {
+        if decimal_places > max {
+            errors.push(builtin_message!(
+                ValidationError::new(key.clone(), codes::TOO_MANY_DECIMAL_PLACES)
+                    .with_param("component", name)
+                    .with_param("max_decimal_places", max),
+                "Too precise",
+                "Must have at most {} decimal places",
+                max
+            ));
+        }
+    }
+}
+
+fn into_result<Key: Clone + PartialEq>(
+    errors: Vec<ValidationError<Key>>,
+) -> Result<(), ValidationErrors<Key>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrors::new(errors))
+    }
+}
+
+/// The number of decimal places in `text`, if it is a plain decimal
+/// number: an optional sign, digits, and optionally a decimal point
+/// followed by more digits.
+fn decimal_places(text: &str) -> Option<usize> {
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let all_digits =
+        |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(integer) {
+        return None;
+    }
+    match fraction {
+        Some(fraction) if all_digits(fraction) => Some(fraction.len()),
+        Some(_) => None,
+        None => Some(0),
+    }
+}
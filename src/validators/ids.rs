@@ -0,0 +1,87 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+use uuid::Uuid;
+
+/// Validates that a string is a UUID, for example
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`. Produces an
+/// `INVALID_UUID` error if it isn't. See [uuid_version()] to only
+/// accept a specific version of UUID.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::uuid();
+///
+/// assert!(v.validate_value(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), &"id").is_ok());
+///
+/// let errors = v.validate_value(&"67e55044".to_string(), &"id").unwrap_err();
+/// assert_eq!("INVALID_UUID", errors.errors[0].type_id);
+/// ```
+pub fn uuid<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("uuid", |value: &Value, key: &Key| {
+        match Uuid::parse_str(value.as_ref()) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(invalid_uuid(key)),
+        }
+    })
+    .described("must be a valid UUID")
+    .produces(&[codes::INVALID_UUID])
+}
+
+/// Validates that a string is a UUID of the specified `version` (for
+/// example `4` for randomly generated UUIDs). Produces an
+/// `INVALID_UUID` error if it isn't a UUID, or a
+/// `UUID_VERSION_MISMATCH` error if it is a different version, with
+/// the expected version available as the `"version"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::uuid_version(4);
+///
+/// assert!(v.validate_value(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), &"id").is_ok());
+///
+/// let errors = v
+///     .validate_value(&"6ba7b810-9dad-11d1-80b4-00c04fd430c8".to_string(), &"id")
+///     .unwrap_err();
+/// assert_eq!("UUID_VERSION_MISMATCH", errors.errors[0].type_id);
+/// assert_eq!(Some("4"), errors.errors[0].param("version"));
+/// ```
+pub fn uuid_version<Value, Key>(version: usize) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("uuid_version({})", version),
+        move |value: &Value, key: &Key| match Uuid::parse_str(value.as_ref()) {
+            Ok(uuid) if uuid.get_version_num() == version => Ok(()),
+            Ok(_) => Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::UUID_VERSION_MISMATCH)
+                    .with_param("version", version),
+                "Invalid UUID version",
+                "Must be a version {} UUID",
+                version
+            )
+            .into()),
+            Err(_) => Err(invalid_uuid(key)),
+        },
+    )
+    .described(format!("must be a valid version {} UUID", version))
+    .produces(&[codes::INVALID_UUID, codes::UUID_VERSION_MISMATCH])
+}
+
+fn invalid_uuid<Key: Clone + PartialEq>(key: &Key) -> ValidationErrors<Key> {
+    builtin_message!(
+        ValidationError::new(key.clone(), codes::INVALID_UUID),
+        "Invalid UUID",
+        "Must be a valid UUID"
+    )
+    .into()
+}
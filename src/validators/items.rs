@@ -0,0 +1,284 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt::Display,
+    hash::Hash,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// A collection of items which can be counted, for use with the
+/// [items_count()] validator. Implemented for slices, arrays and the
+/// standard library's collections, and for references and smart
+/// pointers to them.
+pub trait ItemsCount {
+    /// The number of items in this collection.
+    fn items_count(&self) -> usize;
+}
+
+impl<T: ItemsCount + ?Sized> ItemsCount for &T {
+    fn items_count(&self) -> usize {
+        (**self).items_count()
+    }
+}
+
+impl<T: ItemsCount + ?Sized> ItemsCount for Box<T> {
+    fn items_count(&self) -> usize {
+        (**self).items_count()
+    }
+}
+
+impl<T: ItemsCount + ?Sized> ItemsCount for Rc<T> {
+    fn items_count(&self) -> usize {
+        (**self).items_count()
+    }
+}
+
+impl<T: ItemsCount + ?Sized> ItemsCount for Arc<T> {
+    fn items_count(&self) -> usize {
+        (**self).items_count()
+    }
+}
+
+impl<T> ItemsCount for [T] {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> ItemsCount for [T; N] {
+    fn items_count(&self) -> usize {
+        N
+    }
+}
+
+impl<T> ItemsCount for Vec<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for VecDeque<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for BTreeSet<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> ItemsCount for BTreeMap<K, V> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> ItemsCount for HashSet<T, S> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> ItemsCount for HashMap<K, V, S> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Validates that the number of items in a collection (for example
+/// the selected options of a multi-select, or the tags of a tag
+/// input) is between `min` and `max` inclusive. Produces an
+/// `ITEMS_COUNT_OUT_OF_RANGE` error if it isn't, with the bound that
+/// was violated available as either the `"min"` or the `"max"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use std::collections::BTreeSet;
+///
+/// let v: ValidatorFn<Vec<String>, &str> = validators::items_count(1, 5);
+///
+/// assert!(v.validate_value(&vec!["rust".to_string()], &"tags").is_ok());
+///
+/// let errors = v.validate_value(&Vec::new(), &"tags").unwrap_err();
+/// assert_eq!("ITEMS_COUNT_OUT_OF_RANGE", errors.errors[0].type_id);
+/// assert_eq!(Some("1"), errors.errors[0].param("min"));
+///
+/// let v: ValidatorFn<BTreeSet<u32>, &str> = validators::items_count(0, 2);
+/// let errors = v.validate_value(&(1..=3).collect(), &"choices").unwrap_err();
+/// assert_eq!(Some("2"), errors.errors[0].param("max"));
+/// ```
+pub fn items_count<Value, Key>(min: usize, max: usize) -> ValidatorFn<Value, Key>
+where
+    Value: ItemsCount,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("items_count({}, {})", min, max),
+        move |value: &Value, key: &Key| {
+            let count = value.items_count();
+            if count < min {
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::ITEMS_COUNT_OUT_OF_RANGE)
+                        .with_param("min", min),
+                    "Too few items",
+                    "Must have at least {} items",
+                    min
+                )
+                .into())
+            } else if count > max {
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::ITEMS_COUNT_OUT_OF_RANGE)
+                        .with_param("max", max),
+                    "Too many items",
+                    "Must have at most {} items",
+                    max
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        },
+    )
+    .described(format!("must have between {} and {} items", min, max))
+    .produces(&[codes::ITEMS_COUNT_OUT_OF_RANGE])
+}
+
+/// Validates that a list (for example a `Vec` or an array) doesn't
+/// contain duplicate items. Produces a `DUPLICATE_ITEM` error for each
+/// distinct item which occurs more than once, with the item available
+/// as the `"duplicate"` [parameter](ValidationError::param()), and
+/// the indices of all of its occurrences available as the repeated
+/// `"index"` parameter (see [ValidationError::param_values()]).
+///
+/// See [unique_items_by()] to compare the items using a key, for
+/// example to check that a list of contacts has unique email
+/// addresses.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<Vec<&str>, &str> = validators::unique_items();
+///
+/// assert!(v.validate_value(&vec!["rust", "wasm"], &"tags").is_ok());
+///
+/// let errors = v
+///     .validate_value(&vec!["rust", "wasm", "rust", "rust"], &"tags")
+///     .unwrap_err();
+/// assert_eq!(1, errors.len());
+/// assert_eq!("DUPLICATE_ITEM", errors.errors[0].type_id);
+/// assert_eq!(Some("rust"), errors.errors[0].param("duplicate"));
+/// assert_eq!(
+///     vec!["0", "2", "3"],
+///     errors.errors[0].param_values("index").collect::<Vec<_>>()
+/// );
+/// ```
+pub fn unique_items<Value, Item, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<[Item]>,
+    Item: Eq + Hash + Display,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("unique_items", |value: &Value, key: &Key| {
+        duplicates(value.as_ref().iter(), key)
+    })
+    .described("must not contain duplicates")
+    .produces(&[codes::DUPLICATE_ITEM])
+}
+
+/// Validates that the items of a list have unique keys, obtained from
+/// each item using the `item_key` closure. Produces the same errors
+/// as [unique_items()], with the duplicated key available as the
+/// `"duplicate"` [parameter](ValidationError::param()). Closures
+/// can't be compared, so each instance of this validator has a random
+/// [ValidatorId](crate::ValidatorId).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// struct Contact {
+///     name: String,
+///     email: String,
+/// }
+///
+/// let v: ValidatorFn<Vec<Contact>, &str> =
+///     validators::unique_items_by(|contact: &Contact| contact.email.to_lowercase());
+///
+/// let contacts = vec![
+///     Contact { name: "Alice".to_string(), email: "alice@example.com".to_string() },
+///     Contact { name: "Bob".to_string(), email: "bob@example.com".to_string() },
+///     Contact { name: "Alice 2".to_string(), email: "Alice@example.com".to_string() },
+/// ];
+///
+/// let errors = v.validate_value(&contacts, &"contacts").unwrap_err();
+/// assert_eq!(Some("alice@example.com"), errors.errors[0].param("duplicate"));
+/// assert_eq!(
+///     vec!["0", "2"],
+///     errors.errors[0].param_values("index").collect::<Vec<_>>()
+/// );
+/// ```
+pub fn unique_items_by<Value, Item, ItemKey, F, Key>(item_key: F) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<[Item]>,
+    ItemKey: Eq + Hash + Display,
+    F: Fn(&Item) -> ItemKey + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::new(move |value: &Value, key: &Key| {
+        duplicates(value.as_ref().iter().map(&item_key), key)
+    })
+    .described("must not contain duplicates")
+    .produces(&[codes::DUPLICATE_ITEM])
+}
+
+/// Produce a `DUPLICATE_ITEM` error for each of the `items` which
+/// occurs more than once, in the order of their first occurrence.
+fn duplicates<I, Key>(items: I, key: &Key) -> Result<(), ValidationErrors<Key>>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Display,
+    Key: Clone + PartialEq,
+{
+    let mut occurrences: HashMap<I::Item, Vec<usize>> = HashMap::new();
+    for (index, item) in items.enumerate() {
+        occurrences.entry(item).or_default().push(index);
+    }
+
+    let mut duplicates: Vec<(I::Item, Vec<usize>)> = occurrences
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .collect();
+
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+
+    duplicates.sort_by_key(|(_, indices)| indices[0]);
+
+    let errors = duplicates
+        .into_iter()
+        .map(|(item, indices)| {
+            #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+            let duplicate = item.to_string();
+            let error = indices.iter().fold(
+                ValidationError::new(key.clone(), codes::DUPLICATE_ITEM)
+                    .with_param("duplicate", &duplicate),
+                |error, index| error.with_param("index", index),
+            );
+            builtin_message!(
+                error,
+                "Duplicate item",
+                "Must not contain {} more than once",
+                duplicate
+            )
+        })
+        .collect();
+
+    Err(ValidationErrors::new(errors))
+}
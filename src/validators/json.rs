@@ -0,0 +1,54 @@
+use crate::{codes, ValidationError, ValidatorFn};
+
+/// Validates that a string is valid [JSON](https://www.json.org/),
+/// for example the content of a textarea for editing configuration.
+/// Produces an `INVALID_JSON` error if it isn't, with the location of
+/// the syntax error available as the `"line"` and `"column"`
+/// [parameters](ValidationError::param()) (both starting at `1`),
+/// and included in the message.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::json();
+///
+/// assert!(v.validate_value(&r#"{"retries": 3}"#.to_string(), &"config").is_ok());
+///
+/// let errors = v
+///     .validate_value(&"{\n  \"retries\": 3,\n}".to_string(), &"config")
+///     .unwrap_err();
+/// let error = &errors.errors[0];
+/// assert_eq!("INVALID_JSON", error.type_id);
+/// assert_eq!(Some("3"), error.param("line"));
+/// assert_eq!(Some("1"), error.param("column"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+pub fn json<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        "json",
+        |value: &Value, key: &Key| match serde_json::from_str::<serde_json::Value>(value.as_ref()) {
+            Ok(_) => Ok(()),
+            Err(error) => {
+                let line = error.line();
+                let column = error.column();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::INVALID_JSON)
+                        .with_param("line", line)
+                        .with_param("column", column),
+                    "Invalid JSON",
+                    "Must be valid JSON (syntax error at line {} column {})",
+                    line,
+                    column
+                )
+                .into())
+            }
+        },
+    )
+    .described("must be valid JSON")
+    .produces(&[codes::INVALID_JSON])
+}
@@ -0,0 +1,178 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::{BuildHasher, Hash},
+};
+
+/// Validates that a map contains all of the `required` keys.
+/// Produces a `MISSING_KEY` error for each key which is not present,
+/// with the missing key as the `"key"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use std::collections::HashMap;
+///
+/// let v: ValidatorFn<HashMap<String, String>, &str> =
+///     validators::required_keys(vec!["en".to_string()]);
+///
+/// let mut titles = HashMap::new();
+/// titles.insert("de".to_string(), "Hallo".to_string());
+///
+/// let errors = v.validate_value(&titles, &"title").unwrap_err();
+/// assert_eq!("MISSING_KEY", errors.errors[0].type_id);
+/// ```
+pub fn required_keys<MapKey, Value, S, Key>(
+    required: Vec<MapKey>,
+) -> ValidatorFn<HashMap<MapKey, Value, S>, Key>
+where
+    MapKey: Eq + Hash + Display + Clone + 'static,
+    S: BuildHasher,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("required_keys({})", display_keys(&required)),
+        move |map: &HashMap<MapKey, Value, S>, key: &Key| {
+            let errors: Vec<ValidationError<Key>> = required
+                .iter()
+                .filter(|required_key| !map.contains_key(required_key))
+                .map(|required_key| {
+                    #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                    let required_key = required_key.clone();
+                    builtin_message!(
+                        ValidationError::new(key.clone(), codes::MISSING_KEY)
+                            .with_param("key", &required_key),
+                        "Missing entry",
+                        "Missing an entry for {}",
+                        required_key
+                    )
+                })
+                .collect();
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationErrors::new(errors))
+            }
+        },
+    )
+    .described("must contain an entry for each of the required keys")
+    .produces(&[codes::MISSING_KEY])
+}
+
+/// Validates that a map contains only the `allowed` keys. Produces
+/// an `UNKNOWN_KEY` error for each key which is not allowed, with the
+/// unknown key as the `"key"` [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use std::collections::HashMap;
+///
+/// let v: ValidatorFn<HashMap<String, String>, &str> =
+///     validators::known_keys(vec!["en".to_string(), "de".to_string()]);
+///
+/// let mut titles = HashMap::new();
+/// titles.insert("en".to_string(), "Hello".to_string());
+/// titles.insert("xx".to_string(), "???".to_string());
+///
+/// let errors = v.validate_value(&titles, &"title").unwrap_err();
+/// assert_eq!("UNKNOWN_KEY", errors.errors[0].type_id);
+/// ```
+pub fn known_keys<MapKey, Value, S, Key>(
+    allowed: Vec<MapKey>,
+) -> ValidatorFn<HashMap<MapKey, Value, S>, Key>
+where
+    MapKey: Eq + Hash + Display + Clone + 'static,
+    S: BuildHasher,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("known_keys({})", display_keys(&allowed)),
+        move |map: &HashMap<MapKey, Value, S>, key: &Key| {
+            let errors: Vec<ValidationError<Key>> = map
+                .keys()
+                .filter(|map_key| !allowed.contains(map_key))
+                .map(|map_key| {
+                    #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                    let map_key = map_key.clone();
+                    builtin_message!(
+                        ValidationError::new(key.clone(), codes::UNKNOWN_KEY)
+                            .with_param("key", &map_key),
+                        "Unexpected entry",
+                        "Unexpected entry for {}",
+                        map_key
+                    )
+                })
+                .collect();
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationErrors::new(errors))
+            }
+        },
+    )
+    .described("must only contain entries for known keys")
+    .produces(&[codes::UNKNOWN_KEY])
+}
+
+/// Validates that a map-like payload (for example fields submitted to
+/// an API endpoint) contains only the `allowed` fields. Instead of
+/// silently ignoring extraneous data, an `UNKNOWN_FIELD` error is
+/// produced for each unexpected field, keyed by that field, so it can
+/// be reported alongside the other field errors. The keys can't be
+/// displayed, so each instance of this validator has a random
+/// [ValidatorId](crate::ValidatorId).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use std::collections::HashMap;
+///
+/// let v: ValidatorFn<HashMap<String, String>, String> =
+///     validators::strict_fields(vec!["name".to_string(), "email".to_string()]);
+///
+/// let mut payload = HashMap::new();
+/// payload.insert("name".to_string(), "Alice".to_string());
+/// payload.insert("admin".to_string(), "true".to_string());
+///
+/// let errors = v.validate_value(&payload, &"payload".to_string()).unwrap_err();
+/// assert_eq!(1, errors.len());
+/// assert_eq!("UNKNOWN_FIELD", errors.errors[0].type_id);
+/// assert_eq!("admin", errors.errors[0].key);
+/// ```
+pub fn strict_fields<Value, S, Key>(allowed: Vec<Key>) -> ValidatorFn<HashMap<Key, Value, S>, Key>
+where
+    S: BuildHasher,
+    Key: Eq + Hash + Clone + 'static,
+{
+    ValidatorFn::new(move |map: &HashMap<Key, Value, S>, _key: &Key| {
+        let errors: Vec<ValidationError<Key>> = map
+            .keys()
+            .filter(|field| !allowed.contains(field))
+            .map(|field| {
+                ValidationError::new(field.clone(), codes::UNKNOWN_FIELD).message("Unknown field")
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors::new(errors))
+        }
+    })
+    .described("must not contain any unknown fields")
+    .produces(&[codes::UNKNOWN_FIELD])
+}
+
+/// The keys which configure a validator, for use in its
+/// [ValidatorId](crate::ValidatorId).
+fn display_keys<MapKey: Display>(keys: &[MapKey]) -> String {
+    keys.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,213 @@
+//! Ready-made validation functions for commonly validated values.
+//!
+//! The following validators are always available:
+//!
+//! + Numbers: [range()], [multiple_of()], [number()].
+//! + Coordinates: [latitude()], [longitude()], [coordinates()].
+//! + Dates: [date_before()], [date_after()], [not_in_future()],
+//!   [not_in_past()].
+//! + Choices: [one_of()].
+//! + Identifiers: [uuid()], [uuid_version()].
+//! + Presence: [required()].
+//! + Fields: [equals_field()], and [FieldComparison] (see the
+//!   [rule!](crate::rule!) macro).
+//! + Strings: [string_length()], [alphanumeric()], [ascii_only()],
+//!   [identifier()], [starts_with()], [ends_with()], [contains()],
+//!   [does_not_contain()].
+//! + Files: [file()], for the metadata of a [FileInfo].
+//! + Encodings: [base64()], [data_uri()].
+//! + Collections: [items_count()], [unique_items()],
+//!   [unique_items_by()].
+//! + Maps: [required_keys()], [known_keys()], [strict_fields()].
+//! + Parsing: [ParseError], for converting the errors produced when
+//!   parsing raw input using
+//!   [ValidationError::from_parse_error()](crate::ValidationError::from_parse_error()).
+//!
+//! Groups of validators which are less commonly needed are gated
+//! behind cargo features, to keep the core of this library small for
+//! `wasm32-unknown-unknown` users who only need a handful of rules:
+//!
+//! + `"address"` - [postal_code()], [is_postal_code()],
+//!   [postal_code_formats()].
+//! + `"email"` - [email()].
+//! + `"finance"` - [iban()].
+//! + `"net"` - [ip_address()].
+//! + `"dates"` - [iso_date()].
+//! + `"full"` - enables all of the above.
+//! + `"regex"` - [pattern()], which depends on the
+//!   [regex](https://crates.io/crates/regex) crate, so it is not
+//!   included in `"full"`.
+//! + `"url"` - [url()], which depends on the
+//!   [url](https://crates.io/crates/url) crate, so it is not included
+//!   in `"full"`.
+//! + `"phone"` - [phone()], which depends on the
+//!   [phonenumber](https://crates.io/crates/phonenumber) crate, so it
+//!   is not included in `"full"`.
+//! + `"unicode"` - [string_length()] counts user-perceived characters
+//!   (grapheme clusters) using the
+//!   [unicode-segmentation](https://crates.io/crates/unicode-segmentation)
+//!   crate, so it is not included in `"full"`.
+//! + `"zxcvbn"` - [password_strength()], which depends on the
+//!   [zxcvbn](https://crates.io/crates/zxcvbn) crate, so it is not
+//!   included in `"full"`.
+//! + `"serde_json"` - [json()], which depends on the
+//!   [serde_json](https://crates.io/crates/serde_json) crate, so it
+//!   is not included in `"full"`.
+//! + `"chrono"` - [chrono::date()] and [chrono::datetime()], for
+//!   dates and times in a custom format, using the
+//!   [chrono](https://crates.io/crates/chrono) crate.
+//! + `"time"` - [time::date()] and [time::datetime()], the same
+//!   validators using the [time](https://crates.io/crates/time) crate
+//!   instead.
+//!
+//! When the `"minimal-messages"` feature is enabled, the built-in
+//! validators use short static messages instead of formatting
+//! detailed ones, for applications with strict WASM binary size
+//! budgets.
+
+/// Sets the message on a built-in validator's error, using the
+/// `$short` static message when the `"minimal-messages"` feature is
+/// enabled, otherwise formatting the full message. A full message
+/// without any arguments is stored as a static message, rather than
+/// being formatted each time it is displayed.
+#[cfg(not(any(feature = "minimal-messages", feature = "send")))]
+#[allow(unused_macros)]
+macro_rules! builtin_message {
+    ($error:expr, $short:literal, $full:literal) => {
+        $error.message($full)
+    };
+    ($error:expr, $short:literal, $($full:tt)+) => {
+        $error.with_message(move |_| format!($($full)+))
+    };
+}
+
+/// When the `"send"` feature is enabled the full message is formatted
+/// immediately, so that the values it refers to don't need to be
+/// `Send + Sync`.
+#[cfg(all(not(feature = "minimal-messages"), feature = "send"))]
+#[allow(unused_macros)]
+macro_rules! builtin_message {
+    ($error:expr, $short:literal, $full:literal) => {
+        $error.message($full)
+    };
+    ($error:expr, $short:literal, $($full:tt)+) => {
+        $error.message(format!($($full)+))
+    };
+}
+
+#[cfg(feature = "minimal-messages")]
+#[allow(unused_macros)]
+macro_rules! builtin_message {
+    ($error:expr, $short:literal, $($full:tt)+) => {
+        $error.message($short)
+    };
+}
+
+#[cfg(feature = "address")]
+mod address;
+mod choices;
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub mod chrono;
+mod date_bounds;
+#[cfg(feature = "dates")]
+mod dates;
+#[cfg(feature = "email")]
+mod email;
+mod encoding;
+mod fields;
+mod files;
+#[cfg(feature = "finance")]
+mod finance;
+mod geo;
+mod ids;
+mod items;
+#[cfg(feature = "serde_json")]
+mod json;
+mod maps;
+#[cfg(feature = "net")]
+mod net;
+mod numbers;
+mod parse;
+#[cfg(feature = "zxcvbn")]
+mod password;
+#[cfg(feature = "regex")]
+mod pattern;
+#[cfg(feature = "phone")]
+mod phone;
+mod presence;
+mod strings;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub mod time;
+#[cfg(feature = "url")]
+mod urls;
+
+#[cfg(feature = "address")]
+pub use address::*;
+pub use choices::*;
+pub use date_bounds::*;
+#[cfg(feature = "dates")]
+pub use dates::*;
+#[cfg(feature = "email")]
+pub use email::*;
+pub use encoding::*;
+pub use fields::*;
+pub use files::*;
+#[cfg(feature = "finance")]
+pub use finance::*;
+pub use geo::*;
+pub use ids::*;
+pub use items::*;
+#[cfg(feature = "serde_json")]
+pub use json::*;
+pub use maps::*;
+#[cfg(feature = "net")]
+pub use net::*;
+pub use numbers::*;
+pub use parse::*;
+#[cfg(feature = "zxcvbn")]
+pub use password::*;
+#[cfg(feature = "regex")]
+pub use pattern::*;
+#[cfg(feature = "phone")]
+pub use phone::*;
+pub use presence::*;
+pub use strings::*;
+#[cfg(feature = "url")]
+pub use urls::*;
+
+/// The error produced by the date and time validators when the value
+/// doesn't match the `format`, optionally at a known character
+/// `position`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+fn invalid_date_format<Key: Clone + PartialEq>(
+    key: &Key,
+    type_id: &'static str,
+    format: &str,
+    position: Option<usize>,
+) -> crate::ValidationErrors<Key> {
+    let mut error = crate::ValidationError::new(key.clone(), type_id).with_param("format", format);
+    if let Some(position) = position {
+        error = error.with_param("position", position);
+    }
+
+    let format = format.to_string();
+    match position {
+        Some(position) => builtin_message!(
+            error,
+            "Invalid date",
+            "Must be in the format {} (invalid at character {})",
+            format,
+            position + 1
+        ),
+        None => builtin_message!(
+            error,
+            "Invalid date",
+            "Must be a valid date in the format {}",
+            format
+        ),
+    }
+    .into()
+}
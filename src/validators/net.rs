@@ -0,0 +1,38 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use std::net::IpAddr;
+
+/// Validates that a string is an IPv4 or IPv6 address. Produces an
+/// `INVALID_IP_ADDRESS` error if it isn't.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::ip_address();
+///
+/// assert!(v.validate_value(&"192.168.0.1".to_string(), &"address").is_ok());
+/// assert!(v.validate_value(&"::1".to_string(), &"address").is_ok());
+///
+/// let errors = v.validate_value(&"192.168.0".to_string(), &"address").unwrap_err();
+/// assert_eq!("INVALID_IP_ADDRESS", errors.errors[0].type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+pub fn ip_address<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("ip_address", |value: &Value, key: &Key| {
+        match value.as_ref().parse::<IpAddr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::INVALID_IP_ADDRESS),
+                "Invalid IP address",
+                "Must be a valid IP address"
+            )
+            .into()),
+        }
+    })
+    .described("must be a valid IP address")
+    .produces(&[codes::INVALID_IP_ADDRESS])
+}
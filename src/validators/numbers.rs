@@ -0,0 +1,303 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+use std::{fmt::Display, ops::RangeInclusive};
+
+/// Validates that a value (for example an `i32`, `f64` or `u64`) is
+/// within the inclusive `range`. Produces an `OUT_OF_RANGE` error if
+/// it isn't, with the bound that was violated available as either
+/// the `"min"` or the `"max"` [parameter](ValidationError::param()).
+/// Values which can't be compared with the bounds (such as
+/// `f64::NAN`) are out of range, with both parameters.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<i32, &str> = validators::range(1..=10);
+///
+/// assert!(v.validate_value(&1, &"quantity").is_ok());
+/// assert!(v.validate_value(&10, &"quantity").is_ok());
+///
+/// let errors = v.validate_value(&11, &"quantity").unwrap_err();
+/// assert_eq!("OUT_OF_RANGE", errors.errors[0].type_id);
+/// assert_eq!(Some("10"), errors.errors[0].param("max"));
+/// assert_eq!(None, errors.errors[0].param("min"));
+///
+/// let v: ValidatorFn<f64, &str> = validators::range(0.0..=1.0);
+/// let errors = v.validate_value(&-0.5, &"ratio").unwrap_err();
+/// assert_eq!(Some("0"), errors.errors[0].param("min"));
+///
+/// let errors = v.validate_value(&f64::NAN, &"ratio").unwrap_err();
+/// assert_eq!("OUT_OF_RANGE", errors.errors[0].type_id);
+/// assert_eq!(Some("1"), errors.errors[0].param("max"));
+/// ```
+pub fn range<Value, Key>(range: RangeInclusive<Value>) -> ValidatorFn<Value, Key>
+where
+    Value: PartialOrd + Display + Copy + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    let (min, max) = range.into_inner();
+
+    ValidatorFn::with_id_into(
+        format!("range({}..={})", min, max),
+        move |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+            if *value < min {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::OUT_OF_RANGE).with_param("min", min),
+                    "Too small",
+                    "Must be at least {}",
+                    min
+                ))
+            } else if *value > max {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::OUT_OF_RANGE).with_param("max", max),
+                    "Too large",
+                    "Must be at most {}",
+                    max
+                ))
+            } else if !(min <= *value && *value <= max) {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::OUT_OF_RANGE)
+                        .with_param("min", min)
+                        .with_param("max", max),
+                    "Out of range",
+                    "Must be between {} and {}",
+                    min,
+                    max
+                ))
+            }
+        },
+    )
+    .described(format!("must be between {} and {}", min, max))
+    .produces(&[codes::OUT_OF_RANGE])
+}
+
+/// A number which can be checked for alignment to a step, for use
+/// with the [multiple_of()] validator. Implemented for the primitive
+/// integer and floating point types.
+pub trait Step {
+    /// Whether this value is a whole multiple of `step`. A `step` of
+    /// zero (or a negative or non-finite floating point `step`)
+    /// allows any value, like `step="any"` in HTML.
+    fn is_step_of(&self, step: &Self) -> bool;
+}
+
+macro_rules! impl_step_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Step for $ty {
+                fn is_step_of(&self, step: &Self) -> bool {
+                    // checked_rem only fails for a zero step, or for
+                    // MIN / -1, which is a multiple.
+                    self.checked_rem(*step).map_or(true, |remainder| remainder == 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_step_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_step_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Step for $ty {
+                fn is_step_of(&self, step: &Self) -> bool {
+                    if !(step.is_finite() && *step > 0.0) {
+                        return true;
+                    }
+                    if !self.is_finite() {
+                        return false;
+                    }
+                    // allow for the representation error of steps
+                    // like 0.05, which aren't exact in binary.
+                    let quotient = self / step;
+                    (quotient - quotient.round()).abs() <= 1e-9 * quotient.abs().max(1.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_step_float!(f32, f64);
+
+/// Validates that a number is a whole multiple of `step` (for example
+/// quantities sold in packs of `6`, or prices in increments of
+/// `0.05`), like the `step` attribute of an HTML `<input>`. Floating
+/// point values are compared with a small tolerance, so that
+/// representation errors don't cause spurious failures. Produces a
+/// `NOT_MULTIPLE_OF` error if it isn't, with the step available as
+/// the `"step"` [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<u32, &str> = validators::multiple_of(6);
+///
+/// assert!(v.validate_value(&12, &"quantity").is_ok());
+///
+/// let errors = v.validate_value(&10, &"quantity").unwrap_err();
+/// assert_eq!("NOT_MULTIPLE_OF", errors.errors[0].type_id);
+/// assert_eq!(Some("6"), errors.errors[0].param("step"));
+///
+/// let v: ValidatorFn<f64, &str> = validators::multiple_of(0.05);
+/// assert!(v.validate_value(&1.15, &"price").is_ok());
+/// assert!(v.validate_value(&1.17, &"price").is_err());
+/// ```
+pub fn multiple_of<Value, Key>(step: Value) -> ValidatorFn<Value, Key>
+where
+    Value: Step + Display + Copy + 'static,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("multiple_of({})", step),
+        move |value: &Value, key: &Key| {
+            if value.is_step_of(&step) {
+                Ok(())
+            } else {
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::NOT_MULTIPLE_OF)
+                        .with_param("step", step),
+                    "Invalid step",
+                    "Must be a multiple of {}",
+                    step
+                )
+                .into())
+            }
+        },
+    )
+    .described(format!("must be a multiple of {}", step))
+    .produces(&[codes::NOT_MULTIPLE_OF])
+}
+
+/// The conventions used to write numbers in a particular locale, for
+/// use with the [number()] validator.
+///
+/// ## Example
+/// ```
+/// use form_validation::validators::NumberLocale;
+///
+/// assert_eq!(Some("1234.56".to_string()), NumberLocale::DOT_DECIMAL.normalize("1,234.56"));
+/// assert_eq!(Some("-1234.56".to_string()), NumberLocale::COMMA_DECIMAL.normalize("-1.234,56"));
+/// assert_eq!(Some(1234.56), NumberLocale::COMMA_DECIMAL.parse("1234,56"));
+/// assert_eq!(None, NumberLocale::DOT_DECIMAL.parse("1.234,56"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// The character separating the integer and fractional parts of
+    /// a number.
+    pub decimal_separator: char,
+    /// The character (optionally) separating groups of three digits
+    /// in the integer part of a number.
+    pub grouping_separator: Option<char>,
+}
+
+impl NumberLocale {
+    /// Numbers written like `1,234.56` (for example in English).
+    pub const DOT_DECIMAL: NumberLocale = NumberLocale::new('.', Some(','));
+    /// Numbers written like `1.234,56` (for example in German).
+    pub const COMMA_DECIMAL: NumberLocale = NumberLocale::new(',', Some('.'));
+
+    /// Create a new `NumberLocale`.
+    pub const fn new(decimal_separator: char, grouping_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            grouping_separator,
+        }
+    }
+
+    /// Normalize a number written in this locale into the format
+    /// used by Rust (and most serialization formats), without any
+    /// digit grouping, and using `.` as the decimal separator.
+    /// Returns `None` if `value` is not a valid number in this
+    /// locale.
+    pub fn normalize(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", value.strip_prefix('+').unwrap_or(value)),
+        };
+
+        let (integer, fraction) = match unsigned.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let integer = match self.grouping_separator {
+            Some(separator) if integer.contains(separator) => {
+                let mut groups = integer.split(separator);
+                let first_valid = groups
+                    .next()
+                    .is_some_and(|group| (1..=3).contains(&group.len()));
+                if !first_valid || !groups.all(|group| group.len() == 3) {
+                    return None;
+                }
+                integer.replace(separator, "")
+            }
+            _ => integer.to_string(),
+        };
+
+        let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+
+        if !all_digits(&integer) {
+            return None;
+        }
+
+        match fraction {
+            Some(fraction) if fraction.is_empty() || !all_digits(fraction) => None,
+            Some(fraction) if integer.is_empty() => Some(format!("{}0.{}", sign, fraction)),
+            Some(fraction) => Some(format!("{}{}.{}", sign, integer, fraction)),
+            None if integer.is_empty() => None,
+            None => Some(format!("{}{}", sign, integer)),
+        }
+    }
+
+    /// Parse a number written in this locale. Returns `None` if
+    /// `value` is not a valid number in this locale.
+    pub fn parse(&self, value: &str) -> Option<f64> {
+        self.normalize(value)?.parse().ok()
+    }
+}
+
+/// Validates that a string is a number written according to the
+/// conventions of the specified `locale` (see [NumberLocale]).
+/// Produces an `INVALID_NUMBER` error if it isn't. Use
+/// [NumberLocale::normalize()] or [NumberLocale::parse()] to obtain
+/// the value to store.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use form_validation::validators::NumberLocale;
+///
+/// let v: ValidatorFn<String, &str> = validators::number(NumberLocale::COMMA_DECIMAL);
+///
+/// assert!(v.validate_value(&"1.234,56".to_string(), &"amount").is_ok());
+/// assert!(v.validate_value(&"1234".to_string(), &"amount").is_ok());
+///
+/// let errors = v.validate_value(&"1,234.56".to_string(), &"amount").unwrap_err();
+/// assert_eq!("INVALID_NUMBER", errors.errors[0].type_id);
+/// ```
+pub fn number<Value, Key>(locale: NumberLocale) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("number({:?})", locale),
+        move |value: &Value, key: &Key| {
+            if locale.normalize(value.as_ref()).is_some() {
+                Ok(())
+            } else {
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::INVALID_NUMBER),
+                    "Invalid number",
+                    "Must be a valid number"
+                )
+                .into())
+            }
+        },
+    )
+    .described("must be a valid number")
+    .produces(&[codes::INVALID_NUMBER])
+}
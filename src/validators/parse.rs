@@ -0,0 +1,136 @@
+use crate::{codes, ValidationError};
+use std::{
+    char::ParseCharError,
+    fmt::Display,
+    net::AddrParseError,
+    num::{ParseFloatError, ParseIntError},
+    str::ParseBoolError,
+};
+
+/// An error produced when parsing the raw input of a field into a
+/// value, which can be converted into a [ValidationError] using
+/// [ValidationError::from_parse_error()].
+///
+/// This is implemented for the parse errors of the standard library,
+/// and for those of [url](https://crates.io/crates/url) and
+/// [chrono](https://crates.io/crates/chrono) when the `"url"` or
+/// `"chrono"` features are enabled.
+pub trait ParseError: Display {
+    /// The [type_id](ValidationError::type_id) of the error, for
+    /// example [codes::INVALID_NUMBER].
+    fn code(&self) -> &'static str;
+    /// A description of the value which was expected, for use in the
+    /// message, for example `"a whole number"`.
+    fn expected(&self) -> &'static str;
+}
+
+impl ParseError for ParseIntError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_NUMBER
+    }
+
+    fn expected(&self) -> &'static str {
+        "a whole number"
+    }
+}
+
+impl ParseError for ParseFloatError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_NUMBER
+    }
+
+    fn expected(&self) -> &'static str {
+        "a number"
+    }
+}
+
+impl ParseError for ParseBoolError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_BOOLEAN
+    }
+
+    fn expected(&self) -> &'static str {
+        "true or false"
+    }
+}
+
+impl ParseError for ParseCharError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_CHARACTER
+    }
+
+    fn expected(&self) -> &'static str {
+        "a single character"
+    }
+}
+
+impl ParseError for AddrParseError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_IP_ADDRESS
+    }
+
+    fn expected(&self) -> &'static str {
+        "a valid address"
+    }
+}
+
+#[cfg(feature = "url")]
+impl ParseError for url::ParseError {
+    fn code(&self) -> &'static str {
+        codes::URL_INVALID
+    }
+
+    fn expected(&self) -> &'static str {
+        "a valid URL"
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ParseError for chrono::ParseError {
+    fn code(&self) -> &'static str {
+        codes::INVALID_DATE
+    }
+
+    fn expected(&self) -> &'static str {
+        "a valid date"
+    }
+}
+
+impl<Key> ValidationError<Key> {
+    /// Create a new `ValidationError` from an `error` produced when
+    /// parsing the `raw_input` of a field, with the
+    /// [type_id](ParseError::code()) and message for the kind of
+    /// error, and the `"value"` and `"reason"`
+    /// [parameters](ValidationError::param()).
+    ///
+    /// ## Example
+    /// ```
+    /// use form_validation::{codes, ValidationError};
+    ///
+    /// let raw_input = "12x";
+    /// let error = raw_input
+    ///     .parse::<u32>()
+    ///     .map_err(|error| ValidationError::from_parse_error("quantity", error, raw_input))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(codes::INVALID_NUMBER, error.type_id);
+    /// assert_eq!(Some("12x"), error.param("value"));
+    /// # #[cfg(not(feature = "minimal-messages"))]
+    /// assert_eq!("\"12x\" is not a whole number", error.to_string());
+    /// ```
+    #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+    pub fn from_parse_error<E: ParseError>(key: Key, error: E, raw_input: &str) -> Self {
+        let expected = error.expected();
+        let raw_input = raw_input.to_string();
+        let error = ValidationError::new(key, error.code())
+            .with_param("value", &raw_input)
+            .with_param("reason", &error);
+        builtin_message!(
+            error,
+            "Invalid value",
+            "\"{}\" is not {}",
+            raw_input,
+            expected
+        )
+    }
+}
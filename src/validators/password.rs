@@ -0,0 +1,92 @@
+use crate::{codes, ValidationError, ValidatorFn};
+
+/// Validates that a password is strong enough, by estimating how
+/// easily it could be guessed using the
+/// [zxcvbn](https://crates.io/crates/zxcvbn) algorithm, which
+/// produces a score between `0` (too guessable) and `4` (very
+/// unguessable). Produces a `WEAK_PASSWORD` error if the score is
+/// less than `min_score`, with the following
+/// [parameters](ValidationError::param()), so that a user interface
+/// can display hints:
+///
+/// + `"score"` - the score of the password.
+/// + `"min_score"` - the `min_score`.
+/// + `"crack_time"` - the estimated time to crack the password
+///   offline if it is stored using a slow hash function, for example
+///   `"3 hours"`.
+/// + `"warning"` - an explanation of what's wrong with the password,
+///   if there is one.
+/// + `"suggestion"` - suggestions to help choose a better password,
+///   repeated for each suggestion (see
+///   [ValidationError::param_values()]).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::password_strength(3);
+///
+/// assert!(v.validate_value(&"correct horse battery staple".to_string(), &"password").is_ok());
+///
+/// let errors = v.validate_value(&"password1".to_string(), &"password").unwrap_err();
+/// let error = &errors.errors[0];
+/// assert_eq!("WEAK_PASSWORD", error.type_id);
+/// assert_eq!(Some("0"), error.param("score"));
+/// assert_eq!(Some("3"), error.param("min_score"));
+/// assert!(error.param("warning").is_some());
+/// assert!(error.param_values("suggestion").count() > 0);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "zxcvbn")))]
+#[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+pub fn password_strength<Value, Key>(min_score: u8) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("password_strength({})", min_score),
+        move |value: &Value, key: &Key| {
+            let entropy = zxcvbn::zxcvbn(value.as_ref(), &[]);
+            let score = u8::from(entropy.score());
+
+            if score >= min_score {
+                return Ok(());
+            }
+
+            let mut error = ValidationError::new(key.clone(), codes::WEAK_PASSWORD)
+                .with_param("score", score)
+                .with_param("min_score", min_score)
+                .with_param(
+                    "crack_time",
+                    entropy.crack_times().offline_slow_hashing_1e4_per_second(),
+                );
+
+            let warning = entropy
+                .feedback()
+                .and_then(|feedback| feedback.warning())
+                .map(|warning| warning.to_string());
+
+            if let Some(warning) = &warning {
+                error = error.with_param("warning", warning);
+            }
+            if let Some(feedback) = entropy.feedback() {
+                for suggestion in feedback.suggestions() {
+                    error = error.with_param("suggestion", suggestion);
+                }
+            }
+
+            Err(match warning {
+                Some(warning) => builtin_message!(
+                    error,
+                    "Password too weak",
+                    "Password is too weak: {}",
+                    warning
+                ),
+                None => builtin_message!(error, "Password too weak", "Password is too weak"),
+            }
+            .into())
+        },
+    )
+    .described("must be a strong password")
+    .produces(&[codes::WEAK_PASSWORD])
+}
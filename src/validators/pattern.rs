@@ -0,0 +1,56 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use regex::Regex;
+
+/// Validates that a string matches the regular expression `regex`.
+/// Produces a `PATTERN_MISMATCH` error if it doesn't.
+///
+/// The regular expression is compiled once when the validator is
+/// created, and an error is returned if it is invalid. The pattern is
+/// exposed so that user interfaces can surface it, as the
+/// validator's [description](ValidatorFn::description()), and as the
+/// `"pattern"` [parameter](ValidationError::param()) on errors.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::pattern(r"^[A-Z]{3}$").unwrap();
+///
+/// assert!(v.validate_value(&"ABC".to_string(), &"code").is_ok());
+///
+/// let errors = v.validate_value(&"abc".to_string(), &"code").unwrap_err();
+/// assert_eq!("PATTERN_MISMATCH", errors.errors[0].type_id);
+/// assert_eq!(Some("^[A-Z]{3}$"), errors.errors[0].param("pattern"));
+///
+/// assert!(validators::pattern::<String, &str>("(").is_err());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub fn pattern<Value, Key>(regex: &str) -> Result<ValidatorFn<Value, Key>, regex::Error>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let regex = Regex::new(regex)?;
+    let description = format!("must match the pattern {}", regex.as_str());
+
+    Ok(ValidatorFn::with_id(
+        format!("pattern({:?})", regex.as_str()),
+        move |value: &Value, key: &Key| {
+            if regex.is_match(value.as_ref()) {
+                Ok(())
+            } else {
+                let pattern = regex.as_str().to_string();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::PATTERN_MISMATCH)
+                        .with_param("pattern", &pattern),
+                    "Invalid format",
+                    "Must match the pattern {}",
+                    pattern
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::PATTERN_MISMATCH]))
+}
@@ -0,0 +1,113 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use phonenumber::Mode;
+
+/// A region (CLDR country id) used to interpret phone numbers which
+/// are written in a national format, rather than starting with `+`
+/// and a country code. Re-exported from the
+/// [phonenumber](https://crates.io/crates/phonenumber) crate.
+#[cfg_attr(docsrs, doc(cfg(feature = "phone")))]
+pub use phonenumber::country::Id as PhoneRegion;
+
+/// Validates that a string is a valid phone number. Numbers written
+/// in a national format are interpreted as belonging to the
+/// `default_region`. Produces one of the following errors if it
+/// isn't valid:
+///
+/// + `PHONE_INVALID` - the value could not be parsed as a phone
+///   number.
+/// + `PHONE_INVALID_FOR_REGION` - the value was parsed, but is not a
+///   valid number for its region, which is available as the
+///   `"region"` [parameter](ValidationError::param()).
+///
+/// Use [normalize_phone()] to obtain the number to store in E.164
+/// format.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use form_validation::validators::PhoneRegion;
+///
+/// let v: ValidatorFn<String, &str> = validators::phone(Some(PhoneRegion::AU));
+///
+/// assert!(v.validate_value(&"0412 345 678".to_string(), &"phone").is_ok());
+/// assert!(v.validate_value(&"+1 650 253 0000".to_string(), &"phone").is_ok());
+///
+/// let errors = v.validate_value(&"not a number".to_string(), &"phone").unwrap_err();
+/// assert_eq!("PHONE_INVALID", errors.errors[0].type_id);
+///
+/// let errors = v.validate_value(&"0412".to_string(), &"phone").unwrap_err();
+/// assert_eq!("PHONE_INVALID_FOR_REGION", errors.errors[0].type_id);
+/// assert_eq!(Some("AU"), errors.errors[0].param("region"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "phone")))]
+pub fn phone<Value, Key>(default_region: Option<PhoneRegion>) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id(
+        format!("phone({:?})", default_region),
+        move |value: &Value, key: &Key| {
+            let number = match phonenumber::parse(default_region, value.as_ref()) {
+                Ok(number) => number,
+                Err(_) => {
+                    return Err(builtin_message!(
+                        ValidationError::new(key.clone(), codes::PHONE_INVALID),
+                        "Invalid phone number",
+                        "Must be a valid phone number"
+                    )
+                    .into())
+                }
+            };
+
+            if phonenumber::is_valid(&number) {
+                Ok(())
+            } else {
+                let region = number
+                    .country()
+                    .id()
+                    .or(default_region)
+                    .map(|region| region.as_ref().to_string())
+                    .unwrap_or_default();
+                #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                let code = number.country().code();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::PHONE_INVALID_FOR_REGION)
+                        .with_param("region", region),
+                    "Invalid phone number",
+                    "Must be a valid phone number for country code +{}",
+                    code
+                )
+                .into())
+            }
+        },
+    )
+    .described("must be a valid phone number")
+    .produces(&[codes::PHONE_INVALID, codes::PHONE_INVALID_FOR_REGION])
+}
+
+/// Normalize a phone number into the E.164 format (for example
+/// `+61412345678`), interpreting numbers written in a national format
+/// as belonging to the `default_region`. Returns `None` if `value` is
+/// not a valid phone number.
+///
+/// ## Example
+/// ```
+/// use form_validation::validators::{normalize_phone, PhoneRegion};
+///
+/// assert_eq!(
+///     Some("+61412345678".to_string()),
+///     normalize_phone(Some(PhoneRegion::AU), "0412 345 678")
+/// );
+/// assert_eq!(None, normalize_phone(Some(PhoneRegion::AU), "0412"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "phone")))]
+pub fn normalize_phone(default_region: Option<PhoneRegion>, value: &str) -> Option<String> {
+    let number = phonenumber::parse(default_region, value).ok()?;
+
+    if phonenumber::is_valid(&number) {
+        Some(number.format().mode(Mode::E164).to_string())
+    } else {
+        None
+    }
+}
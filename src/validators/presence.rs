@@ -0,0 +1,41 @@
+use crate::{codes, IsEmpty, ValidationError, ValidationErrors, ValidatorFn};
+
+/// Validates that a value has been filled in, according to its
+/// [IsEmpty] implementation (for example a string which isn't only
+/// whitespace, a non-empty `Vec`, or `Some` non-empty value).
+/// Produces a `REQUIRED` error if it is empty.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::required();
+///
+/// assert!(v.validate_value(&"alice".to_string(), &"username").is_ok());
+///
+/// let errors = v.validate_value(&"   ".to_string(), &"username").unwrap_err();
+/// assert_eq!("REQUIRED", errors.errors[0].type_id);
+///
+/// let v: ValidatorFn<Option<Vec<String>>, &str> = validators::required();
+/// assert!(v.validate_value(&Some(Vec::new()), &"tags").is_err());
+/// ```
+pub fn required<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: IsEmpty,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id_into(
+        "required",
+        |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+            if value.is_empty_value() {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::REQUIRED),
+                    "Required",
+                    "This field is required"
+                ))
+            }
+        },
+    )
+    .described("required")
+    .produces(&[codes::REQUIRED])
+}
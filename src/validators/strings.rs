@@ -0,0 +1,420 @@
+use crate::{codes, ValidationError, ValidationErrors, ValidatorFn};
+
+/// Validates that the length of a string, counted in characters
+/// (rather than bytes), is between `min` and `max` inclusive. Produces
+/// a `LENGTH_OUT_OF_RANGE` error if it isn't, with the `"min"` and
+/// `"max"` [parameters](ValidationError::param()).
+///
+/// When the `"unicode"` feature is enabled, the length is counted in
+/// user-perceived characters (extended grapheme clusters), so that
+/// emoji and letters with combining marks count as a single
+/// character.
+///
+/// This works for both `String` and `&str` values, or any other value
+/// which implements `AsRef<str>`.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::string_length(2, 5);
+///
+/// assert!(v.validate_value(&"ab".to_string(), &"name").is_ok());
+/// assert!(v.validate_value(&"héllo".to_string(), &"name").is_ok());
+/// assert!(v.validate_value(&"a".to_string(), &"name").is_err());
+///
+/// let errors = v.validate_value(&"abcdef".to_string(), &"name").unwrap_err();
+/// assert_eq!("LENGTH_OUT_OF_RANGE", errors.errors[0].type_id);
+///
+/// let v: ValidatorFn<&str, &str> = validators::string_length(2, 5);
+/// assert!(v.validate_value(&"abc", &"name").is_ok());
+///
+/// // a family emoji, made up of 5 chars joined by zero width joiners
+/// # #[cfg(feature = "unicode")]
+/// assert!(v.validate_value(&"👩\u{200d}👩\u{200d}👧!", &"name").is_ok());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`, because no string could
+/// pass the validation.
+pub fn string_length<Value, Key>(min: usize, max: usize) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    assert!(
+        min <= max,
+        "string_length: min ({}) must not be greater than max ({})",
+        min,
+        max
+    );
+
+    ValidatorFn::with_id_into(
+        format!("string_length({}, {})", min, max),
+        move |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+            let length = length_in(value.as_ref());
+            if length < min || length > max {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::LENGTH_OUT_OF_RANGE)
+                        .with_param("min", min)
+                        .with_param("max", max),
+                    "Invalid length",
+                    "Must be between {} and {} characters long",
+                    min,
+                    max
+                ))
+            }
+        },
+    )
+    .described(format!(
+        "must be between {} and {} characters long",
+        min, max
+    ))
+    .produces(&[codes::LENGTH_OUT_OF_RANGE])
+}
+
+/// Validates that a string contains only ASCII letters and digits
+/// (`a-z`, `A-Z` and `0-9`). Produces a `NOT_ALPHANUMERIC` error if
+/// it doesn't, with the character offset of the first invalid
+/// character available as the `"position"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::alphanumeric();
+///
+/// assert!(v.validate_value(&"Abc123".to_string(), &"code").is_ok());
+///
+/// let errors = v.validate_value(&"abc-123".to_string(), &"code").unwrap_err();
+/// assert_eq!("NOT_ALPHANUMERIC", errors.errors[0].type_id);
+/// assert_eq!(Some("3"), errors.errors[0].param("position"));
+/// ```
+pub fn alphanumeric<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("alphanumeric", |value: &Value, key: &Key| {
+        match value
+            .as_ref()
+            .chars()
+            .position(|c| !c.is_ascii_alphanumeric())
+        {
+            None => Ok(()),
+            Some(position) => Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::NOT_ALPHANUMERIC)
+                    .with_param("position", position),
+                "Invalid characters",
+                "Must only contain letters and digits"
+            )
+            .into()),
+        }
+    })
+    .described("must only contain letters and digits")
+    .produces(&[codes::NOT_ALPHANUMERIC])
+}
+
+/// Validates that a string contains only ASCII characters. Produces a
+/// `NOT_ASCII` error if it doesn't, with the character offset of the
+/// first invalid character available as the `"position"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::ascii_only();
+///
+/// assert!(v.validate_value(&"hello, world!".to_string(), &"code").is_ok());
+///
+/// let errors = v.validate_value(&"héllo".to_string(), &"code").unwrap_err();
+/// assert_eq!("NOT_ASCII", errors.errors[0].type_id);
+/// assert_eq!(Some("1"), errors.errors[0].param("position"));
+/// ```
+pub fn ascii_only<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id("ascii_only", |value: &Value, key: &Key| {
+        match value.as_ref().chars().position(|c| !c.is_ascii()) {
+            None => Ok(()),
+            Some(position) => Err(builtin_message!(
+                ValidationError::new(key.clone(), codes::NOT_ASCII)
+                    .with_param("position", position),
+                "Invalid characters",
+                "Must only contain ASCII characters"
+            )
+            .into()),
+        }
+    })
+    .described("must only contain ASCII characters")
+    .produces(&[codes::NOT_ASCII])
+}
+
+/// Validates that a string is an identifier: one or more ASCII
+/// letters, digits and underscores (`_`), which doesn't start with a
+/// digit, for example a username or a code. Produces an
+/// `INVALID_IDENTIFIER` error if it isn't.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::identifier();
+///
+/// assert!(v.validate_value(&"user_42".to_string(), &"username").is_ok());
+/// assert!(v.validate_value(&"_private".to_string(), &"username").is_ok());
+/// assert!(v.validate_value(&"".to_string(), &"username").is_err());
+/// assert!(v.validate_value(&"user-42".to_string(), &"username").is_err());
+///
+/// let errors = v.validate_value(&"42user".to_string(), &"username").unwrap_err();
+/// assert_eq!("INVALID_IDENTIFIER", errors.errors[0].type_id);
+/// ```
+pub fn identifier<Value, Key>() -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    ValidatorFn::with_id_into(
+        "identifier",
+        |value: &Value, key: &Key, errors: &mut ValidationErrors<Key>| {
+            let value = value.as_ref();
+            let valid = value
+                .chars()
+                .next()
+                .is_some_and(|first| !first.is_ascii_digit())
+                && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+            if !valid {
+                errors.push(builtin_message!(
+                    ValidationError::new(key.clone(), codes::INVALID_IDENTIFIER),
+                    "Invalid identifier",
+                    "Must only contain letters, digits and underscores, and not start with a digit"
+                ))
+            }
+        },
+    )
+    .described("must only contain letters, digits and underscores, and not start with a digit")
+    .produces(&[codes::INVALID_IDENTIFIER])
+}
+
+/// Validates that a string starts with the specified `prefix`.
+/// Produces a `MISSING_PREFIX` error if it doesn't, with the prefix
+/// available as the `"prefix"` [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::starts_with("https://");
+///
+/// assert!(v.validate_value(&"https://example.com".to_string(), &"website").is_ok());
+///
+/// let errors = v.validate_value(&"http://example.com".to_string(), &"website").unwrap_err();
+/// assert_eq!("MISSING_PREFIX", errors.errors[0].type_id);
+/// assert_eq!(Some("https://"), errors.errors[0].param("prefix"));
+/// ```
+pub fn starts_with<Value, Key, S>(prefix: S) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    S: Into<String>,
+{
+    let prefix = prefix.into();
+    let description = format!("must start with \"{}\"", prefix);
+    ValidatorFn::with_id(
+        format!("starts_with({:?})", prefix),
+        move |value: &Value, key: &Key| {
+            if value.as_ref().starts_with(prefix.as_str()) {
+                Ok(())
+            } else {
+                let prefix = prefix.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::MISSING_PREFIX)
+                        .with_param("prefix", &prefix),
+                    "Invalid start",
+                    "Must start with \"{}\"",
+                    prefix
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::MISSING_PREFIX])
+}
+
+/// Validates that a string ends with the specified `suffix`. Produces
+/// a `MISSING_SUFFIX` error if it doesn't, with the suffix available
+/// as the `"suffix"` [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::ends_with(".pdf");
+///
+/// assert!(v.validate_value(&"invoice.pdf".to_string(), &"file").is_ok());
+///
+/// let errors = v.validate_value(&"invoice.doc".to_string(), &"file").unwrap_err();
+/// assert_eq!("MISSING_SUFFIX", errors.errors[0].type_id);
+/// assert_eq!(Some(".pdf"), errors.errors[0].param("suffix"));
+/// ```
+pub fn ends_with<Value, Key, S>(suffix: S) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    S: Into<String>,
+{
+    let suffix = suffix.into();
+    let description = format!("must end with \"{}\"", suffix);
+    ValidatorFn::with_id(
+        format!("ends_with({:?})", suffix),
+        move |value: &Value, key: &Key| {
+            if value.as_ref().ends_with(suffix.as_str()) {
+                Ok(())
+            } else {
+                let suffix = suffix.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::MISSING_SUFFIX)
+                        .with_param("suffix", &suffix),
+                    "Invalid end",
+                    "Must end with \"{}\"",
+                    suffix
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::MISSING_SUFFIX])
+}
+
+/// Validates that a string contains the specified `substring`.
+/// Produces a `MISSING_SUBSTRING` error if it doesn't, with the
+/// substring available as the `"substring"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::contains("@");
+///
+/// assert!(v.validate_value(&"@kellpossible".to_string(), &"handle").is_ok());
+///
+/// let errors = v.validate_value(&"kellpossible".to_string(), &"handle").unwrap_err();
+/// assert_eq!("MISSING_SUBSTRING", errors.errors[0].type_id);
+/// assert_eq!(Some("@"), errors.errors[0].param("substring"));
+/// ```
+pub fn contains<Value, Key, S>(substring: S) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    S: Into<String>,
+{
+    let substring = substring.into();
+    let description = format!("must contain \"{}\"", substring);
+    ValidatorFn::with_id(
+        format!("contains({:?})", substring),
+        move |value: &Value, key: &Key| {
+            if value.as_ref().contains(substring.as_str()) {
+                Ok(())
+            } else {
+                let substring = substring.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::MISSING_SUBSTRING)
+                        .with_param("substring", &substring),
+                    "Missing text",
+                    "Must contain \"{}\"",
+                    substring
+                )
+                .into())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::MISSING_SUBSTRING])
+}
+
+/// Validates that a string does not contain the specified
+/// `substring`. Produces a `FORBIDDEN_SUBSTRING` error if it does,
+/// with the substring available as the `"substring"`
+/// [parameter](ValidationError::param()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::does_not_contain(" ");
+///
+/// assert!(v.validate_value(&"kellpossible".to_string(), &"username").is_ok());
+///
+/// let errors = v.validate_value(&"kell possible".to_string(), &"username").unwrap_err();
+/// assert_eq!("FORBIDDEN_SUBSTRING", errors.errors[0].type_id);
+/// assert_eq!(Some(" "), errors.errors[0].param("substring"));
+/// ```
+pub fn does_not_contain<Value, Key, S>(substring: S) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+    S: Into<String>,
+{
+    let substring = substring.into();
+    let description = format!("must not contain \"{}\"", substring);
+    ValidatorFn::with_id(
+        format!("does_not_contain({:?})", substring),
+        move |value: &Value, key: &Key| {
+            if value.as_ref().contains(substring.as_str()) {
+                let substring = substring.clone();
+                Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::FORBIDDEN_SUBSTRING)
+                        .with_param("substring", &substring),
+                    "Forbidden text",
+                    "Must not contain \"{}\"",
+                    substring
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        },
+    )
+    .described(description)
+    .produces(&[codes::FORBIDDEN_SUBSTRING])
+}
+
+/// The length of `value` in the characters counted by the length
+/// validators: grapheme clusters when the `"unicode"` feature is
+/// enabled, otherwise `char`s.
+fn length_in(value: &str) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        value.chars().count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::string_length;
+    use crate::ValidatorFn;
+
+    #[test]
+    #[should_panic(expected = "string_length: min (5) must not be greater than max (2)")]
+    fn string_length_rejects_min_greater_than_max() {
+        let _: ValidatorFn<String, &str> = string_length(5, 2);
+    }
+
+    #[test]
+    fn string_length_accepts_min_equal_to_max() {
+        let _: ValidatorFn<String, &str> = string_length(3, 3);
+    }
+}
@@ -0,0 +1,151 @@
+//! Validators for dates and times written in a custom format, using
+//! the [time](https://crates.io/crates/time) crate.
+
+use super::invalid_date_format;
+use crate::{codes, ValidatorFn};
+use ::time::{
+    error::InvalidFormatDescription,
+    format_description::{self, OwnedFormatItem},
+    parsing::Parsed,
+    Date, PrimitiveDateTime,
+};
+use std::convert::TryFrom;
+
+/// Validates that a string is a date written in the specified
+/// `format` (using time's
+/// [format description](https://time-rs.github.io/book/api/format-description.html)
+/// syntax, for example `"[day]/[month]/[year]"`). Produces an
+/// `INVALID_DATE` error if it isn't, with the format available as
+/// the `"format"` [parameter](crate::ValidationError::param()), and
+/// the character offset where parsing failed (when known) as the
+/// `"position"` parameter. Returns an error if the `format` is
+/// invalid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> = validators::time::date("[day]/[month]/[year]").unwrap();
+///
+/// assert!(v.validate_value(&"29/02/2020".to_string(), &"date").is_ok());
+///
+/// let errors = v.validate_value(&"29-02-2020".to_string(), &"date").unwrap_err();
+/// assert_eq!("INVALID_DATE", errors.errors[0].type_id);
+/// assert_eq!(Some("2"), errors.errors[0].param("position"));
+///
+/// // not a leap year
+/// let errors = v.validate_value(&"29/02/2021".to_string(), &"date").unwrap_err();
+/// assert_eq!(None, errors.errors[0].param("position"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub fn date<Value, Key>(format: &str) -> Result<ValidatorFn<Value, Key>, InvalidFormatDescription>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let items = items(format)?;
+    let format = format.to_string();
+    let description = format!("must be a date in the format {}", format);
+
+    Ok(ValidatorFn::with_id(
+        format!("time_date({:?})", format),
+        move |value: &Value, key: &Key| {
+            parse(value.as_ref(), &items, |parsed| {
+                Date::try_from(parsed).is_ok()
+            })
+            .map_err(|position| invalid_date_format(key, codes::INVALID_DATE, &format, position))
+        },
+    )
+    .described(description)
+    .produces(&[codes::INVALID_DATE]))
+}
+
+/// Validates that a string is a date and time written in the
+/// specified `format` (using time's
+/// [format description](https://time-rs.github.io/book/api/format-description.html)
+/// syntax, for example `"[year]-[month]-[day] [hour]:[minute]"`).
+/// Produces an `INVALID_DATETIME` error if it isn't, with the same
+/// parameters as [date()]. Returns an error if the `format` is
+/// invalid.
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+///
+/// let v: ValidatorFn<String, &str> =
+///     validators::time::datetime("[year]-[month]-[day] [hour]:[minute]").unwrap();
+///
+/// assert!(v.validate_value(&"2020-02-29 13:30".to_string(), &"start").is_ok());
+///
+/// let errors = v.validate_value(&"2020-02-29 25:30".to_string(), &"start").unwrap_err();
+/// assert_eq!("INVALID_DATETIME", errors.errors[0].type_id);
+/// assert_eq!(Some("11"), errors.errors[0].param("position"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub fn datetime<Value, Key>(
+    format: &str,
+) -> Result<ValidatorFn<Value, Key>, InvalidFormatDescription>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let items = items(format)?;
+    let format = format.to_string();
+    let description = format!("must be a date and time in the format {}", format);
+
+    Ok(ValidatorFn::with_id(
+        format!("time_datetime({:?})", format),
+        move |value: &Value, key: &Key| {
+            parse(value.as_ref(), &items, |parsed| {
+                PrimitiveDateTime::try_from(parsed).is_ok()
+            })
+            .map_err(|position| {
+                invalid_date_format(key, codes::INVALID_DATETIME, &format, position)
+            })
+        },
+    )
+    .described(description)
+    .produces(&[codes::INVALID_DATETIME]))
+}
+
+/// Parse the `format` description into its top level items, so they
+/// can be parsed one at a time.
+fn items(format: &str) -> Result<Vec<OwnedFormatItem>, InvalidFormatDescription> {
+    match format_description::parse_owned::<1>(format)? {
+        OwnedFormatItem::Compound(items) => Ok(items.into_vec()),
+        item => Ok(vec![item]),
+    }
+}
+
+/// Parse `value` one format item at a time, so that the position of
+/// the first item which fails to parse is known. Returns `Err` with
+/// the character offset where parsing failed, or `None` if the parsed
+/// fields don't `resolve` to a valid value.
+fn parse<R>(value: &str, items: &[OwnedFormatItem], resolve: R) -> Result<(), Option<usize>>
+where
+    R: FnOnce(Parsed) -> bool,
+{
+    let mut parsed = Parsed::new();
+    let mut remainder = value.as_bytes();
+    let position = |remainder: &[u8]| {
+        String::from_utf8_lossy(&value.as_bytes()[..value.len() - remainder.len()])
+            .chars()
+            .count()
+    };
+
+    for item in items {
+        remainder = parsed
+            .parse_item(remainder, item)
+            .map_err(|_| Some(position(remainder)))?;
+    }
+
+    if !remainder.is_empty() {
+        return Err(Some(position(remainder)));
+    }
+
+    if resolve(parsed) {
+        Ok(())
+    } else {
+        Err(None)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{codes, ValidationError, ValidatorFn};
+use url::Url;
+
+/// Configuration for the [url()] validator.
+///
+/// By default any scheme is allowed, and a host is not required.
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UrlOptions {
+    allowed_schemes: Option<Vec<String>>,
+    require_host: bool,
+}
+
+impl UrlOptions {
+    /// Create a new `UrlOptions` with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Factory method to only allow urls with one of the specified
+    /// schemes (for example only `"https"`).
+    pub fn allowed_schemes<S: Into<String>>(mut self, schemes: Vec<S>) -> Self {
+        self.allowed_schemes = Some(
+            schemes
+                .into_iter()
+                .map(|scheme| scheme.into().to_lowercase())
+                .collect(),
+        );
+        self
+    }
+
+    /// Factory method to set whether urls are required to have a host
+    /// (which rejects urls like `mailto:user@example.com`).
+    pub fn require_host(mut self, require_host: bool) -> Self {
+        self.require_host = require_host;
+        self
+    }
+}
+
+/// Validates that a string is an absolute url, according to the
+/// configured `options`. Produces one of the following errors if it
+/// isn't:
+///
+/// + `URL_INVALID` - the url could not be parsed.
+/// + `URL_SCHEME_NOT_ALLOWED` - the url's scheme is not one of the
+///   [allowed schemes](UrlOptions::allowed_schemes()), which is
+///   available as the `"scheme"` [parameter](ValidationError::param()).
+/// + `URL_HOST_REQUIRED` - the url doesn't have a host, and one is
+///   [required](UrlOptions::require_host()).
+///
+/// ## Example
+/// ```
+/// use form_validation::{validators, Validation, ValidatorFn};
+/// use form_validation::validators::UrlOptions;
+///
+/// let v: ValidatorFn<String, &str> = validators::url(
+///     UrlOptions::new()
+///         .allowed_schemes(vec!["https"])
+///         .require_host(true),
+/// );
+///
+/// assert!(v.validate_value(&"https://example.com/path".to_string(), &"website").is_ok());
+///
+/// let errors = v.validate_value(&"example".to_string(), &"website").unwrap_err();
+/// assert_eq!("URL_INVALID", errors.errors[0].type_id);
+///
+/// let errors = v.validate_value(&"http://example.com".to_string(), &"website").unwrap_err();
+/// assert_eq!("URL_SCHEME_NOT_ALLOWED", errors.errors[0].type_id);
+/// assert_eq!(Some("http"), errors.errors[0].param("scheme"));
+///
+/// let v: ValidatorFn<String, &str> = validators::url(UrlOptions::new().require_host(true));
+/// let errors = v.validate_value(&"mailto:user@example.com".to_string(), &"website").unwrap_err();
+/// assert_eq!("URL_HOST_REQUIRED", errors.errors[0].type_id);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn url<Value, Key>(options: UrlOptions) -> ValidatorFn<Value, Key>
+where
+    Value: AsRef<str>,
+    Key: Clone + PartialEq + 'static,
+{
+    let description = match &options.allowed_schemes {
+        Some(schemes) => format!("must be a valid {} url", schemes.join(" or ")),
+        None => "must be a valid url".to_string(),
+    };
+
+    ValidatorFn::with_id(
+        format!(
+            "url({:?}, {})",
+            options.allowed_schemes, options.require_host
+        ),
+        move |value: &Value, key: &Key| {
+            let url = match Url::parse(value.as_ref()) {
+                Ok(url) => url,
+                Err(_) => {
+                    return Err(builtin_message!(
+                        ValidationError::new(key.clone(), codes::URL_INVALID),
+                        "Invalid url",
+                        "Must be a valid url"
+                    )
+                    .into())
+                }
+            };
+
+            if let Some(schemes) = &options.allowed_schemes {
+                if !schemes.iter().any(|scheme| scheme == url.scheme()) {
+                    #[cfg_attr(feature = "minimal-messages", allow(unused_variables))]
+                    let allowed = schemes.join(", ");
+                    return Err(builtin_message!(
+                        ValidationError::new(key.clone(), codes::URL_SCHEME_NOT_ALLOWED)
+                            .with_param("scheme", url.scheme()),
+                        "Url scheme not allowed",
+                        "Must be a url starting with one of: {}",
+                        allowed
+                    )
+                    .into());
+                }
+            }
+
+            if options.require_host && url.host().is_none() {
+                return Err(builtin_message!(
+                    ValidationError::new(key.clone(), codes::URL_HOST_REQUIRED),
+                    "Url host required",
+                    "Must be a url with a host"
+                )
+                .into());
+            }
+
+            Ok(())
+        },
+    )
+    .described(description)
+    .produces(&[
+        codes::URL_INVALID,
+        codes::URL_SCHEME_NOT_ALLOWED,
+        codes::URL_HOST_REQUIRED,
+    ])
+}
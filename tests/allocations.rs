@@ -0,0 +1,85 @@
+use form_validation::{validators, Validation, ValidationError, ValidationErrors, Validator};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Counts the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The number of allocations made while running `f`.
+fn allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// Validating into a reused buffer with validation functions which
+/// push their errors directly into it doesn't allocate, whereas
+/// validation functions which return their errors allocate a new
+/// collection for each failure.
+#[test]
+fn validate_value_into_reused_buffer_does_not_allocate() {
+    let v: Validator<String, &'static str> = Validator::new()
+        .validation(validators::required())
+        .validation(validators::identifier());
+    let returning: Validator<String, &'static str> = Validator::new()
+        .validation(|value: &String, key: &&'static str| {
+            if value.trim().is_empty() {
+                Err(ValidationError::new(*key, "REQUIRED").into())
+            } else {
+                Ok(())
+            }
+        })
+        .validation(|value: &String, key: &&'static str| {
+            if value.starts_with(|c: char| c.is_ascii_digit()) {
+                Err(ValidationError::new(*key, "INVALID_IDENTIFIER").into())
+            } else {
+                Ok(())
+            }
+        });
+
+    let values = vec![
+        String::new(),
+        "1abc".to_string(),
+        "abc".to_string(),
+        "   ".to_string(),
+    ];
+    let mut errors: ValidationErrors<&'static str> = ValidationErrors::with_capacity(16);
+
+    let pushed = allocations(|| {
+        for value in &values {
+            errors.clear();
+            v.validate_value_into(value, &"username", &mut errors);
+        }
+    });
+    assert_eq!("INVALID_IDENTIFIER", errors.errors[1].type_id);
+    assert_eq!(0, pushed);
+
+    let returned = allocations(|| {
+        for value in &values {
+            errors.clear();
+            returning.validate_value_into(value, &"username", &mut errors);
+        }
+    });
+    assert_eq!("REQUIRED", errors.errors[0].type_id);
+    assert!(returned > 0);
+}
@@ -0,0 +1,393 @@
+#![cfg(feature = "derive")]
+
+use form_validation::{
+    rules, validator, FieldPath, FormField, FormFields, UnknownField, Validatable, Validation,
+    ValidationError, ValidationErrors, Validator, ValidatorFn,
+};
+
+#[derive(Validatable)]
+struct Profile {
+    #[validate(required, length(max = 10))]
+    name: String,
+    #[validate(starts_with("https://"))]
+    #[validate(length(min = 12))]
+    website: String,
+    #[validate(multiple_of(6))]
+    quantity: u32,
+    #[allow(dead_code)]
+    notes: String,
+}
+
+impl Profile {
+    fn valid() -> Self {
+        Self {
+            name: "Alice".to_string(),
+            website: "https://example.com".to_string(),
+            quantity: 12,
+            notes: String::new(),
+        }
+    }
+}
+
+fn type_ids<Key: ToString>(errors: &ValidationErrors<Key>) -> Vec<(String, &str)> {
+    errors
+        .errors
+        .iter()
+        .map(|error| (error.key.to_string(), error.type_id.as_ref()))
+        .collect()
+}
+
+#[test]
+fn valid_form_is_valid() {
+    assert!(Validatable::<&'static str>::validate(&Profile::valid()).is_ok());
+    assert!(Validatable::<FieldPath>::validate(&Profile::valid()).is_ok());
+}
+
+#[test]
+fn errors_are_keyed_by_field_name() {
+    let profile = Profile {
+        name: " ".to_string(),
+        website: "http://x".to_string(),
+        quantity: 7,
+        ..Profile::valid()
+    };
+
+    let errors: ValidationErrors<String> = profile.validate().unwrap_err();
+    assert_eq!(
+        vec![
+            ("name".to_string(), "REQUIRED"),
+            ("website".to_string(), "MISSING_PREFIX"),
+            ("website".to_string(), "LENGTH_OUT_OF_RANGE"),
+            ("quantity".to_string(), "NOT_MULTIPLE_OF"),
+        ],
+        type_ids(&errors)
+    );
+
+    let errors: ValidationErrors<FieldPath> = profile.validate().unwrap_err();
+    assert_eq!(4, errors.len());
+    assert!(errors.get(&FieldPath::from("website")).is_some());
+}
+
+#[test]
+fn length_defaults_to_no_maximum() {
+    let profile = Profile {
+        website: format!("https://{}.com", "a".repeat(1000)),
+        ..Profile::valid()
+    };
+    assert!(Validatable::<String>::validate(&profile).is_ok());
+}
+
+fn even<Key: Clone + PartialEq>(value: &u32, key: &Key) -> Result<(), ValidationErrors<Key>> {
+    if value.is_multiple_of(2) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(key.clone(), "ODD").into())
+    }
+}
+
+#[derive(Validatable)]
+struct Generic<T> {
+    #[validate(with = "even", range(min = 2, max = 10))]
+    count: u32,
+    #[allow(dead_code)]
+    value: T,
+}
+
+#[test]
+fn custom_validation_functions_and_generic_structs() {
+    let form = Generic {
+        count: 11,
+        value: (),
+    };
+    let errors: ValidationErrors<&'static str> = form.validate().unwrap_err();
+    assert_eq!(
+        vec![
+            ("count".to_string(), "ODD"),
+            ("count".to_string(), "OUT_OF_RANGE")
+        ],
+        type_ids(&errors)
+    );
+}
+
+#[derive(FormFields, Validatable)]
+#[validate(fields = "SignupField")]
+struct Signup {
+    #[validate(length(min = 3))]
+    user_name: String,
+    #[validate(range(min = 13, max = 130))]
+    age: u32,
+    #[validate(identifier)]
+    r#type: String,
+}
+
+#[derive(FormFields)]
+#[form_fields(name = "Field")]
+#[allow(dead_code)]
+struct Renamed {
+    value: u32,
+}
+
+#[test]
+fn form_fields_are_typed_keys() {
+    let form = Signup {
+        user_name: "al".to_string(),
+        age: 12,
+        r#type: "admin".to_string(),
+    };
+    let errors: ValidationErrors<SignupField> = form.validate().unwrap_err();
+    assert_eq!(
+        "LENGTH_OUT_OF_RANGE",
+        errors.get(&SignupField::UserName).unwrap().errors[0].type_id
+    );
+    assert_eq!(
+        "OUT_OF_RANGE",
+        errors.get(&SignupField::Age).unwrap().errors[0].type_id
+    );
+    assert!(errors.get(&SignupField::Type).is_none());
+}
+
+#[test]
+fn form_fields_names() {
+    assert_eq!(
+        &[SignupField::UserName, SignupField::Age, SignupField::Type],
+        SignupField::ALL
+    );
+    assert_eq!("user_name", SignupField::UserName.to_string());
+    assert_eq!("type", SignupField::Type.name());
+    assert_eq!("age", <&'static str>::from(SignupField::Age));
+    assert_eq!(FieldPath::from("age"), FieldPath::from(SignupField::Age));
+    assert_eq!(Ok(SignupField::Type), "type".parse());
+    assert_eq!(
+        Err(UnknownField {
+            name: "email".to_string()
+        }),
+        "email".parse::<SignupField>()
+    );
+    assert_eq!(Some(Field::Value), Field::from_name("value"));
+}
+
+#[derive(Validatable, Clone, PartialEq)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(length(min = 4, max = 4))]
+    postcode: String,
+}
+
+#[derive(Validatable, Clone, PartialEq)]
+struct Order {
+    #[validate(length(min = 3))]
+    email: String,
+    #[validate(nested)]
+    shipping: Address,
+    #[validate(nested)]
+    billing: Address,
+}
+
+fn invalid_order() -> Order {
+    Order {
+        email: "alice@example.com".to_string(),
+        shipping: Address {
+            street: String::new(),
+            postcode: "2000".to_string(),
+        },
+        billing: Address {
+            street: "1 Main St".to_string(),
+            postcode: "20".to_string(),
+        },
+    }
+}
+
+#[test]
+fn nested_forms_prefix_keys() {
+    let errors: ValidationErrors<FieldPath> = invalid_order().validate().unwrap_err();
+    assert_eq!(
+        vec![
+            ("shipping.street".to_string(), "LENGTH_OUT_OF_RANGE"),
+            ("billing.postcode".to_string(), "LENGTH_OUT_OF_RANGE")
+        ],
+        type_ids(&errors)
+    );
+
+    let errors: ValidationErrors<String> = invalid_order().validate().unwrap_err();
+    assert!(errors.get(&"shipping.street".to_string()).is_some());
+}
+
+#[validator]
+fn not_blank<Key: Clone + PartialEq>(
+    value: &String,
+    key: &Key,
+) -> Result<(), ValidationErrors<Key>> {
+    if value.trim().is_empty() {
+        Err(ValidationError::new(key.clone(), "BLANK").into())
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn validator_functions() {
+    let v: ValidatorFn<String, FieldPath> = not_blank();
+    assert_eq!("derive::not_blank", v.id().to_string());
+    assert_eq!(v, not_blank::<FieldPath>());
+
+    let key = FieldPath::from("name");
+    assert!(v.validate_value(&"alice".to_string(), &key).is_ok());
+    let errors = v.validate_value(&"  ".to_string(), &key).unwrap_err();
+    assert_eq!("BLANK", errors.errors[0].type_id);
+}
+
+#[test]
+fn rules_macro() {
+    let v: Validator<String, &str> =
+        rules!(String, "len(2..4) && (identifier && starts_with(\"a\"))");
+    let key = "name";
+    assert!(v.validate_value(&"abc".to_string(), &key).is_ok());
+    assert!(v.validate_value(&"abcd".to_string(), &key).is_err());
+    assert_eq!(
+        vec![
+            ("name".to_string(), "LENGTH_OUT_OF_RANGE"),
+            ("name".to_string(), "INVALID_IDENTIFIER"),
+            ("name".to_string(), "MISSING_PREFIX")
+        ],
+        type_ids(&v.validate_value(&"9".to_string(), &key).unwrap_err())
+    );
+
+    let v: Validator<String, &str> = rules!(String, "len(..=3)");
+    assert!(v.validate_value(&"".to_string(), &key).is_ok());
+    assert!(v.validate_value(&"abcd".to_string(), &key).is_err());
+}
+
+#[cfg(feature = "async")]
+mod asynchronous {
+    use super::type_ids;
+    use form_validation::{
+        AsyncValidatable, FieldPath, FormState, SubmitError, Validatable, ValidationError,
+        ValidationErrors,
+    };
+    use futures::executor::block_on;
+    use std::{cell::RefCell, future::Future, pin::Pin};
+
+    type ValidationFuture<Key> = Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>>;
+
+    fn not_taken<Key>(value: &String, key: &Key) -> ValidationFuture<Key>
+    where
+        Key: Clone + PartialEq + 'static,
+    {
+        let taken = value == "alice";
+        let key = key.clone();
+        Box::pin(async move {
+            if taken {
+                Err(ValidationError::new(key, "TAKEN").into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[derive(Validatable, AsyncValidatable)]
+    struct Account {
+        #[validate(identifier)]
+        #[validate_async(with = "not_taken")]
+        username: String,
+        #[validate(range(min = 1, max = 10))]
+        seats: u32,
+        #[validate_async(with = "not_taken")]
+        team: String,
+    }
+
+    #[test]
+    fn async_validation_includes_sync_validators() {
+        let account = Account {
+            username: "alice".to_string(),
+            seats: 0,
+            team: "alice".to_string(),
+        };
+
+        let errors: ValidationErrors<FieldPath> = account.validate().unwrap_err();
+        assert_eq!(
+            vec![("seats".to_string(), "OUT_OF_RANGE")],
+            type_ids(&errors)
+        );
+
+        let errors: ValidationErrors<FieldPath> = block_on(account.validate_future()).unwrap_err();
+        assert_eq!(
+            vec![
+                ("username".to_string(), "TAKEN"),
+                ("seats".to_string(), "OUT_OF_RANGE"),
+                ("team".to_string(), "TAKEN"),
+            ],
+            type_ids(&errors)
+        );
+    }
+
+    #[derive(Clone, Validatable, AsyncValidatable)]
+    struct Invitation {
+        #[validate(length(min = 3))]
+        #[validate_async(with = "not_taken")]
+        name: String,
+    }
+
+    /// The synchronous errors which are repeated by the derived
+    /// asynchronous validation are only reported once on submission.
+    #[test]
+    fn submit_reports_sync_errors_once() {
+        let state = RefCell::new(FormState::new(
+            Invitation {
+                name: "al".to_string(),
+            },
+            vec![FieldPath::from("name")],
+        ));
+
+        let result = block_on(FormState::submit_with(&state, |_| async { Ok(()) }));
+
+        match result {
+            Err(SubmitError::Invalid(errors)) => assert_eq!(
+                vec![("name".to_string(), "LENGTH_OUT_OF_RANGE")],
+                type_ids(&errors)
+            ),
+            other => panic!("expected invalid submission, got {:?}", other),
+        }
+        assert_eq!(1, state.borrow().errors().len());
+    }
+
+    #[derive(Validatable, AsyncValidatable)]
+    struct Organisation {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(nested)]
+        owner: Account,
+    }
+
+    #[test]
+    fn nested_forms_are_validated_asynchronously() {
+        let organisation = Organisation {
+            name: String::new(),
+            owner: Account {
+                username: "alice".to_string(),
+                seats: 3,
+                team: "builders".to_string(),
+            },
+        };
+
+        let errors: ValidationErrors<FieldPath> =
+            block_on(organisation.validate_future()).unwrap_err();
+        assert_eq!(
+            vec![
+                ("name".to_string(), "LENGTH_OUT_OF_RANGE"),
+                ("owner.username".to_string(), "TAKEN"),
+            ],
+            type_ids(&errors)
+        );
+    }
+
+    #[test]
+    fn valid_form_is_valid_asynchronously() {
+        let account = Account {
+            username: "bob".to_string(),
+            seats: 3,
+            team: "builders".to_string(),
+        };
+        assert!(block_on(AsyncValidatable::<String>::validate_future(&account)).is_ok());
+    }
+}
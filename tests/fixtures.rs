@@ -0,0 +1,144 @@
+#![cfg(feature = "fixtures")]
+
+use form_validation::{
+    fixtures::{CheckoutForm, LineItem, SettingsForm, SignupForm},
+    AsyncValidatable, FieldPath, FormState, RevalidationStrategy, SubmitError, Validatable,
+};
+use futures::executor::block_on;
+use std::cell::RefCell;
+
+fn type_ids(errors: &form_validation::ValidationErrors<FieldPath>) -> Vec<(String, &str)> {
+    errors
+        .errors
+        .iter()
+        .map(|error| (error.key.to_string(), error.type_id.as_ref()))
+        .collect()
+}
+
+#[test]
+fn valid_fixtures_are_valid() {
+    assert!(SignupForm::valid().validate().is_ok());
+    assert!(block_on(SignupForm::valid().validate_future()).is_ok());
+    assert!(CheckoutForm::valid().validate().is_ok());
+    assert!(block_on(CheckoutForm::valid().validate_future()).is_ok());
+    assert!(SettingsForm::valid().validate().is_ok());
+}
+
+#[test]
+fn signup_errors() {
+    let form = SignupForm {
+        username: "1st".to_string(),
+        email: "".to_string(),
+        password: "short".to_string(),
+        confirm_password: "shirt".to_string(),
+        accept_terms: false,
+    };
+
+    assert_eq!(
+        vec![
+            ("username".to_string(), "INVALID_IDENTIFIER"),
+            ("email".to_string(), "REQUIRED"),
+            ("password".to_string(), "LENGTH_OUT_OF_RANGE"),
+            ("confirm_password".to_string(), "FIELDS_DO_NOT_MATCH"),
+            ("accept_terms".to_string(), "TERMS_NOT_ACCEPTED"),
+        ],
+        type_ids(&form.validate().unwrap_err())
+    );
+}
+
+#[test]
+fn signup_submit_reports_taken_username() {
+    let form = SignupForm {
+        username: "alice".to_string(),
+        ..SignupForm::valid()
+    };
+    let state = RefCell::new(FormState::new(form, SignupForm::fields()));
+
+    match block_on(FormState::submit_with(&state, |_| async { Ok(()) })) {
+        Err(SubmitError::Invalid(errors)) => assert_eq!(
+            vec![("username".to_string(), "USERNAME_TAKEN")],
+            type_ids(&errors)
+        ),
+        other => panic!(
+            "unexpected result: {:?}",
+            other.map_err(|error| error.to_string())
+        ),
+    }
+    assert!(state.borrow().is_touched(&FieldPath::from("username")));
+}
+
+#[test]
+fn checkout_nested_and_collection_errors() {
+    let mut form = CheckoutForm::valid();
+    form.billing_same_as_shipping = false;
+    form.shipping.postcode = "ABCD".to_string();
+    form.items.push(LineItem {
+        sku: "".to_string(),
+        quantity: 100,
+    });
+
+    assert_eq!(
+        vec![
+            ("shipping.postcode".to_string(), "INVALID_POSTCODE"),
+            ("billing.street".to_string(), "REQUIRED"),
+            ("billing.city".to_string(), "REQUIRED"),
+            ("billing.postcode".to_string(), "REQUIRED"),
+            ("billing.postcode".to_string(), "INVALID_POSTCODE"),
+            ("items[1].sku".to_string(), "REQUIRED"),
+            ("items[1].quantity".to_string(), "OUT_OF_RANGE"),
+        ],
+        type_ids(&form.validate().unwrap_err())
+    );
+
+    form.items = Vec::new();
+    assert!(type_ids(&form.validate().unwrap_err()).contains(&("items".to_string(), "REQUIRED")));
+}
+
+#[test]
+fn checkout_async_stock_check() {
+    let mut form = CheckoutForm::valid();
+    form.items.push(LineItem {
+        sku: "SKU-404".to_string(),
+        quantity: 1,
+    });
+
+    assert_eq!(
+        vec![("items[1].sku".to_string(), "OUT_OF_STOCK")],
+        type_ids(&block_on(form.validate_future()).unwrap_err())
+    );
+}
+
+#[test]
+fn settings_optional_and_map_errors() {
+    let mut form = SettingsForm::valid();
+    form.notification_email = Some("not an email".to_string());
+    form.theme = "solarized".to_string();
+    form.signatures.insert("de".to_string(), "x".repeat(201));
+
+    assert_eq!(
+        vec![
+            ("notification_email".to_string(), "INVALID_EMAIL"),
+            ("theme".to_string(), "NOT_ONE_OF"),
+            ("signatures.de".to_string(), "LENGTH_OUT_OF_RANGE"),
+        ],
+        type_ids(&form.validate().unwrap_err())
+    );
+}
+
+#[test]
+fn settings_form_state_revalidation() {
+    let mut state = FormState::new(SettingsForm::valid(), SettingsForm::fields())
+        .strategy(RevalidationStrategy::Smart);
+    let display_name = FieldPath::from("display_name");
+
+    state.form_mut().display_name = "".to_string();
+    state.field_changed(&display_name);
+    assert!(state.errors().is_empty());
+
+    state.field_blurred(&display_name);
+    assert_eq!(1, state.errors().len());
+
+    state.form_mut().display_name = "Bob".to_string();
+    state.field_changed(&display_name);
+    assert!(state.errors().is_empty());
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "send")]
+
+use form_validation::{validators, Validation, ValidationError, ValidationErrors, ValidatorFn};
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn errors_are_send_and_sync() {
+    assert_send_sync::<ValidationError<String>>();
+    assert_send_sync::<ValidationErrors<String>>();
+}
+
+#[test]
+fn errors_can_be_sent_between_threads() {
+    let errors = thread::spawn(|| {
+        let v: ValidatorFn<String, String> = validators::string_length(3, 16);
+        let mut errors = v
+            .validate_value(&"al".to_string(), &"username".to_string())
+            .unwrap_err();
+        errors.push(
+            ValidationError::new("email".to_string(), "REQUIRED")
+                .with_message(|key| format!("{} is required", key)),
+        );
+        errors
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!("LENGTH_OUT_OF_RANGE", errors.errors[0].type_id);
+    assert_eq!("email is required", errors.errors[1].to_string());
+}
@@ -0,0 +1,138 @@
+//! Tests which run on the `wasm32-unknown-unknown` platform using
+//! [wasm-bindgen-test](https://crates.io/crates/wasm-bindgen-test),
+//! to check that the advertised features work in the browser (and
+//! node), where there is no `std::time::Instant`, and futures are
+//! driven by the JavaScript event loop.
+//!
+//! Run with:
+//!
+//! ```sh
+//! wasm-pack test --node -- --features "wasm-bindgen-support async full"
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use form_validation::{
+    validate_items_async, validators, AsyncValidatable, AsyncValidator, AsyncValidatorFn,
+    FormState, Validatable, Validation, ValidationError, ValidationErrors, ValidationOutcome,
+    Validator, ValidatorFn,
+};
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn not_negative() -> Validator<i32, &'static str> {
+    Validator::new().validation(|value: &i32, key: &&'static str| {
+        if *value < 0 {
+            Err(ValidationError::new(*key, "NOT_LESS_THAN_0").into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+#[wasm_bindgen_test]
+fn validator() {
+    let v = not_negative();
+    assert!(v.validate_value(&1, &"field1").is_ok());
+    assert!(v.validate_value(&-1, &"field1").is_err());
+}
+
+#[wasm_bindgen_test]
+fn validator_sink_without_instant() {
+    let durations = Rc::new(RefCell::new(Vec::new()));
+    let v = not_negative().sink({
+        let durations = durations.clone();
+        move |outcome: &ValidationOutcome<&'static str>| {
+            durations.borrow_mut().push(outcome.duration);
+        }
+    });
+
+    let _ = v.validate_value(&-1, &"field1");
+
+    // timing is not available on wasm32-unknown-unknown
+    assert_eq!(vec![None], *durations.borrow());
+}
+
+#[wasm_bindgen_test]
+fn builtin_validators() {
+    let v: ValidatorFn<String, &str> = validators::email();
+    assert!(v
+        .validate_value(&"user@example.com".to_string(), &"email")
+        .is_ok());
+
+    let v: ValidatorFn<String, &str> = validators::iso_date();
+    assert!(v
+        .validate_value(&"2021-02-29".to_string(), &"date")
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+async fn async_validator() {
+    let v: AsyncValidator<i32, &'static str> = AsyncValidator::new()
+        .validation(AsyncValidatorFn::new(|value: &i32, key: &&'static str| {
+            let value = *value;
+            let key = *key;
+            Box::pin(async move {
+                if value > 10 {
+                    Err(ValidationError::new(key, "NOT_GREATER_THAN_10").into())
+                } else {
+                    Ok(())
+                }
+            })
+        }))
+        .validation(not_negative().validations.remove(0));
+
+    assert!(v.validate_value(&5, &"field1").await.is_ok());
+    assert!(v.validate_value(&11, &"field1").await.is_err());
+    assert!(v.validate_value(&-1, &"field1").await.is_err());
+}
+
+struct Item {
+    sku: String,
+}
+
+impl AsyncValidatable<&'static str> for Item {
+    fn validate_future(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<&'static str>>>>> {
+        let sku = self.sku.clone();
+        Box::pin(async move {
+            if sku.is_empty() {
+                Err(ValidationError::new("sku", "REQUIRED").into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+impl Validatable<&'static str> for Item {
+    fn validate(&self) -> Result<(), ValidationErrors<&'static str>> {
+        Ok(())
+    }
+}
+
+#[wasm_bindgen_test]
+async fn remote_collection_validation() {
+    let items = vec![
+        Item {
+            sku: "SKU-1".to_string(),
+        },
+        Item { sku: String::new() },
+    ];
+
+    let errors = validate_items_async(&items, 1, |index, key| format!("items[{}].{}", index, key))
+        .await
+        .unwrap_err();
+
+    assert_eq!("items[1].sku", errors.errors[0].key);
+}
+
+#[wasm_bindgen_test]
+async fn form_submission() {
+    let state = RefCell::new(FormState::new(Item { sku: String::new() }, vec!["sku"]));
+
+    let result = FormState::submit_with(&state, |_| async { Ok(()) }).await;
+
+    assert!(result.is_err());
+    assert!(!state.borrow().is_submitting());
+}
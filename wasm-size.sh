@@ -0,0 +1,58 @@
+#!/bin/sh
+# Checks that the built-in validators stay within the binary size
+# budget when compiled for wasm32-unknown-unknown with the
+# "minimal-messages" feature. Requires wasm-opt and python3, and
+# twiggy (if installed) is used to report the largest functions. Run
+# by the "wasm" job in CI, which fails if the budget is exceeded.
+set -e
+
+BUDGET_BYTES=${BUDGET_BYTES:-220000}
+WASM=target/wasm32-unknown-unknown/release/examples/wasm_size.wasm
+
+export CARGO_PROFILE_RELEASE_OPT_LEVEL=z
+export CARGO_PROFILE_RELEASE_LTO=true
+export CARGO_PROFILE_RELEASE_CODEGEN_UNITS=1
+export CARGO_PROFILE_RELEASE_PANIC=abort
+
+cargo build --release --target wasm32-unknown-unknown --example wasm_size --features "full minimal-messages wasm-bindgen-support"
+wasm-opt -Oz --strip-debug --strip-producers -o target/wasm_size.opt.wasm "$WASM"
+if command -v twiggy > /dev/null; then
+    twiggy top -n 20 target/wasm_size.opt.wasm
+fi
+
+# Only count the code and data, not the custom sections (such as the
+# metadata for wasm-bindgen), which are removed before shipping.
+SIZE=$(python3 - target/wasm_size.opt.wasm <<'EOF'
+import sys
+
+data = open(sys.argv[1], "rb").read()
+
+
+def leb128(offset):
+    value = shift = 0
+    while True:
+        byte = data[offset]
+        offset += 1
+        value |= (byte & 0x7F) << shift
+        shift += 7
+        if byte < 0x80:
+            return value, offset
+
+
+size = 0
+offset = 8
+while offset < len(data):
+    section_id = data[offset]
+    length, offset = leb128(offset + 1)
+    if section_id != 0:
+        size += length
+    offset += length
+print(size)
+EOF
+)
+echo "wasm_size.wasm: $SIZE bytes (budget: $BUDGET_BYTES bytes)"
+
+if [ "$SIZE" -gt "$BUDGET_BYTES" ]; then
+    echo "binary size budget exceeded"
+    exit 1
+fi